@@ -12,7 +12,9 @@ use crate::cli_parser;
 use crate::config;
 use crate::descriptor;
 use crate::environment;
+use crate::environment::provenance;
 use crate::error::CargoMakeError;
+use crate::functions::user_functions;
 use crate::logger;
 use crate::logger::LoggerOptions;
 use crate::profile;
@@ -20,8 +22,9 @@ use crate::recursion_level;
 use crate::runner;
 use crate::time_summary;
 use crate::toolchain;
-use crate::types::{CliArgs, GlobalConfig};
+use crate::types::{CliArgs, Config, EnvInfo, GlobalConfig};
 use crate::version;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 pub(crate) static VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -37,8 +40,15 @@ pub fn run(
     global_config: &GlobalConfig,
     logger_options: Option<LoggerOptions>,
 ) -> Result<(), CargoMakeError> {
+    // printed before the logger is initialized so the output is a valid JSON document
+    if cli_args.print_schema {
+        return cli_commands::print_schema::print(&mut std::io::stdout());
+    }
+
     let start_time = SystemTime::now();
 
+    provenance::clear();
+
     recursion_level::increment();
 
     logger::init(&logger_options.unwrap_or(LoggerOptions {
@@ -82,53 +92,39 @@ pub fn run(
         Some(ref value) => Some(value.as_ref()),
         None => None,
     };
-    let home = environment::setup_cwd(cwd);
+    let home = provenance::track("working directory setup", || environment::setup_cwd(cwd));
 
     let force_makefile = cli_args.build_file.is_some();
     let build_file = &cli_args
         .build_file
         .clone()
-        .unwrap_or(DEFAULT_TOML.to_string());
-    let task = &cli_args.task;
-    let profile_name = &cli_args
-        .profile
-        .clone()
-        .unwrap_or_else(profile::default_profile);
-    let normalized_profile_name = profile::set(&profile_name);
+        .unwrap_or_else(|| descriptor::get_default_makefile(Path::new(".")));
 
-    environment::load_env_file(cli_args.env_file.clone());
+    if cli_args.fmt {
+        return cli_commands::format_makefile::run(build_file, cli_args.fmt_check);
+    }
 
-    let env = cli_args.env.clone();
+    if cli_args.migrate {
+        return cli_commands::migrate_makefile::run(build_file, cli_args.migrate_write);
+    }
 
+    let task = &cli_args.task;
     let experimental = cli_args.experimental;
-    let config = descriptor::load(&build_file, force_makefile, env, experimental)?;
-
-    let mut time_summary_vec = vec![];
-    time_summary::add(
-        &mut time_summary_vec,
-        "[Load Makefiles]",
-        start_time.clone(),
-    );
-    let step_time = SystemTime::now();
-
-    match config.config.additional_profiles {
-        Some(ref profiles) => profile::set_additional(profiles),
-        None => profile::set_additional(&vec![]),
-    };
+    let (config, normalized_profile_name) = load_makefiles(cli_args, build_file, force_makefile)?;
 
-    let env_info = environment::setup_env(&cli_args, &config, &task, home, &mut time_summary_vec)?;
-    time_summary::add(&mut time_summary_vec, "[Setup Env]", step_time);
-
-    let crate_name = envmnt::get_or("CARGO_MAKE_CRATE_NAME", "");
-    if crate_name.len() > 0 {
-        info!("Project: {}", &crate_name);
+    if cli_args.lint {
+        return cli_commands::lint::run(&mut std::io::stdout(), &config);
     }
-    info!("Build File: {}", &build_file);
-    info!("Task: {}", &task);
-    info!("Profile: {}", &normalized_profile_name);
 
-    // ensure profile env was not overridden
-    profile::set(&normalized_profile_name);
+    let (env_info, time_summary_vec) = setup_flow(
+        cli_args,
+        &config,
+        build_file,
+        &normalized_profile_name,
+        home,
+        start_time,
+        None,
+    )?;
 
     if cli_args.list_all_steps || cli_args.list_category_steps.is_some() {
         cli_commands::list_steps::run(
@@ -158,6 +154,8 @@ pub fn run(
             &env_info.crate_info,
             cli_args.skip_init_end_tasks,
         )
+    } else if cli_args.print_env {
+        cli_commands::print_env::print(&mut std::io::stdout(), &config, task)
     } else {
         runner::run(
             config,
@@ -172,6 +170,98 @@ pub fn run(
     Ok(())
 }
 
+/// Sets the profile and the cli env and loads the makefiles.<br>
+/// Returns the loaded config and the normalized profile name.
+pub(crate) fn load_makefiles(
+    cli_args: &CliArgs,
+    build_file: &str,
+    force_makefile: bool,
+) -> Result<(Config, String), CargoMakeError> {
+    let profile_name = &cli_args
+        .profile
+        .clone()
+        .unwrap_or_else(profile::default_profile);
+    let normalized_profile_name = provenance::track("--profile", || profile::set(profile_name));
+
+    provenance::track("--env-file", || {
+        environment::load_env_file(cli_args.env_file.clone())
+    });
+
+    let env = cli_args.env.clone();
+
+    if cli_args.offline {
+        envmnt::set_bool("CARGO_MAKE_OFFLINE", true);
+    }
+    if cli_args.locked {
+        envmnt::set_bool("CARGO_MAKE_LOCKED", true);
+    }
+    if cli_args.update_lock {
+        envmnt::set_bool("CARGO_MAKE_UPDATE_LOCK", true);
+    }
+
+    let experimental = cli_args.experimental;
+    let config = provenance::track("makefile loading", || {
+        descriptor::load(build_file, force_makefile, env, experimental)
+    })?;
+
+    Ok((config, normalized_profile_name))
+}
+
+/// Sets up the additional profiles, user defined functions and env of the loaded makefiles.<br>
+/// Shared by the cli and the in-process workspace member flows, the cached env info (if
+/// provided) is reused instead of loading the rust, git and CI info again.
+pub(crate) fn setup_flow(
+    cli_args: &CliArgs,
+    config: &Config,
+    build_file: &str,
+    normalized_profile_name: &str,
+    home: Option<PathBuf>,
+    start_time: SystemTime,
+    cached_env_info: Option<&EnvInfo>,
+) -> Result<(EnvInfo, Vec<(String, u128)>), CargoMakeError> {
+    let task = &cli_args.task;
+
+    let mut time_summary_vec = vec![];
+    time_summary::add(&mut time_summary_vec, "[Load Makefiles]", start_time);
+    let step_time = SystemTime::now();
+
+    provenance::track("additional_profiles", || {
+        match config.config.additional_profiles {
+            Some(ref profiles) => profile::set_additional(profiles),
+            None => profile::set_additional(&vec![]),
+        }
+    });
+
+    // user defined functions may be invoked while setting up the env
+    user_functions::register(&config.functions)?;
+
+    let env_info = match cached_env_info {
+        Some(_) => environment::setup_env_with_cache(
+            cli_args,
+            config,
+            task,
+            home,
+            &mut time_summary_vec,
+            cached_env_info,
+        )?,
+        None => environment::setup_env(cli_args, config, task, home, &mut time_summary_vec)?,
+    };
+    time_summary::add(&mut time_summary_vec, "[Setup Env]", step_time);
+
+    let crate_name = envmnt::get_or("CARGO_MAKE_CRATE_NAME", "");
+    if !crate_name.is_empty() {
+        info!("Project: {}", &crate_name);
+    }
+    info!("Build File: {}", &build_file);
+    info!("Task: {}", &task);
+    info!("Profile: {}", &normalized_profile_name);
+
+    // ensure profile env was not overridden
+    provenance::track("--profile", || profile::set(normalized_profile_name));
+
+    Ok((env_info, time_summary_vec))
+}
+
 /// Handles the command line arguments and executes the runner.
 pub fn run_cli(command_name: String, sub_command: bool) -> Result<CliArgs, CargoMakeError> {
     let global_config = config::load()?;
@@ -11,6 +11,7 @@ use crate::command;
 use crate::error::CargoMakeError;
 use crate::execution_plan::ExecutionPlanBuilder;
 use crate::io::{create_file, delete_file};
+use crate::secrets;
 use crate::types::{CliArgs, Config, CrateInfo, ExecutionPlan};
 use regex::Regex;
 use std::fs::File;
@@ -19,7 +20,8 @@ use std::io::{BufWriter, Write};
 
 fn write_as_string(execution_plan: &ExecutionPlan, file: &File) -> io::Result<()> {
     let mut writer = BufWriter::new(file);
-    writeln!(&mut writer, "{:#?}", &execution_plan.steps)
+    let output = format!("{:#?}", &execution_plan.steps);
+    writeln!(&mut writer, "{}", secrets::redact(&output))
 }
 
 /// Runs the execution plan diff
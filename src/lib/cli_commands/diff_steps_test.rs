@@ -9,8 +9,10 @@ fn run_same() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -36,8 +38,10 @@ fn run_different() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -52,8 +56,10 @@ fn run_different() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -77,8 +83,10 @@ fn run_different_with_skip() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -93,8 +101,10 @@ fn run_different_with_skip() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -115,8 +125,10 @@ fn run_missing_task_in_first_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -127,8 +139,10 @@ fn run_missing_task_in_first_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -153,8 +167,10 @@ fn run_missing_task_in_second_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -166,8 +182,10 @@ fn run_missing_task_in_second_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
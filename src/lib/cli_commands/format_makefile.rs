@@ -0,0 +1,312 @@
+//! # format_makefile
+//!
+//! Rewrites a TOML makefile in a canonical form while preserving its comments.<br>
+//! Keys are ordered based on the makefile types field order, arrays are normalized and the
+//! task sections are grouped by their category.
+//!
+
+#[cfg(test)]
+#[path = "format_makefile_test.rs"]
+mod format_makefile_test;
+
+use crate::descriptor::descriptor_deserializer::Format;
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::{ConfigSection, ExternalConfig, PlatformOverrideTask, Task};
+use schemars::JsonSchema;
+use std::path::PathBuf;
+use toml_edit::{Array, Decor, DocumentMut, Item, Table, Value};
+
+static ARRAY_INDENTATION: &str = "  ";
+
+/// Returns the attribute names of the provided type in their definition order
+fn get_field_order<T: JsonSchema>() -> Vec<String> {
+    let schema = schemars::schema_for!(T);
+
+    match schema.schema.object {
+        Some(object) => object.properties.keys().cloned().collect(),
+        None => vec![],
+    }
+}
+
+fn get_rank(order: &[String], key: &str) -> usize {
+    order
+        .iter()
+        .position(|field| field == key)
+        .unwrap_or(order.len())
+}
+
+fn sort_values(table: &mut Table, order: &[String]) {
+    // the sort is stable so unknown keys keep their original order after the known keys
+    table.sort_values_by(|key1, _, key2, _| {
+        get_rank(order, key1.get()).cmp(&get_rank(order, key2.get()))
+    });
+}
+
+fn decor_contains(decor: &Decor, value: char) -> bool {
+    [decor.prefix(), decor.suffix()].iter().any(|raw| {
+        raw.and_then(|raw| raw.as_str())
+            .map(|text| text.contains(value))
+            .unwrap_or(false)
+    })
+}
+
+fn array_contains(array: &Array, value: char) -> bool {
+    array.iter().any(|item| decor_contains(item.decor(), value))
+        || array
+            .trailing()
+            .as_str()
+            .map(|text| text.contains(value))
+            .unwrap_or(false)
+}
+
+fn format_array(array: &mut Array) {
+    for value in array.iter_mut() {
+        format_value(value);
+    }
+
+    // comments are kept in the array whitespace so such arrays are left untouched
+    if array_contains(array, '#') {
+        return;
+    }
+
+    if !array.is_empty() && array_contains(array, '\n') {
+        for value in array.iter_mut() {
+            let decor = value.decor_mut();
+            decor.set_prefix(format!("\n{}", ARRAY_INDENTATION));
+            decor.set_suffix("");
+        }
+        array.set_trailing_comma(true);
+        array.set_trailing("\n");
+    } else {
+        array.fmt();
+    }
+}
+
+fn format_value(value: &mut Value) {
+    match value {
+        Value::Array(array) => format_array(array),
+        Value::InlineTable(table) => {
+            for (_, value) in table.iter_mut() {
+                format_value(value);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn format_values(table: &mut Table) {
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Value(value) => format_value(value),
+            Item::Table(table) => format_values(table),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    format_values(table);
+                }
+            }
+            Item::None => (),
+        }
+    }
+}
+
+fn sort_makefile_values(document: &mut DocumentMut) {
+    let root = document.as_table_mut();
+    sort_values(root, &get_field_order::<ExternalConfig>());
+
+    if let Some(Item::Table(config)) = root.get_mut("config") {
+        sort_values(config, &get_field_order::<ConfigSection>());
+    }
+
+    let task_order = get_field_order::<Task>();
+    let override_order = get_field_order::<PlatformOverrideTask>();
+    for section in ["templates", "tasks"] {
+        if let Some(Item::Table(tasks)) = root.get_mut(section) {
+            for (_, task) in tasks.iter_mut() {
+                if let Item::Table(task) = task {
+                    sort_values(task, &task_order);
+
+                    for platform in ["linux", "windows", "mac"] {
+                        if let Some(Item::Table(override_task)) = task.get_mut(platform) {
+                            sort_values(override_task, &override_order);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the document position of the table header (or of its first sub table header)
+fn get_position(item: &Item) -> usize {
+    match item {
+        Item::Table(table) if !table.is_implicit() && !table.is_dotted() => {
+            table.position().unwrap_or(usize::MAX)
+        }
+        Item::Table(table) => table
+            .iter()
+            .map(|(_, item)| get_position(item))
+            .min()
+            .unwrap_or(usize::MAX),
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .filter_map(|table| table.position())
+            .min()
+            .unwrap_or(usize::MAX),
+        _ => usize::MAX,
+    }
+}
+
+fn get_category(item: &Item) -> Option<String> {
+    item.get("category")
+        .and_then(|category| category.as_str())
+        .map(|category| category.to_string())
+}
+
+/// Returns the sub table keys in their canonical order
+fn get_ordered_sub_tables(table: &Table, path: &[String]) -> Vec<String> {
+    let mut sub_tables: Vec<(usize, usize, String)> = table
+        .iter()
+        .filter(|(_, item)| item.is_table() || item.is_array_of_tables())
+        .map(|(key, item)| (0, get_position(item), key.to_string()))
+        .collect();
+    sub_tables.sort_by_key(|(_, position, _)| *position);
+
+    let order = match path.len() {
+        0 => get_field_order::<ExternalConfig>(),
+        2 if path[0] == "tasks" || path[0] == "templates" => get_field_order::<Task>(),
+        _ => vec![],
+    };
+
+    if path.len() == 1 && path[0] == "tasks" {
+        // tasks are grouped by category, in the order each category first appears
+        let mut categories = vec![];
+        for (rank, _, key) in sub_tables.iter_mut() {
+            let category = table.get(key).and_then(get_category);
+            *rank = match categories.iter().position(|value| *value == category) {
+                Some(index) => index,
+                None => {
+                    categories.push(category);
+                    categories.len() - 1
+                }
+            };
+        }
+    } else if !order.is_empty() {
+        for (rank, _, key) in sub_tables.iter_mut() {
+            *rank = get_rank(&order, key);
+        }
+    }
+
+    sub_tables.sort_by_key(|(rank, position, _)| (*rank, *position));
+    sub_tables.into_iter().map(|(_, _, key)| key).collect()
+}
+
+fn trim_leading_blank_lines(text: &str) -> String {
+    let mut rest = text;
+    while let Some(index) = rest.find('\n') {
+        if rest[..index].trim().is_empty() {
+            rest = &rest[index + 1..];
+        } else {
+            break;
+        }
+    }
+
+    rest.to_string()
+}
+
+fn format_header(table: &mut Table, first_header: &mut bool) {
+    if table.is_implicit() || table.is_dotted() {
+        return;
+    }
+
+    let prefix = table
+        .decor()
+        .prefix()
+        .and_then(|prefix| prefix.as_str())
+        .map(trim_leading_blank_lines)
+        .unwrap_or_default();
+
+    // table headers are separated by a single blank line
+    if *first_header {
+        table.decor_mut().set_prefix(prefix);
+        *first_header = false;
+    } else {
+        table.decor_mut().set_prefix(format!("\n{}", prefix));
+    }
+}
+
+fn format_tables(
+    table: &mut Table,
+    path: &mut Vec<String>,
+    position: &mut usize,
+    first_header: &mut bool,
+) {
+    for key in get_ordered_sub_tables(table, path) {
+        path.push(key.clone());
+
+        match table.get_mut(&key) {
+            Some(Item::Table(sub_table)) => {
+                sub_table.set_position(*position);
+                *position += 1;
+                format_header(sub_table, first_header);
+                format_tables(sub_table, path, position, first_header);
+            }
+            Some(Item::ArrayOfTables(sub_tables)) => {
+                for sub_table in sub_tables.iter_mut() {
+                    sub_table.set_position(*position);
+                    *position += 1;
+                    format_header(sub_table, first_header);
+                    format_tables(sub_table, path, position, first_header);
+                }
+            }
+            _ => (),
+        }
+
+        path.pop();
+    }
+}
+
+/// Returns the provided TOML makefile content in the canonical format
+pub(crate) fn format(content: &str) -> Result<String, String> {
+    let mut document = content
+        .parse::<DocumentMut>()
+        .map_err(|error| error.to_string())?;
+
+    sort_makefile_values(&mut document);
+    format_values(document.as_table_mut());
+
+    let root = document.as_table_mut();
+    let mut first_header = !root.iter().any(|(_, item)| item.is_value());
+    let mut position = 1;
+    format_tables(root, &mut vec![], &mut position, &mut first_header);
+
+    Ok(document.to_string())
+}
+
+/// Formats the provided makefile or, in check mode, fails if the makefile is not formatted
+pub(crate) fn run(makefile: &str, check: bool) -> Result<(), CargoMakeError> {
+    if Format::from_file(makefile) != Format::Toml {
+        return Err(CargoMakeError::ParseFileFailed(
+            makefile.to_string(),
+            "only TOML makefiles can be formatted".to_string(),
+        ));
+    }
+
+    let file_path = PathBuf::from(makefile);
+    let content = io::read_text_file(&file_path)?;
+    let formatted = match format(&content) {
+        Ok(formatted) => formatted,
+        Err(error) => return Err(CargoMakeError::ParseFileFailed(makefile.to_string(), error)),
+    };
+
+    if formatted == content {
+        info!("Makefile: {} is formatted.", makefile);
+        Ok(())
+    } else if check {
+        Err(CargoMakeError::MakefileNotFormatted(makefile.to_string()))
+    } else {
+        fsio::file::write_text_file(&file_path, &formatted)?;
+        info!("Formatted makefile: {}", makefile);
+        Ok(())
+    }
+}
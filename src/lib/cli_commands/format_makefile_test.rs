@@ -0,0 +1,231 @@
+use super::*;
+use crate::test;
+use fsio::file::{read_text_file, write_text_file};
+
+#[test]
+fn get_field_order_task() {
+    let order = get_field_order::<Task>();
+
+    assert!(get_rank(&order, "description") < get_rank(&order, "command"));
+    assert!(get_rank(&order, "command") < get_rank(&order, "dependencies"));
+    assert_eq!(get_rank(&order, "unknown"), order.len());
+}
+
+#[test]
+fn trim_leading_blank_lines_with_comment() {
+    assert_eq!(
+        trim_leading_blank_lines("\n  \n# comment\n\n"),
+        "# comment\n\n"
+    );
+    assert_eq!(trim_leading_blank_lines("\n\n"), "");
+}
+
+#[test]
+fn format_task_key_order() {
+    let output = format(
+        r#"
+[tasks.build]
+# the build command
+command = "cargo"
+args = ["build"]
+description = "Builds"
+custom = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[tasks.build]
+description = "Builds"
+# the build command
+command = "cargo"
+args = ["build"]
+custom = true
+"#
+    );
+}
+
+#[test]
+fn format_root_order() {
+    let output = format(
+        r#"# header comment
+[tasks.build]
+command = "cargo"
+
+[env]
+A = "1"
+
+[config]
+skip_core_tasks = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[config]
+skip_core_tasks = true
+
+[env]
+A = "1"
+
+# header comment
+[tasks.build]
+command = "cargo"
+"#
+    );
+}
+
+#[test]
+fn format_templates() {
+    let output = format(
+        r#"[tasks.build]
+instantiate = { template = "cross-build" }
+
+[templates.cross-build]
+args = ["build"]
+command = "cargo"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[templates.cross-build]
+command = "cargo"
+args = ["build"]
+
+[tasks.build]
+instantiate = { template = "cross-build" }
+"#
+    );
+}
+
+#[test]
+fn format_arrays() {
+    let output = format(
+        r#"[tasks.build]
+args = [ "build",    "--release" ]
+dependencies = ["a",
+    "b"]
+env_files = [
+  # comment
+  "a.env"   ,
+]
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[tasks.build]
+env_files = [
+  # comment
+  "a.env"   ,
+]
+args = ["build", "--release"]
+dependencies = [
+  "a",
+  "b",
+]
+"#
+    );
+}
+
+#[test]
+fn format_tasks_grouped_by_category() {
+    let output = format(
+        r#"[tasks.build]
+category = "Build"
+
+[tasks.test]
+category = "Test"
+
+[tasks.build.env]
+A = "1"
+
+[tasks.clean]
+category = "Build"
+[tasks.other]
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[tasks.build]
+category = "Build"
+
+[tasks.build.env]
+A = "1"
+
+[tasks.clean]
+category = "Build"
+
+[tasks.test]
+category = "Test"
+
+[tasks.other]
+"#
+    );
+}
+
+#[test]
+fn format_idempotent() {
+    let output = format(include_str!("../descriptor/makefiles/stable.toml")).unwrap();
+
+    assert_eq!(format(&output).unwrap(), output);
+}
+
+#[test]
+fn format_invalid() {
+    let output = format("[tasks.build");
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn run_check_not_formatted() {
+    let directory = test::get_temp_test_directory("format_makefile_run_check_not_formatted");
+    let makefile = directory.join("Makefile.toml");
+    write_text_file(&makefile, "[tasks.build]\nargs = [ \"build\" ]\n").unwrap();
+    let makefile_string = makefile.to_string_lossy().to_string();
+
+    let output = run(&makefile_string, true);
+
+    match output {
+        Err(CargoMakeError::MakefileNotFormatted(file)) => assert_eq!(file, makefile_string),
+        _ => panic!("expected not formatted error"),
+    }
+    assert_eq!(
+        read_text_file(&makefile).unwrap(),
+        "[tasks.build]\nargs = [ \"build\" ]\n"
+    );
+}
+
+#[test]
+fn run_write() {
+    let directory = test::get_temp_test_directory("format_makefile_run_write");
+    let makefile = directory.join("Makefile.toml");
+    write_text_file(&makefile, "[tasks.build]\nargs = [ \"build\" ]\n").unwrap();
+    let makefile_string = makefile.to_string_lossy().to_string();
+
+    run(&makefile_string, false).unwrap();
+
+    assert_eq!(
+        read_text_file(&makefile).unwrap(),
+        "[tasks.build]\nargs = [\"build\"]\n"
+    );
+    run(&makefile_string, true).unwrap();
+}
+
+#[test]
+fn run_not_toml() {
+    let output = run("Makefile.yaml", false);
+
+    match output {
+        Err(CargoMakeError::ParseFileFailed(file, _)) => assert_eq!(file, "Makefile.yaml"),
+        _ => panic!("expected parse error"),
+    }
+}
@@ -0,0 +1,370 @@
+//! # lint
+//!
+//! Statically validates the loaded makefiles and reports all found problems.<br>
+//! Only tasks defined in the user makefiles are validated, while references are resolved
+//! against all loaded tasks (including the core tasks).
+//!
+
+#[cfg(test)]
+#[path = "lint_test.rs"]
+mod lint_test;
+
+use crate::descriptor::descriptor_deserializer;
+use crate::descriptor::imports::{self, TaskCollision};
+use crate::error::CargoMakeError;
+use crate::types::{
+    Config, DependencyIdentifier, EnvValue, LintLevel, RunTaskInfo, RunTaskName, Task,
+};
+use indexmap::IndexSet;
+use std::io;
+
+/// All lint rules and their default severity
+pub(crate) static RULES: [(&str, LintLevel); 8] = [
+    ("unknown-key", LintLevel::Warning),
+    ("missing-dependency", LintLevel::Error),
+    ("missing-alias", LintLevel::Error),
+    ("missing-run-task", LintLevel::Error),
+    ("missing-extend", LintLevel::Error),
+    ("multiple-actions", LintLevel::Error),
+    ("unused-private-task", LintLevel::Warning),
+    ("task-collision", LintLevel::Warning),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single problem found in the makefiles
+pub(crate) struct LintProblem {
+    /// The lint rule name
+    pub(crate) rule: &'static str,
+    /// The problem severity
+    pub(crate) level: LintLevel,
+    /// The makefile in which the problem was found
+    pub(crate) file: String,
+    /// The task in which the problem was found (if relevant)
+    pub(crate) task: Option<String>,
+    /// The problem description
+    pub(crate) message: String,
+}
+
+/// A task name referenced by another task
+struct TaskReference {
+    rule: &'static str,
+    attribute: String,
+    name: String,
+}
+
+fn get_level(config: &Config, rule: &str) -> LintLevel {
+    let level = match config.config.lint_levels {
+        Some(ref lint_levels) => lint_levels.get(rule).cloned(),
+        None => None,
+    };
+
+    match level {
+        Some(level) => level,
+        None => RULES
+            .iter()
+            .find(|(name, _)| *name == rule)
+            .map(|(_, level)| *level)
+            .unwrap_or(LintLevel::Error),
+    }
+}
+
+/// Returns the user makefile in which the task was defined (none for core tasks)
+fn get_makefile(task: &Task) -> Option<String> {
+    match task.env {
+        Some(ref env) => match env.get("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE") {
+            Some(EnvValue::Value(file)) => Some(file.to_string()),
+            _ => None,
+        },
+        None => None,
+    }
+}
+
+fn add_run_task_names(references: &mut Vec<TaskReference>, prefix: &str, run_task: &RunTaskInfo) {
+    let mut add = |attribute: &str, name: &str| {
+        references.push(TaskReference {
+            rule: "missing-run-task",
+            attribute: format!("{}{}", prefix, attribute),
+            name: name.to_string(),
+        })
+    };
+
+    let mut add_details = |name: &RunTaskName, cleanup_task: &Option<String>| {
+        match name {
+            RunTaskName::Single(name) => add("run_task", name),
+            RunTaskName::Multiple(names) => {
+                for name in names {
+                    add("run_task", name);
+                }
+            }
+        }
+
+        if let Some(cleanup_task) = cleanup_task {
+            add("run_task.cleanup_task", cleanup_task);
+        }
+    };
+
+    match run_task {
+        RunTaskInfo::Name(name) => add_details(&RunTaskName::Single(name.to_string()), &None),
+        RunTaskInfo::Details(details) => add_details(&details.name, &details.cleanup_task),
+        RunTaskInfo::Routing(routing_info) => {
+            for info in routing_info {
+                add_details(&info.name, &info.cleanup_task);
+            }
+        }
+    }
+}
+
+fn add_references(
+    references: &mut Vec<TaskReference>,
+    prefix: &str,
+    dependencies: &Option<Vec<DependencyIdentifier>>,
+    run_task: &Option<RunTaskInfo>,
+    extend: &Option<String>,
+) {
+    if let Some(dependencies) = dependencies {
+        for dependency in dependencies {
+            let name = match dependency {
+                DependencyIdentifier::Name(name) => name,
+                // dependencies from other makefiles are resolved only at runtime
+                DependencyIdentifier::Definition(identifier) if identifier.path.is_some() => {
+                    continue
+                }
+                DependencyIdentifier::Definition(identifier) => &identifier.name,
+            };
+
+            references.push(TaskReference {
+                rule: "missing-dependency",
+                attribute: format!("{}dependencies", prefix),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    if let Some(run_task) = run_task {
+        add_run_task_names(references, prefix, run_task);
+    }
+
+    if let Some(extend) = extend {
+        references.push(TaskReference {
+            rule: "missing-extend",
+            attribute: format!("{}extend", prefix),
+            name: extend.to_string(),
+        });
+    }
+}
+
+fn get_references(task: &Task) -> Vec<TaskReference> {
+    let mut references = vec![];
+
+    add_references(
+        &mut references,
+        "",
+        &task.dependencies,
+        &task.run_task,
+        &task.extend,
+    );
+
+    let overrides = [
+        ("linux.", &task.linux),
+        ("windows.", &task.windows),
+        ("mac.", &task.mac),
+    ];
+    for (prefix, override_task) in overrides {
+        if let Some(override_task) = override_task {
+            add_references(
+                &mut references,
+                prefix,
+                &override_task.dependencies,
+                &override_task.run_task,
+                &override_task.extend,
+            );
+        }
+    }
+
+    let aliases = [
+        ("alias", &task.alias),
+        ("linux_alias", &task.linux_alias),
+        ("windows_alias", &task.windows_alias),
+        ("mac_alias", &task.mac_alias),
+    ];
+    for (attribute, alias) in aliases {
+        if let Some(alias) = alias {
+            references.push(TaskReference {
+                rule: "missing-alias",
+                attribute: attribute.to_string(),
+                name: alias.to_string(),
+            });
+        }
+    }
+
+    references
+}
+
+/// Returns all problems found in the provided config, unknown keys (file and key path) and task collisions
+pub(crate) fn get_problems(
+    config: &Config,
+    unknown_keys: &[(String, String)],
+    collisions: &[TaskCollision],
+) -> Vec<LintProblem> {
+    let mut problems = vec![];
+    let mut add = |rule: &'static str, file: &str, task: Option<&str>, message: String| {
+        let level = get_level(config, rule);
+        if level != LintLevel::Off {
+            problems.push(LintProblem {
+                rule,
+                level,
+                file: file.to_string(),
+                task: task.map(|task| task.to_string()),
+                message,
+            });
+        }
+    };
+
+    for (file, key) in unknown_keys {
+        add("unknown-key", file, None, format!("unknown key: {}", key));
+    }
+
+    for collision in collisions {
+        add(
+            "task-collision",
+            &collision.file,
+            Some(&collision.task),
+            format!(
+                "overrides the task defined in: {}",
+                collision.overridden_file
+            ),
+        );
+    }
+
+    let mut referenced_tasks = IndexSet::new();
+    let config_tasks = [
+        &config.config.init_task,
+        &config.config.end_task,
+        &config.config.on_error_task,
+        &config.config.legacy_migration_task,
+    ];
+    for task_name in config_tasks.into_iter().flatten() {
+        referenced_tasks.insert(task_name.to_string());
+    }
+
+    for (task_name, task) in &config.tasks {
+        let makefile = get_makefile(task);
+
+        for reference in get_references(task) {
+            if reference.name != *task_name {
+                referenced_tasks.insert(reference.name.clone());
+            }
+
+            if let Some(ref makefile) = makefile {
+                if !config.tasks.contains_key(&reference.name) {
+                    add(
+                        reference.rule,
+                        makefile,
+                        Some(task_name),
+                        format!("{}: {} does not exist", reference.attribute, reference.name),
+                    );
+                }
+            }
+        }
+
+        if let Some(ref makefile) = makefile {
+            if !task.is_valid() {
+                add(
+                    "multiple-actions",
+                    makefile,
+                    Some(task_name),
+                    "only one of run_task, command or script can be defined".to_string(),
+                );
+            }
+        }
+    }
+
+    for (task_name, task) in &config.tasks {
+        if let Some(ref makefile) = get_makefile(task) {
+            if task.private.unwrap_or(false) && !referenced_tasks.contains(task_name) {
+                add(
+                    "unused-private-task",
+                    makefile,
+                    Some(task_name),
+                    "private task is not referenced by any other task".to_string(),
+                );
+            }
+        }
+    }
+
+    problems
+}
+
+fn format_problem(problem: &LintProblem) -> String {
+    let level = match problem.level {
+        LintLevel::Error => "error",
+        LintLevel::Warning => "warning",
+        LintLevel::Off => "off",
+    };
+
+    match problem.task {
+        Some(ref task) => format!(
+            "{}[{}]: {} task: {} - {}",
+            level, problem.rule, problem.file, task, problem.message
+        ),
+        None => format!(
+            "{}[{}]: {} - {}",
+            level, problem.rule, problem.file, problem.message
+        ),
+    }
+}
+
+fn lint(
+    output_buffer: &mut impl io::Write,
+    config: &Config,
+    unknown_keys: &[(String, String)],
+    collisions: &[TaskCollision],
+) -> Result<(), CargoMakeError> {
+    let problems = get_problems(config, unknown_keys, collisions);
+
+    for problem in &problems {
+        writeln!(output_buffer, "{}", format_problem(problem))?;
+    }
+
+    let errors = problems
+        .iter()
+        .filter(|problem| problem.level == LintLevel::Error)
+        .count();
+    let warnings = problems.len() - errors;
+
+    if problems.is_empty() {
+        writeln!(output_buffer, "No lint problems found.")?;
+    } else {
+        writeln!(
+            output_buffer,
+            "Found {} error(s) and {} warning(s).",
+            errors, warnings
+        )?;
+    }
+
+    if errors > 0 {
+        Err(CargoMakeError::LintFailed(errors))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates the loaded makefiles, prints all found problems and fails if any error level
+/// problem was found
+pub(crate) fn run(
+    output_buffer: &mut impl io::Write,
+    config: &Config,
+) -> Result<(), CargoMakeError> {
+    // use the same absolute makefile paths as the task locations
+    let unknown_keys: Vec<(String, String)> = descriptor_deserializer::get_unknown_keys()
+        .into_iter()
+        .map(|(file, key)| (crate::io::canonicalize_to_string(&file), key))
+        .collect();
+
+    lint(
+        output_buffer,
+        config,
+        &unknown_keys,
+        &imports::get_collisions(),
+    )
+}
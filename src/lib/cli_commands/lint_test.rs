@@ -0,0 +1,289 @@
+use super::*;
+use crate::types::{
+    ConfigSection, PlatformOverrideTask, RunTaskDetails, RunTaskRoutingInfo, TaskIdentifier,
+};
+use indexmap::IndexMap;
+
+fn create_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    }
+}
+
+fn create_user_task() -> Task {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("Makefile.toml".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.env = Some(env);
+    task
+}
+
+fn get_messages(problems: &[LintProblem]) -> Vec<String> {
+    problems.iter().map(format_problem).collect()
+}
+
+#[test]
+fn get_level_default() {
+    let config = create_config();
+
+    assert_eq!(get_level(&config, "unknown-key"), LintLevel::Warning);
+    assert_eq!(get_level(&config, "missing-dependency"), LintLevel::Error);
+}
+
+#[test]
+fn get_level_overridden() {
+    let mut config = create_config();
+    let mut lint_levels = IndexMap::new();
+    lint_levels.insert("unknown-key".to_string(), LintLevel::Error);
+    config.config.lint_levels = Some(lint_levels);
+
+    assert_eq!(get_level(&config, "unknown-key"), LintLevel::Error);
+    assert_eq!(
+        get_level(&config, "unused-private-task"),
+        LintLevel::Warning
+    );
+}
+
+#[test]
+fn get_problems_none() {
+    let mut config = create_config();
+    let mut task = create_user_task();
+    task.dependencies = Some(vec!["core".into()]);
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("core".to_string(), Task::new());
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn get_problems_unknown_keys() {
+    let config = create_config();
+
+    let problems = get_problems(
+        &config,
+        &[("Makefile.toml".to_string(), "tasks.test.commnd".to_string())],
+        &[],
+    );
+
+    assert_eq!(
+        get_messages(&problems),
+        vec!["warning[unknown-key]: Makefile.toml - unknown key: tasks.test.commnd"]
+    );
+}
+
+#[test]
+fn get_problems_task_collisions() {
+    let config = create_config();
+
+    let problems = get_problems(
+        &config,
+        &[],
+        &[TaskCollision {
+            task: "build".to_string(),
+            file: "Makefile.toml".to_string(),
+            overridden_file: "shared.toml".to_string(),
+        }],
+    );
+
+    assert_eq!(
+        get_messages(&problems),
+        vec!["warning[task-collision]: Makefile.toml task: build - overrides the task defined in: shared.toml"]
+    );
+}
+
+#[test]
+fn get_problems_missing_references() {
+    let mut config = create_config();
+    let mut task = create_user_task();
+    task.dependencies = Some(vec![
+        "missing1".into(),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "missing2".to_string(),
+            path: None,
+        }),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "other".to_string(),
+            path: Some("other.toml".to_string()),
+        }),
+    ]);
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(vec!["missing3".to_string()]),
+        fork: None,
+        parallel: None,
+        cleanup_task: Some("missing4".to_string()),
+    }));
+    task.extend = Some("missing5".to_string());
+    task.alias = Some("missing6".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert_eq!(
+        get_messages(&problems),
+        vec![
+            "error[missing-dependency]: Makefile.toml task: test - dependencies: missing1 does not exist",
+            "error[missing-dependency]: Makefile.toml task: test - dependencies: missing2 does not exist",
+            "error[missing-run-task]: Makefile.toml task: test - run_task: missing3 does not exist",
+            "error[missing-run-task]: Makefile.toml task: test - run_task.cleanup_task: missing4 does not exist",
+            "error[missing-extend]: Makefile.toml task: test - extend: missing5 does not exist",
+            "error[missing-alias]: Makefile.toml task: test - alias: missing6 does not exist",
+        ]
+    );
+}
+
+#[test]
+fn get_problems_missing_platform_references() {
+    let mut config = create_config();
+    let mut task = create_user_task();
+    let mut override_task: PlatformOverrideTask = serde_json::from_str("{}").unwrap();
+    override_task.run_task = Some(RunTaskInfo::Routing(vec![RunTaskRoutingInfo {
+        name: RunTaskName::Single("missing1".to_string()),
+        fork: None,
+        parallel: None,
+        cleanup_task: None,
+        condition: None,
+        condition_script: None,
+        condition_script_runner_args: None,
+    }]));
+    task.linux = Some(override_task);
+    task.windows_alias = Some("missing2".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert_eq!(
+        get_messages(&problems),
+        vec![
+            "error[missing-run-task]: Makefile.toml task: test - linux.run_task: missing1 does not exist",
+            "error[missing-alias]: Makefile.toml task: test - windows_alias: missing2 does not exist",
+        ]
+    );
+}
+
+#[test]
+fn get_problems_core_tasks_ignored() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["missing".into()]);
+    task.private = Some(true);
+    task.command = Some("echo".to_string());
+    task.script = Some(crate::types::ScriptValue::Text(vec!["echo".to_string()]));
+    config.tasks.insert("core".to_string(), task);
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn get_problems_multiple_actions() {
+    let mut config = create_config();
+    let mut task = create_user_task();
+    task.command = Some("echo".to_string());
+    task.run_task = Some(RunTaskInfo::Name("other".to_string()));
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("other".to_string(), Task::new());
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert_eq!(
+        get_messages(&problems),
+        vec!["error[multiple-actions]: Makefile.toml task: test - only one of run_task, command or script can be defined"]
+    );
+}
+
+#[test]
+fn get_problems_unused_private_task() {
+    let mut config = create_config();
+    let mut used = create_user_task();
+    used.private = Some(true);
+    let mut init = create_user_task();
+    init.private = Some(true);
+    let mut unused = create_user_task();
+    unused.private = Some(true);
+    // self references do not count as usage
+    unused.alias = Some("unused".to_string());
+    let mut task = create_user_task();
+    task.dependencies = Some(vec!["used".into()]);
+    config.tasks.insert("used".to_string(), used);
+    config.tasks.insert("init".to_string(), init);
+    config.tasks.insert("unused".to_string(), unused);
+    config.tasks.insert("test".to_string(), task);
+    config.config.init_task = Some("init".to_string());
+
+    let problems = get_problems(&config, &[], &[]);
+
+    assert_eq!(
+        get_messages(&problems),
+        vec!["warning[unused-private-task]: Makefile.toml task: unused - private task is not referenced by any other task"]
+    );
+}
+
+#[test]
+fn get_problems_level_off() {
+    let mut config = create_config();
+    let mut lint_levels = IndexMap::new();
+    lint_levels.insert("missing-dependency".to_string(), LintLevel::Off);
+    lint_levels.insert("unknown-key".to_string(), LintLevel::Error);
+    config.config.lint_levels = Some(lint_levels);
+    let mut task = create_user_task();
+    task.dependencies = Some(vec!["missing".into()]);
+    config.tasks.insert("test".to_string(), task);
+
+    let problems = get_problems(
+        &config,
+        &[("Makefile.toml".to_string(), "bad".to_string())],
+        &[],
+    );
+
+    assert_eq!(
+        get_messages(&problems),
+        vec!["error[unknown-key]: Makefile.toml - unknown key: bad"]
+    );
+}
+
+#[test]
+fn lint_no_problems() {
+    let config = create_config();
+
+    let mut output = vec![];
+    lint(&mut output, &config, &[], &[]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "No lint problems found.\n"
+    );
+}
+
+#[test]
+fn lint_errors() {
+    let mut config = create_config();
+    let mut task = create_user_task();
+    task.dependencies = Some(vec!["missing".into()]);
+    task.private = Some(true);
+    config.tasks.insert("test".to_string(), task);
+
+    let mut output = vec![];
+    let result = lint(&mut output, &config, &[], &[]);
+
+    match result {
+        Err(CargoMakeError::LintFailed(errors)) => assert_eq!(errors, 1),
+        _ => panic!("expected lint error"),
+    }
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .ends_with("Found 1 error(s) and 1 warning(s).\n"));
+}
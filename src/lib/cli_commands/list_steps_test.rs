@@ -50,8 +50,10 @@ fn run_empty() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -75,8 +77,10 @@ fn run_all_public() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -116,8 +120,10 @@ fn run_all_public_hide_uninteresting() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -162,8 +168,10 @@ fn run_aliases() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -200,8 +208,10 @@ fn run_all_public_markdown() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -238,8 +248,10 @@ fn run_all_public_markdown_sub_section() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -276,8 +288,10 @@ fn run_all_public_markdown_single_page() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -318,8 +332,10 @@ fn run_all_private() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -352,8 +368,10 @@ fn run_mixed() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
@@ -391,8 +409,10 @@ fn run_write_to_file() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     let file = "./target/_temp/tasklist.md";
@@ -438,8 +458,10 @@ fn run_category_public() {
         env_files: vec![],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     check(
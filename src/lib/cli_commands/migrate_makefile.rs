@@ -0,0 +1,332 @@
+//! # migrate_makefile
+//!
+//! Rewrites the deprecated attributes and deprecated core task references of a TOML makefile
+//! with their replacements while preserving its formatting and comments.<br>
+//! The deprecated attributes are taken from the legacy deprecations table and the deprecated
+//! core tasks from the deprecated attribute of the internal makefiles tasks.
+//!
+
+#[cfg(test)]
+#[path = "migrate_makefile_test.rs"]
+mod migrate_makefile_test;
+
+use crate::command;
+use crate::descriptor;
+use crate::descriptor::descriptor_deserializer::Format;
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::io::{create_text_file, delete_file};
+use crate::legacy::{self, DEPRECATIONS};
+use indexmap::IndexMap;
+use std::path::PathBuf;
+use toml_edit::{DocumentMut, InlineTable, Item, Key, Table, TableLike, Value};
+
+fn migrate_task_name(value: &mut Value, task_replacements: &IndexMap<String, String>) {
+    let replacement = value
+        .as_str()
+        .and_then(|name| task_replacements.get(name))
+        .cloned();
+
+    if let Some(replacement) = replacement {
+        info!(
+            "Replacing deprecated task: {} with: {}",
+            value.as_str().unwrap_or_default(),
+            replacement
+        );
+
+        let decor = value.decor().clone();
+        *value = Value::from(replacement.as_str());
+        *value.decor_mut() = decor;
+    }
+}
+
+fn migrate_task_names(value: &mut Value, task_replacements: &IndexMap<String, String>) {
+    match value {
+        Value::Array(names) => {
+            for name in names.iter_mut() {
+                migrate_task_name(name, task_replacements);
+            }
+        }
+        _ => migrate_task_name(value, task_replacements),
+    }
+}
+
+fn migrate_value(
+    table: &mut dyn TableLike,
+    key: &str,
+    task_replacements: &IndexMap<String, String>,
+) {
+    if let Some(value) = table.get_mut(key).and_then(|item| item.as_value_mut()) {
+        migrate_task_names(value, task_replacements);
+    }
+}
+
+fn migrate_run_task_info(info: &mut dyn TableLike, task_replacements: &IndexMap<String, String>) {
+    migrate_value(info, "name", task_replacements);
+    migrate_value(info, "cleanup_task", task_replacements);
+}
+
+fn migrate_run_task(item: &mut Item, task_replacements: &IndexMap<String, String>) {
+    match item {
+        Item::Value(Value::Array(routing)) => {
+            for value in routing.iter_mut() {
+                if let Some(info) = value.as_inline_table_mut() {
+                    migrate_run_task_info(info, task_replacements);
+                }
+            }
+        }
+        Item::Value(Value::InlineTable(info)) => migrate_run_task_info(info, task_replacements),
+        Item::Value(value) => migrate_task_name(value, task_replacements),
+        Item::Table(info) => migrate_run_task_info(info, task_replacements),
+        Item::ArrayOfTables(routing) => {
+            for info in routing.iter_mut() {
+                migrate_run_task_info(info, task_replacements);
+            }
+        }
+        Item::None => (),
+    }
+}
+
+fn migrate_dependencies(item: &mut Item, task_replacements: &IndexMap<String, String>) {
+    if let Some(dependencies) = item.as_array_mut() {
+        for dependency in dependencies.iter_mut() {
+            match dependency.as_inline_table_mut() {
+                // dependencies from other makefiles do not reference the core tasks
+                Some(identifier) if !identifier.contains_key("path") => {
+                    migrate_value(identifier, "name", task_replacements)
+                }
+                Some(_) => (),
+                None => migrate_task_name(dependency, task_replacements),
+            }
+        }
+    }
+}
+
+fn migrate_references(task: &mut dyn TableLike, task_replacements: &IndexMap<String, String>) {
+    for key in [
+        "extend",
+        "alias",
+        "linux_alias",
+        "windows_alias",
+        "mac_alias",
+    ] {
+        migrate_value(task, key, task_replacements);
+    }
+
+    if let Some(dependencies) = task.get_mut("dependencies") {
+        migrate_dependencies(dependencies, task_replacements);
+    }
+
+    if let Some(run_task) = task.get_mut("run_task") {
+        migrate_run_task(run_task, task_replacements);
+    }
+}
+
+/// Returns the key and all keys which come after it
+fn get_trailing_keys<'a>(keys: impl Iterator<Item = &'a str>, key: &str) -> Vec<String> {
+    keys.skip_while(|current| *current != key)
+        .map(|current| current.to_string())
+        .collect()
+}
+
+fn create_key(key: &Key, name: &str, replacement: &str) -> Key {
+    if key.get() == name {
+        Key::new(replacement).with_leaf_decor(key.leaf_decor().clone())
+    } else {
+        key.clone()
+    }
+}
+
+fn rename_table_key(table: &mut Table, name: &str, replacement: &str) {
+    // the renamed key and all following keys are reinserted to keep the original order
+    let keys = get_trailing_keys(table.iter().map(|(key, _)| key), name);
+    let entries: Vec<(Key, Item)> = keys
+        .iter()
+        .filter_map(|key| table.remove_entry(key))
+        .collect();
+
+    for (key, item) in entries {
+        table.insert_formatted(&create_key(&key, name, replacement), item);
+    }
+}
+
+fn rename_inline_table_key(table: &mut InlineTable, name: &str, replacement: &str) {
+    let keys = get_trailing_keys(table.iter().map(|(key, _)| key), name);
+    let entries: Vec<(Key, Value)> = keys
+        .iter()
+        .filter_map(|key| table.remove_entry(key))
+        .collect();
+
+    for (key, value) in entries {
+        table.insert_formatted(&create_key(&key, name, replacement), value);
+    }
+}
+
+fn migrate_attributes(task: &mut Item) {
+    for deprecation in DEPRECATIONS {
+        let replacement = deprecation.replacement;
+
+        let (defined, replacement_defined) = match task.as_table_like() {
+            Some(table) => (
+                table.contains_key(deprecation.name),
+                table.contains_key(replacement),
+            ),
+            None => (false, false),
+        };
+
+        if !defined {
+            continue;
+        }
+
+        if replacement_defined {
+            // the replacement attribute already takes precedence over the deprecated one
+            info!(
+                "Removing deprecated attribute: {} as: {} is already defined",
+                deprecation.name, replacement
+            );
+
+            if let Some(table) = task.as_table_like_mut() {
+                table.remove(deprecation.name);
+            }
+        } else {
+            info!(
+                "Replacing deprecated attribute: {} with: {}",
+                deprecation.name, replacement
+            );
+
+            match task {
+                Item::Table(table) => rename_table_key(table, deprecation.name, replacement),
+                Item::Value(Value::InlineTable(table)) => {
+                    rename_inline_table_key(table, deprecation.name, replacement)
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+fn migrate_task(task: &mut Item, task_replacements: &IndexMap<String, String>) {
+    migrate_attributes(task);
+
+    if let Some(table) = task.as_table_like_mut() {
+        migrate_references(table, task_replacements);
+
+        for platform in ["linux", "windows", "mac"] {
+            if let Some(override_task) = table.get_mut(platform) {
+                migrate_attributes(override_task);
+
+                if let Some(override_table) = override_task.as_table_like_mut() {
+                    migrate_references(override_table, task_replacements);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the provided TOML makefile content with all deprecations replaced.<br>
+/// The deprecated core tasks are provided as a map of the deprecated task name to its replacement.
+pub(crate) fn migrate(
+    content: &str,
+    deprecated_tasks: &IndexMap<String, String>,
+) -> Result<String, String> {
+    let mut document = content
+        .parse::<DocumentMut>()
+        .map_err(|error| error.to_string())?;
+    let root = document.as_table_mut();
+
+    // deprecated core tasks overridden by the makefile are no longer the deprecated tasks
+    let task_replacements: IndexMap<String, String> =
+        match root.get("tasks").and_then(Item::as_table_like) {
+            Some(tasks) => deprecated_tasks
+                .iter()
+                .filter(|(name, _)| !tasks.contains_key(name))
+                .map(|(name, replacement)| (name.to_string(), replacement.to_string()))
+                .collect(),
+            None => deprecated_tasks.clone(),
+        };
+
+    for section in ["templates", "tasks"] {
+        if let Some(tasks) = root.get_mut(section).and_then(Item::as_table_like_mut) {
+            for (_, task) in tasks.iter_mut() {
+                migrate_task(task, &task_replacements);
+            }
+        }
+    }
+
+    if let Some(config) = root.get_mut("config").and_then(Item::as_table_like_mut) {
+        for key in [
+            "init_task",
+            "end_task",
+            "on_error_task",
+            "legacy_migration_task",
+        ] {
+            migrate_value(config, key, &task_replacements);
+        }
+    }
+
+    Ok(document.to_string())
+}
+
+/// Prints the diff between the makefile and the migrated makefile using the provided git command
+/// and returns false in case the diff could not be printed (for example git is not installed)
+fn print_diff(git: &str, makefile: &str, migrated_file: &str) -> Result<bool, CargoMakeError> {
+    let exit_code = command::run_command(
+        git,
+        &Some(vec![
+            "--no-pager".to_string(),
+            "diff".to_string(),
+            "--no-index".to_string(),
+            makefile.to_string(),
+            migrated_file.to_string(),
+        ]),
+        false,
+    )?;
+
+    // git diff exits with 1 when differences are found
+    Ok(exit_code == 0 || exit_code == 1)
+}
+
+/// Prints the diff of the migrated makefile and, in write mode, updates the makefile
+pub(crate) fn run(makefile: &str, write: bool) -> Result<(), CargoMakeError> {
+    if Format::from_file(makefile) != Format::Toml {
+        return Err(CargoMakeError::ParseFileFailed(
+            makefile.to_string(),
+            "only TOML makefiles can be migrated".to_string(),
+        ));
+    }
+
+    let file_path = PathBuf::from(makefile);
+    let content = io::read_text_file(&file_path)?;
+    let core_config = descriptor::load_internal_descriptors(true, false, None)?;
+    let deprecated_tasks = legacy::get_task_replacements(&core_config.tasks);
+    let migrated = match migrate(&content, &deprecated_tasks) {
+        Ok(migrated) => migrated,
+        Err(error) => return Err(CargoMakeError::ParseFileFailed(makefile.to_string(), error)),
+    };
+
+    if migrated == content {
+        info!("Makefile: {} does not contain deprecations.", makefile);
+        return Ok(());
+    }
+
+    let migrated_file = create_text_file(&migrated, "toml")?;
+
+    info!("Printing diff...");
+    let diff_printed = print_diff("git", makefile, &migrated_file);
+
+    delete_file(&migrated_file);
+
+    if !diff_printed? {
+        warn!("Unable to print the diff using git, printing the migrated makefile instead.");
+        println!("{}", &migrated);
+    }
+
+    if write {
+        fsio::file::write_text_file(&file_path, &migrated)?;
+        info!("Migrated makefile: {}", makefile);
+    } else {
+        info!("Run with --migrate --write to update the makefile.");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use super::*;
+use crate::test;
+use fsio::file::{read_text_file, write_text_file};
+
+fn migrate_core(content: &str) -> Result<String, String> {
+    let core_config = descriptor::load_internal_descriptors(true, false, None).unwrap();
+    let deprecated_tasks = legacy::get_task_replacements(&core_config.tasks);
+
+    migrate(content, &deprecated_tasks)
+}
+
+#[test]
+fn migrate_no_deprecations() {
+    let content = r#"# comment
+[tasks.build]
+ignore_errors = true
+dependencies = ["clippy-ci-flow"]
+"#;
+
+    let output = migrate_core(content).unwrap();
+
+    assert_eq!(output, content);
+}
+
+#[test]
+fn migrate_attribute_keeps_order_and_comments() {
+    let output = migrate_core(
+        r#"[tasks.build]
+command = "cargo"
+# do not fail the flow
+force   = true # inline comment
+args = ["build"]
+
+[tasks.build.linux]
+force = false
+
+[tasks.test]
+windows = { force = true, command = "test.exe" }
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[tasks.build]
+command = "cargo"
+# do not fail the flow
+ignore_errors   = true # inline comment
+args = ["build"]
+
+[tasks.build.linux]
+ignore_errors = false
+
+[tasks.test]
+windows = { ignore_errors = true, command = "test.exe" }
+"#
+    );
+}
+
+#[test]
+fn migrate_attribute_replacement_defined() {
+    let output = migrate_core(
+        r#"[tasks.build]
+force = true
+ignore_errors = false
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[tasks.build]
+ignore_errors = false
+"#
+    );
+}
+
+#[test]
+fn migrate_task_references() {
+    let output = migrate_core(
+        r#"[config]
+init_task = "conditioned-clippy"
+
+[tasks.ci]
+dependencies = [
+  "conditioned-check-format", # format
+  { name = "conditioned-clippy" },
+  { name = "conditioned-clippy", path = "other.toml" },
+]
+alias = "conditioned-clippy"
+
+[tasks.flow]
+run_task = { name = ["build", "conditioned-clippy"], cleanup_task = "conditioned-check-format" }
+
+[tasks.routing]
+run_task = [{ name = "conditioned-clippy" }]
+
+[tasks.single]
+run_task = "conditioned-check-format"
+linux = { extend = "conditioned-clippy" }
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        r#"[config]
+init_task = "clippy-ci-flow"
+
+[tasks.ci]
+dependencies = [
+  "check-format-ci-flow", # format
+  { name = "clippy-ci-flow" },
+  { name = "conditioned-clippy", path = "other.toml" },
+]
+alias = "clippy-ci-flow"
+
+[tasks.flow]
+run_task = { name = ["build", "clippy-ci-flow"], cleanup_task = "check-format-ci-flow" }
+
+[tasks.routing]
+run_task = [{ name = "clippy-ci-flow" }]
+
+[tasks.single]
+run_task = "check-format-ci-flow"
+linux = { extend = "clippy-ci-flow" }
+"#
+    );
+}
+
+#[test]
+fn migrate_overridden_deprecated_task() {
+    let content = r#"[tasks.conditioned-clippy]
+command = "cargo"
+
+[tasks.ci]
+dependencies = ["conditioned-clippy"]
+"#;
+
+    let output = migrate_core(content).unwrap();
+
+    assert_eq!(output, content);
+}
+
+#[test]
+fn migrate_provided_deprecated_tasks() {
+    let mut deprecated_tasks = IndexMap::new();
+    deprecated_tasks.insert("old-task".to_string(), "new-task".to_string());
+
+    let output = migrate(
+        "[tasks.ci]\ndependencies = [\"old-task\", \"conditioned-clippy\"]\n",
+        &deprecated_tasks,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        "[tasks.ci]\ndependencies = [\"new-task\", \"conditioned-clippy\"]\n"
+    );
+}
+
+#[test]
+fn migrate_invalid() {
+    let output = migrate_core("[tasks.build");
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn run_diff_only() {
+    let directory = test::get_temp_test_directory("migrate_makefile_run_diff_only");
+    let makefile = directory.join("Makefile.toml");
+    write_text_file(&makefile, "[tasks.build]\nforce = true\n").unwrap();
+    let makefile_string = makefile.to_string_lossy().to_string();
+
+    run(&makefile_string, false).unwrap();
+
+    assert_eq!(
+        read_text_file(&makefile).unwrap(),
+        "[tasks.build]\nforce = true\n"
+    );
+}
+
+#[test]
+fn run_write() {
+    let directory = test::get_temp_test_directory("migrate_makefile_run_write");
+    let makefile = directory.join("Makefile.toml");
+    write_text_file(&makefile, "[tasks.build]\nforce = true\n").unwrap();
+    let makefile_string = makefile.to_string_lossy().to_string();
+
+    run(&makefile_string, true).unwrap();
+
+    assert_eq!(
+        read_text_file(&makefile).unwrap(),
+        "[tasks.build]\nignore_errors = true\n"
+    );
+}
+
+#[test]
+fn print_diff_valid() {
+    let directory = test::get_temp_test_directory("migrate_makefile_print_diff_valid");
+    let makefile = directory.join("Makefile.toml");
+    let migrated_file = directory.join("Makefile.migrated.toml");
+    write_text_file(&makefile, "[tasks.build]\nforce = true\n").unwrap();
+    write_text_file(&migrated_file, "[tasks.build]\nignore_errors = true\n").unwrap();
+
+    let output = print_diff(
+        "git",
+        &makefile.to_string_lossy(),
+        &migrated_file.to_string_lossy(),
+    )
+    .unwrap();
+
+    assert!(output);
+}
+
+#[test]
+fn print_diff_git_not_found() {
+    let directory = test::get_temp_test_directory("migrate_makefile_print_diff_git_not_found");
+    let makefile = directory.join("Makefile.toml");
+    write_text_file(&makefile, "[tasks.build]\nforce = true\n").unwrap();
+    let makefile_string = makefile.to_string_lossy().to_string();
+
+    let output = print_diff(
+        "cargo-make-test-bad-git-command",
+        &makefile_string,
+        &makefile_string,
+    )
+    .unwrap();
+
+    assert!(!output);
+}
+
+#[test]
+fn run_not_toml() {
+    let output = run("Makefile.yaml", false);
+
+    match output {
+        Err(CargoMakeError::ParseFileFailed(file, _)) => assert_eq!(file, "Makefile.yaml"),
+        _ => panic!("expected parse error"),
+    }
+}
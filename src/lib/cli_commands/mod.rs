@@ -4,5 +4,10 @@
 //!
 
 pub(crate) mod diff_steps;
+pub(crate) mod format_makefile;
+pub(crate) mod lint;
 pub mod list_steps;
+pub(crate) mod migrate_makefile;
+pub(crate) mod print_env;
+pub(crate) mod print_schema;
 pub mod print_steps;
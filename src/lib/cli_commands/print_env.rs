@@ -0,0 +1,109 @@
+//! # print_env
+//!
+//! Prints the resolved env and the sources which set (and overrode) each env var.
+//!
+
+#[cfg(test)]
+#[path = "print_env_test.rs"]
+mod print_env_test;
+
+use crate::environment;
+use crate::environment::provenance;
+use crate::environment::provenance::EnvSource;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::secrets;
+use crate::types::Config;
+use std::env;
+use std::io;
+
+fn format_value(value: &str) -> String {
+    secrets::redact(value).replace('\n', "\\n")
+}
+
+fn format_source(source: &EnvSource) -> String {
+    match source.value {
+        Some(ref value) => format!("{} ({})", &source.source, format_value(value)),
+        None => source.source.to_string(),
+    }
+}
+
+fn should_print(key: &str, sources: &[EnvSource]) -> bool {
+    if key.starts_with("CARGO_MAKE_INTERNAL_") {
+        false
+    } else {
+        !sources.is_empty() || key.starts_with("CARGO_MAKE_")
+    }
+}
+
+fn print_env_var(
+    output_buffer: &mut impl io::Write,
+    key: &str,
+    value: &str,
+    sources: &[EnvSource],
+) -> io::Result<()> {
+    writeln!(output_buffer, "{}={}", key, format_value(value))?;
+
+    match sources.split_last() {
+        Some((source, overridden)) => {
+            writeln!(output_buffer, "    source: {}", &source.source)?;
+
+            if !overridden.is_empty() {
+                let overridden_sources: Vec<String> =
+                    overridden.iter().rev().map(format_source).collect();
+                writeln!(
+                    output_buffer,
+                    "    overrides: {}",
+                    overridden_sources.join(", ")
+                )?;
+            }
+        }
+        None => writeln!(
+            output_buffer,
+            "    source: {}",
+            provenance::PROCESS_ENV_SOURCE
+        )?,
+    }
+
+    Ok(())
+}
+
+fn setup_task_env(config: &Config, task: &str) -> Result<(), CargoMakeError> {
+    let task_config = execution_plan::get_normalized_task(config, task, true)?;
+
+    if let Some(env_files) = task_config.env_files {
+        provenance::track(&format!("task {} [env_files]", task), || {
+            environment::set_env_files(env_files)
+        });
+    }
+    if let Some(env) = task_config.env {
+        provenance::track(&format!("task {} [env]", task), || {
+            environment::set_env(env)
+        });
+    }
+
+    Ok(())
+}
+
+/// Applies the task env (if any) and prints all env vars set by cargo-make,
+/// including the source which defined the current value and the sources it overrode.
+pub(crate) fn print(
+    output_buffer: &mut impl io::Write,
+    config: &Config,
+    task: &str,
+) -> Result<(), CargoMakeError> {
+    setup_task_env(config, task)?;
+
+    let mut env_vars: Vec<(String, String)> = env::vars().collect();
+    env_vars.sort();
+
+    for (key, value) in env_vars {
+        let sources = provenance::get_sources(&key);
+
+        if should_print(&key, &sources) {
+            print_env_var(output_buffer, &key, &value, &sources)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use super::*;
+use crate::types::{ConfigSection, EnvValue, Task};
+use indexmap::IndexMap;
+
+#[test]
+fn format_source_with_value() {
+    let output = format_source(&EnvSource {
+        source: "test".to_string(),
+        value: Some("line1\nline2".to_string()),
+    });
+
+    assert_eq!(output, "test (line1\\nline2)");
+}
+
+#[test]
+fn format_source_without_value() {
+    let output = format_source(&EnvSource {
+        source: "test".to_string(),
+        value: None,
+    });
+
+    assert_eq!(output, "test");
+}
+
+#[test]
+fn should_print_no_sources() {
+    assert!(!should_print("TEST_PRINT_ENV", &vec![]));
+}
+
+#[test]
+fn should_print_with_sources() {
+    let sources = vec![EnvSource {
+        source: "test".to_string(),
+        value: None,
+    }];
+
+    assert!(should_print("TEST_PRINT_ENV", &sources));
+}
+
+#[test]
+fn should_print_cargo_make_no_sources() {
+    assert!(should_print("CARGO_MAKE_TEST", &vec![]));
+}
+
+#[test]
+fn should_print_internal() {
+    let sources = vec![EnvSource {
+        source: "test".to_string(),
+        value: None,
+    }];
+
+    assert!(!should_print("CARGO_MAKE_INTERNAL_TEST", &sources));
+}
+
+#[test]
+fn print_env_var_overridden() {
+    let sources = vec![
+        EnvSource {
+            source: "source1".to_string(),
+            value: Some("value1".to_string()),
+        },
+        EnvSource {
+            source: "source2".to_string(),
+            value: None,
+        },
+        EnvSource {
+            source: "source3".to_string(),
+            value: Some("value3".to_string()),
+        },
+    ];
+
+    let mut output = vec![];
+    print_env_var(&mut output, "TEST_PRINT_ENV", "value3", &sources).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "TEST_PRINT_ENV=value3\n    source: source3\n    overrides: source2, source1 (value1)\n"
+    );
+}
+
+#[test]
+fn print_env_var_no_sources() {
+    let mut output = vec![];
+    print_env_var(&mut output, "CARGO_MAKE_TEST", "value", &vec![]).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "CARGO_MAKE_TEST=value\n    source: process environment\n"
+    );
+}
+
+#[test]
+#[ignore]
+fn print_task_env() {
+    provenance::clear();
+    envmnt::set("TEST_PRINT_ENV_TASK", "initial");
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_PRINT_ENV_TASK".to_string(),
+        EnvValue::Value("task".to_string()),
+    );
+    let mut task = Task::new();
+    task.env = Some(env);
+
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    config.tasks.insert("test".to_string(), task);
+
+    let mut output = vec![];
+    print(&mut output, &config, "test").unwrap();
+
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains(
+        "TEST_PRINT_ENV_TASK=task\n    source: task test [env]\n    overrides: process environment (initial)\n"
+    ));
+}
+
+#[test]
+fn print_task_not_found() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+
+    let mut output = vec![];
+    let result = print(&mut output, &config, "test");
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,36 @@
+//! # print_schema
+//!
+//! Prints the makefile JSON schema generated from the makefile types.
+//!
+
+#[cfg(test)]
+#[path = "print_schema_test.rs"]
+mod print_schema_test;
+
+use crate::error::CargoMakeError;
+use crate::types::ExternalConfig;
+use schemars::gen::SchemaSettings;
+use std::io;
+
+/// Returns the makefile JSON schema
+pub(crate) fn get_schema() -> String {
+    // makefiles have no null values, optional attributes are simply omitted
+    let generator = SchemaSettings::draft07()
+        .with(|settings| settings.option_add_null_type = false)
+        .into_generator();
+    let mut schema = generator.into_root_schema_for::<ExternalConfig>();
+    schema.schema.metadata().title = Some("cargo-make makefile".to_string());
+
+    // the schema is generated from static types so serialization can not fail
+    let mut schema_string = serde_json::to_string_pretty(&schema).unwrap_or_default();
+    schema_string.push('\n');
+
+    schema_string
+}
+
+/// Prints the makefile JSON schema
+pub(crate) fn print(output_buffer: &mut impl io::Write) -> Result<(), CargoMakeError> {
+    write!(output_buffer, "{}", get_schema())?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn get_schema_valid() {
+    let schema: serde_json::Value = serde_json::from_str(&get_schema()).unwrap();
+
+    assert_eq!(schema["title"], "cargo-make makefile");
+    assert!(schema["properties"]["tasks"].is_object());
+    assert!(schema["definitions"]["Task"]["properties"]["command"].is_object());
+    assert_eq!(
+        schema["definitions"]["EnvValue"]["description"],
+        "Holds the env value or script"
+    );
+}
+
+#[test]
+fn print_valid() {
+    let mut output = vec![];
+
+    print(&mut output).unwrap();
+
+    assert_eq!(String::from_utf8(output).unwrap(), get_schema());
+}
+
+#[test]
+fn schema_file_in_sync() {
+    let schema_file = include_str!("../../../docs/makefile_schema.json");
+
+    assert!(
+        get_schema() == schema_file,
+        "docs/makefile_schema.json is out of date, regenerate it using: makers --print-schema > docs/makefile_schema.json"
+    );
+}
@@ -11,6 +11,7 @@ use crate::error::CargoMakeError;
 use std::io;
 
 use crate::execution_plan::ExecutionPlanBuilder;
+use crate::secrets;
 use crate::types::{Config, CrateInfo, ExecutionPlan};
 use regex::Regex;
 
@@ -56,11 +57,8 @@ fn print_short_description(
             Some(value) => value,
             None => "no description",
         };
-        writeln!(
-            output_buffer,
-            "{}. {} - {}",
-            counter, &step.name, &description
-        )?;
+        let line = format!("{}. {} - {}", counter, &step.name, &description);
+        writeln!(output_buffer, "{}", secrets::redact(&line))?;
 
         counter = counter + 1;
     }
@@ -71,7 +69,8 @@ fn print_default(
     output_buffer: &mut impl io::Write,
     execution_plan: &ExecutionPlan,
 ) -> io::Result<()> {
-    writeln!(output_buffer, "{:#?}", &execution_plan)
+    let output = format!("{:#?}", &execution_plan);
+    writeln!(output_buffer, "{}", secrets::redact(&output))
 }
 
 /// Only prints the execution plan
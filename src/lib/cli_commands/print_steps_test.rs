@@ -27,8 +27,10 @@ fn print_default_format() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -56,8 +58,10 @@ fn print_task_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -83,8 +87,10 @@ fn print_task_not_found_but_skipped() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -149,8 +155,10 @@ fn print_skip_init_end_tasks() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
 
     let mut output_bytes = Vec::<u8>::new();
@@ -179,3 +187,45 @@ fn print_skip_init_end_tasks() {
         end_task_name
     );
 }
+
+#[test]
+#[ignore]
+fn print_default_format_secret_redacted() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+
+    envmnt::set("PRINT_STEPS_TEST_SECRET", "print-steps-secret-value");
+    secrets::add_key("PRINT_STEPS_TEST_SECRET");
+
+    let mut task = Task::new();
+    task.command = Some("publish".to_string());
+    task.args = Some(vec!["--token=print-steps-secret-value".to_string()]);
+    config.tasks.insert("init".to_string(), Task::new());
+    config.tasks.insert("end".to_string(), Task::new());
+    config.tasks.insert("test".to_string(), task);
+
+    let mut output = vec![];
+    print(
+        &mut output,
+        &config,
+        "test",
+        "default",
+        false,
+        &None,
+        &CrateInfo::new(),
+        false,
+    )
+    .expect("print should succeed");
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("--token=***"));
+    assert!(!output.contains("print-steps-secret-value"));
+}
@@ -99,6 +99,9 @@ fn get_args(
 
     cli_args.disable_check_for_updates = cli_parsed.arguments.contains("disable-check-for-updates");
     cli_args.experimental = cli_parsed.arguments.contains("experimental");
+    cli_args.offline = cli_parsed.arguments.contains("offline") || envmnt::is("CARGO_MAKE_OFFLINE");
+    cli_args.locked = cli_parsed.arguments.contains("locked");
+    cli_args.update_lock = cli_parsed.arguments.contains("update-lock");
     cli_args.print_only = cli_parsed.arguments.contains("print-steps");
     cli_args.disable_workspace = cli_parsed.arguments.contains("no-workspace");
     cli_args.disable_on_error = cli_parsed.arguments.contains("no-on-error");
@@ -106,6 +109,13 @@ fn get_args(
     cli_args.skip_init_end_tasks = cli_parsed.arguments.contains("skip-init-end-tasks");
     cli_args.list_all_steps = cli_parsed.arguments.contains("list-steps");
     cli_args.diff_execution_plan = cli_parsed.arguments.contains("diff-steps");
+    cli_args.print_env = cli_parsed.arguments.contains("print-env");
+    cli_args.print_schema = cli_parsed.arguments.contains("print-schema");
+    cli_args.lint = cli_parsed.arguments.contains("lint");
+    cli_args.fmt = cli_parsed.arguments.contains("fmt");
+    cli_args.fmt_check = cli_parsed.arguments.contains("check");
+    cli_args.migrate = cli_parsed.arguments.contains("migrate");
+    cli_args.migrate_write = cli_parsed.arguments.contains("write");
     cli_args.hide_uninteresting = cli_parsed.arguments.contains("hide-uninteresting");
 
     cli_args.skip_tasks_pattern = match cli_parsed.get_first_value("skip-tasks-pattern") {
@@ -369,6 +379,37 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "Allows access unsupported experimental predefined tasks.".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "offline".to_string(),
+            key: vec!["--offline".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Loads git extended makefiles only from the local cache".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "locked".to_string(),
+            key: vec!["--locked".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Fails in case the extended makefiles do not match the makefile lock file"
+                    .to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "update-lock".to_string(),
+            key: vec!["--update-lock".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Updates the makefile lock file with the current extended makefiles".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "disable-check-for-updates".to_string(),
             key: vec!["--disable-check-for-updates".to_string()],
@@ -452,6 +493,78 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "Runs diff between custom flow and prebuilt flow (requires git)".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "print-env".to_string(),
+            key: vec!["--print-env".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Prints the resolved env (including the task env) and the source of each env var without invoking any task".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "print-schema".to_string(),
+            key: vec!["--print-schema".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Prints the makefile JSON schema without loading any makefile".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "lint".to_string(),
+            key: vec!["--lint".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Validates the makefiles and reports all found problems".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "fmt".to_string(),
+            key: vec!["--fmt".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Rewrites the makefile in the canonical format".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "check".to_string(),
+            key: vec!["--check".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Used with --fmt, fails if the makefile is not formatted instead of rewriting it"
+                    .to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "migrate".to_string(),
+            key: vec!["--migrate".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Prints the diff of replacing deprecated attributes and tasks in the makefile"
+                    .to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "write".to_string(),
+            key: vec!["--write".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Used with --migrate, writes the migrated makefile".to_string(),
+            )),
+        })
         .set_positional_argument(Some(PositionalArgument {
             name: "TASK_CMD".to_string(),
             help: Some(ArgumentHelp::Text(
@@ -51,7 +51,17 @@ fn assert_cli_args(cli_args_ref1: &CliArgs, cli_args_ref2: &CliArgs) {
     assert_eq!(cli_args1.print_only, cli_args2.print_only);
     assert_eq!(cli_args1.list_all_steps, cli_args2.list_all_steps);
     assert_eq!(cli_args1.diff_execution_plan, cli_args2.diff_execution_plan);
+    assert_eq!(cli_args1.print_env, cli_args2.print_env);
+    assert_eq!(cli_args1.print_schema, cli_args2.print_schema);
+    assert_eq!(cli_args1.lint, cli_args2.lint);
+    assert_eq!(cli_args1.fmt, cli_args2.fmt);
+    assert_eq!(cli_args1.fmt_check, cli_args2.fmt_check);
+    assert_eq!(cli_args1.migrate, cli_args2.migrate);
+    assert_eq!(cli_args1.migrate_write, cli_args2.migrate_write);
     assert_eq!(cli_args1.experimental, cli_args2.experimental);
+    assert_eq!(cli_args1.offline, cli_args2.offline);
+    assert_eq!(cli_args1.locked, cli_args2.locked);
+    assert_eq!(cli_args1.update_lock, cli_args2.update_lock);
     assert_eq!(cli_args1.arguments, cli_args2.arguments);
     assert_eq!(cli_args1.output_format, cli_args2.output_format);
     assert_eq!(cli_args1.output_file, cli_args2.output_file);
@@ -370,6 +380,98 @@ fn parse_args_experimental() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_offline() {
+    let cli_args = default_parse_cli_args(vec!["--offline"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.offline = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_print_schema() {
+    let cli_args = default_parse_cli_args(vec!["--print-schema"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_schema = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_lint() {
+    let cli_args = default_parse_cli_args(vec!["--lint"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.lint = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_fmt() {
+    let cli_args = default_parse_cli_args(vec!["--fmt"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.fmt = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_fmt_check() {
+    let cli_args = default_parse_cli_args(vec!["--fmt", "--check"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.fmt = true;
+    expected.fmt_check = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_migrate() {
+    let cli_args = default_parse_cli_args(vec!["--migrate"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.migrate = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_migrate_write() {
+    let cli_args = default_parse_cli_args(vec!["--migrate", "--write"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.migrate = true;
+    expected.migrate_write = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_locked() {
+    let cli_args = default_parse_cli_args(vec!["--locked"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.locked = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_update_lock() {
+    let cli_args = default_parse_cli_args(vec!["--update-lock"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.update_lock = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_disable_check_for_updates() {
     let cli_args = default_parse_cli_args(vec!["--disable-check-for-updates"]).unwrap();
@@ -455,6 +557,22 @@ fn parse_args_diff_steps() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_print_env() {
+    let mut cli_args = default_parse_cli_args(vec!["--print-env"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_env = true;
+
+    assert_cli_args(&cli_args, &expected);
+
+    cli_args = default_parse_cli_args(vec!["--print-env", "task1"]).unwrap();
+    expected.task = "task1".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_task_cmd() {
     let mut cli_args = default_parse_cli_args(vec!["task1"]).unwrap();
@@ -31,7 +31,17 @@ fn run_makefile_not_found() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -70,7 +80,17 @@ fn run_empty_task() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -109,7 +129,17 @@ fn print_empty_task() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -148,7 +178,17 @@ fn list_empty_task() {
             list_all_steps: true,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -187,7 +227,17 @@ fn run_file_and_task() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -229,7 +279,17 @@ fn run_cwd_with_file() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -269,7 +329,17 @@ fn run_file_not_go_to_project_root() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -309,7 +379,17 @@ fn run_cwd_go_to_project_root_current_dir() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -352,7 +432,17 @@ fn run_cwd_go_to_project_root_child_dir() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -395,7 +485,17 @@ fn run_cwd_task_not_found() {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -666,6 +766,31 @@ fn run_diff_steps() {
     run(&cli_args.unwrap(), &global_config, None).unwrap();
 }
 
+#[test]
+#[ignore]
+fn run_print_env() {
+    let global_config = GlobalConfig::new();
+    let cli_args = cli_parser::parse_args(
+        &global_config,
+        &"make".to_string(),
+        true,
+        Some(vec![
+            "cargo",
+            "make",
+            "--makefile",
+            "./examples/env.toml",
+            "-l",
+            "error",
+            "--no-workspace",
+            "--print-env",
+            "echo",
+        ]),
+        create_cli(&global_config, CliSpec::new(), true),
+    );
+
+    run(&cli_args.unwrap(), &global_config, None).unwrap();
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -185,7 +185,12 @@ pub(crate) fn run_command_get_output(
     command.stdin(Stdio::inherit());
 
     if silent {
-        command.stdout(Stdio::null()).stderr(Stdio::null());
+        // captured output is not printed so it is still returned to the caller
+        if capture_output {
+            command.stdout(Stdio::piped()).stderr(Stdio::null());
+        } else {
+            command.stdout(Stdio::null()).stderr(Stdio::null());
+        }
     } else if ctrl_c_handling {
         if capture_output {
             command.stdout(Stdio::piped()).stderr(Stdio::piped());
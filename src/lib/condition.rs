@@ -7,18 +7,23 @@
 #[path = "condition_test.rs"]
 mod condition_test;
 
+use crate::command;
+use crate::condition_expr;
 use crate::environment;
+use crate::environment::crateinfo;
 use crate::error::CargoMakeError;
+use crate::io;
 use crate::profile;
 use crate::scriptengine;
 use crate::types;
 use crate::types::{
-    ConditionScriptValue, ConditionType, FlowInfo, RustVersionCondition, ScriptValue, Step,
-    TaskCondition,
+    ConditionScriptValue, ConditionType, FlowInfo, GitChangedFilesCondition, RustVersionCondition,
+    ScriptValue, Step, TaskCondition,
 };
 use crate::version::{is_newer, is_same};
 use fsio::path::from_path::FromPath;
-use glob::glob;
+use git_info::types::GitInfo;
+use glob::{glob, Pattern};
 use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
 use std::path::Path;
@@ -451,144 +456,527 @@ fn validate_files_modified(condition: &TaskCondition) -> bool {
     }
 }
 
-fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondition>) -> bool {
-    match condition {
-        Some(ref condition_struct) => {
-            debug!("Checking task condition structure.");
+fn get_git_info(flow_info: Option<&FlowInfo>) -> GitInfo {
+    match flow_info {
+        Some(flow_info)
+            if flow_info.env_info.git_info.current_branch.is_some()
+                || flow_info.env_info.git_info.dirty.is_some() =>
+        {
+            flow_info.env_info.git_info.clone()
+        }
+        // git info was not loaded (for example skip_git_env_info is set)
+        _ => git_info::get(),
+    }
+}
 
-            let condition_type = condition_struct.get_condition_type();
-            let validate_any = condition_type == ConditionType::Or;
-            let group_or_condition = condition_type == ConditionType::GroupOr || validate_any;
-            let mut not_valid_found = false;
+fn get_git_command_output(args: &[&str]) -> Option<Vec<String>> {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+
+    match command::run_command_get_output_string("git", &Some(args)) {
+        Some(stdout) => Some(
+            stdout
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect(),
+        ),
+        None => {
+            debug!("Git command failed.");
+            None
+        }
+    }
+}
 
-            let mut valid = validate_os(&condition_struct);
-            if group_or_condition && valid && condition_struct.os.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn matches_any_pattern(value: &str, patterns: &[String]) -> bool {
+    patterns.iter().any(|pattern| {
+        let expanded_pattern = environment::expand_value(pattern);
+        match Pattern::new(&expanded_pattern) {
+            Ok(pattern) => pattern.matches(value),
+            Err(error) => {
+                warn!("Invalid glob pattern: {} {:#?}", &expanded_pattern, &error);
+                false
             }
+        }
+    })
+}
 
-            valid = validate_platform(&condition_struct);
-            if group_or_condition && valid && condition_struct.platforms.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn validate_git_branch(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.git_branch {
+        Some(ref patterns) => {
+            let mut branch = get_git_info(flow_info).current_branch;
+            if branch.is_none() {
+                // detached head (common on CI), fallback to the CI branch name
+                branch = match flow_info {
+                    Some(flow_info) => flow_info.env_info.ci_info.branch_name.clone(),
+                    None => ci_info::get().branch_name,
+                };
             }
 
-            valid = validate_profile(&condition_struct);
-            if group_or_condition && valid && condition_struct.profiles.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
+            match branch {
+                Some(ref branch_name) => {
+                    let valid = matches_any_pattern(branch_name, patterns);
+                    if !valid {
+                        debug!(
+                            "Failed git branch condition, current branch: {}",
+                            branch_name
+                        );
+                    }
 
-            valid = validate_channel(&condition_struct, flow_info);
-            if group_or_condition && valid && condition_struct.channels.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+                    valid
+                }
+                None => {
+                    debug!("Failed git branch condition, current branch not found");
+                    false
+                }
             }
+        }
+        None => true,
+    }
+}
 
-            valid = validate_env(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn validate_git_dirty(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.git_dirty {
+        Some(dirty) => match get_git_info(flow_info).dirty {
+            Some(current_dirty) => current_dirty == dirty,
+            None => {
+                debug!("Failed git dirty condition, git state not found");
+                false
             }
+        },
+        None => true,
+    }
+}
 
-            valid = validate_env_set(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_set.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
+fn get_git_changed_files(base_ref: &Option<String>) -> Option<Vec<String>> {
+    match base_ref {
+        Some(ref base_ref) => {
+            // only the changes since the merge base are checked, so changes on the base are ignored
+            let range = format!("{}...HEAD", environment::expand_value(base_ref));
+            get_git_command_output(&["diff", "--name-only", "--relative", &range, "--"])
+        }
+        None => {
+            let mut changed_files =
+                get_git_command_output(&["diff", "--name-only", "--relative", "HEAD"])?;
+            let untracked_files =
+                get_git_command_output(&["ls-files", "--others", "--exclude-standard"])?;
+            changed_files.extend(untracked_files);
+
+            Some(changed_files)
+        }
+    }
+}
 
-            valid = validate_env_not_set(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_not_set.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
+fn validate_git_changed_files(condition: &TaskCondition) -> bool {
+    match condition.git_changed_files {
+        Some(ref changed_files_condition) => {
+            let (patterns, base_ref) = match changed_files_condition {
+                GitChangedFilesCondition::Files(files) => (files.clone(), None),
+                GitChangedFilesCondition::Options(options) => {
+                    (options.files.clone(), options.base_ref.clone())
+                }
+            };
 
-            valid = validate_env_bool(&condition_struct, true, validate_any);
-            if group_or_condition && valid && condition_struct.env_true.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
+            match get_git_changed_files(&base_ref) {
+                Some(changed_files) => {
+                    let valid = changed_files
+                        .iter()
+                        .any(|file| matches_any_pattern(file, &patterns));
+                    if !valid {
+                        debug!("Failed git changed files condition, no matching changed files");
+                    }
 
-            valid = validate_env_bool(&condition_struct, false, validate_any);
-            if group_or_condition && valid && condition_struct.env_false.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+                    valid
+                }
+                None => {
+                    debug!("Failed git changed files condition, unable to get changed files");
+                    false
+                }
             }
+        }
+        None => true,
+    }
+}
 
-            valid = validate_env_contains(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_contains.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn validate_git_tag_on_head(condition: &TaskCondition) -> bool {
+    match condition.git_tag_on_head {
+        Some(tag_on_head) => match get_git_command_output(&["tag", "--points-at", "HEAD"]) {
+            Some(tags) => {
+                let tagged = !tags.is_empty();
+                tagged == tag_on_head
+            }
+            None => {
+                debug!("Failed git tag on head condition, unable to get tags");
+                false
             }
+        },
+        None => true,
+    }
+}
 
-            valid = validate_rust_version(&condition_struct);
-            if group_or_condition && valid && condition_struct.rust_version.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn validate_commands_exist(condition: &TaskCondition, validate_any: bool) -> bool {
+    match condition.commands_exist {
+        Some(ref commands) => {
+            let mut found_any = commands.is_empty();
+
+            for command in commands.iter() {
+                let expanded_command = environment::expand_value(command);
+                let exists = io::find_executable(&expanded_command).is_some();
+                if exists {
+                    if validate_any {
+                        return true;
+                    }
+
+                    found_any = true;
+                } else if !validate_any {
+                    debug!(
+                        "Failed commands exist condition, command: {} not found",
+                        command
+                    );
+                    return false;
+                }
             }
 
-            valid = validate_files_exist(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.files_exist.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+            found_any
+        }
+        None => true,
+    }
+}
+
+fn validate_target_triples(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.target_triples {
+        Some(ref patterns) => {
+            let rust_target_triple = match flow_info {
+                Some(flow_info) => flow_info.env_info.rust_info.target_triple.clone(),
+                None => Some(envmnt::get_or("CARGO_MAKE_RUST_TARGET_TRIPLE", "")),
+            };
+            let target_triple = match envmnt::get_or("CARGO_MAKE_CRATE_TARGET_TRIPLE", "") {
+                value if !value.is_empty() => Some(value),
+                _ => rust_target_triple.filter(|value| !value.is_empty()),
+            };
+
+            match target_triple {
+                Some(ref target_triple) => {
+                    let valid = matches_any_pattern(target_triple, patterns);
+                    if !valid {
+                        debug!(
+                            "Failed target triple condition, current target triple: {}",
+                            target_triple
+                        );
+                    }
+
+                    valid
+                }
+                None => {
+                    debug!("Failed target triple condition, target triple not found");
+                    false
+                }
             }
+        }
+        None => true,
+    }
+}
 
-            valid = validate_files_not_exist(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.files_not_exist.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+fn validate_crate_features(
+    condition: &TaskCondition,
+    flow_info: Option<&FlowInfo>,
+    validate_any: bool,
+) -> bool {
+    match condition.crate_features {
+        Some(ref feature_names) => {
+            let features = match flow_info {
+                Some(flow_info) if flow_info.env_info.crate_info.features.is_some() => {
+                    flow_info.env_info.crate_info.features.clone()
+                }
+                // crate info was not loaded (for example skip_crate_env_info is set)
+                _ => crateinfo::load_features(),
             }
+            .unwrap_or_default();
+
+            let mut found_any = feature_names.is_empty();
+
+            for feature_name in feature_names.iter() {
+                if features.contains_key(feature_name) {
+                    if validate_any {
+                        return true;
+                    }
 
-            valid = validate_files_modified(&condition_struct);
-            if !valid {
-                return false;
+                    found_any = true;
+                } else if !validate_any {
+                    debug!(
+                        "Failed crate features condition, feature: {} not found",
+                        feature_name
+                    );
+                    return false;
+                }
             }
 
-            !not_valid_found || !group_or_condition || condition_struct.files_modified.is_some()
+            found_any
         }
         None => true,
     }
 }
 
+fn validate_nested_all(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.all {
+        Some(ref conditions) => conditions
+            .iter()
+            .all(|nested_condition| validate_condition_struct(flow_info, nested_condition)),
+        None => true,
+    }
+}
+
+fn validate_nested_any(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.any {
+        Some(ref conditions) => {
+            conditions.is_empty()
+                || conditions
+                    .iter()
+                    .any(|nested_condition| validate_condition_struct(flow_info, nested_condition))
+        }
+        None => true,
+    }
+}
+
+fn validate_nested_not(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> bool {
+    match condition.not {
+        Some(ref nested_condition) => !validate_condition_struct(flow_info, nested_condition),
+        None => true,
+    }
+}
+
+fn validate_condition_struct(
+    flow_info: Option<&FlowInfo>,
+    condition_struct: &TaskCondition,
+) -> bool {
+    debug!("Checking task condition structure.");
+
+    let condition_type = condition_struct.get_condition_type();
+    let validate_any = condition_type == ConditionType::Or;
+    let group_or_condition = condition_type == ConditionType::GroupOr || validate_any;
+    let mut not_valid_found = false;
+
+    let mut valid = validate_os(&condition_struct);
+    if group_or_condition && valid && condition_struct.os.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_platform(&condition_struct);
+    if group_or_condition && valid && condition_struct.platforms.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_profile(&condition_struct);
+    if group_or_condition && valid && condition_struct.profiles.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_channel(&condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.channels.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.env.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env_set(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.env_set.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env_not_set(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.env_not_set.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env_bool(&condition_struct, true, validate_any);
+    if group_or_condition && valid && condition_struct.env_true.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env_bool(&condition_struct, false, validate_any);
+    if group_or_condition && valid && condition_struct.env_false.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_env_contains(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.env_contains.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_rust_version(&condition_struct);
+    if group_or_condition && valid && condition_struct.rust_version.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_files_exist(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.files_exist.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_files_not_exist(&condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.files_not_exist.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_git_branch(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.git_branch.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_git_dirty(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.git_dirty.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_git_changed_files(condition_struct);
+    if group_or_condition && valid && condition_struct.git_changed_files.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_git_tag_on_head(condition_struct);
+    if group_or_condition && valid && condition_struct.git_tag_on_head.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_commands_exist(condition_struct, validate_any);
+    if group_or_condition && valid && condition_struct.commands_exist.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_target_triples(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.target_triples.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_crate_features(condition_struct, flow_info, validate_any);
+    if group_or_condition && valid && condition_struct.crate_features.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_nested_all(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.all.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_nested_any(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.any.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_nested_not(condition_struct, flow_info);
+    if group_or_condition && valid && condition_struct.not.is_some() {
+        return true;
+    } else if !group_or_condition && !valid {
+        return false;
+    } else if group_or_condition && !valid {
+        not_valid_found = true;
+    }
+
+    valid = validate_files_modified(&condition_struct);
+    if !valid {
+        return false;
+    }
+
+    !not_valid_found || !group_or_condition || condition_struct.files_modified.is_some()
+}
+
+fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondition>) -> bool {
+    match condition {
+        Some(ref condition_struct) => validate_condition_struct(flow_info, condition_struct),
+        None => true,
+    }
+}
+
 pub(crate) fn get_script_text(script: &ConditionScriptValue) -> Vec<String> {
     match script {
         ConditionScriptValue::SingleLine(text) => vec![text.clone()],
@@ -649,10 +1037,36 @@ pub(crate) fn validate_conditions(
     }
 }
 
+fn validate_expr(
+    flow_info: &FlowInfo,
+    task_name: &str,
+    condition_expr: &Option<String>,
+) -> Result<bool, CargoMakeError> {
+    match condition_expr {
+        Some(ref expression) => {
+            debug!("Checking task condition expression: {}", expression);
+
+            condition_expr::evaluate(flow_info, expression).map_err(|error| {
+                CargoMakeError::InvalidConditionExpr(
+                    task_name.to_string(),
+                    envmnt::get_or("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE", "unknown"),
+                    error,
+                )
+            })
+        }
+        None => Ok(true),
+    }
+}
+
 pub(crate) fn validate_condition_for_step(
     flow_info: &FlowInfo,
     step: &Step,
 ) -> Result<bool, CargoMakeError> {
+    if !validate_expr(flow_info, &step.name, &step.config.condition_expr)? {
+        debug!("Failed condition expression");
+        return Ok(false);
+    }
+
     validate_conditions(
         &flow_info,
         &step.config.condition,
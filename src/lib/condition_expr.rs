@@ -0,0 +1,736 @@
+//! # condition_expr
+//!
+//! Parses and evaluates the task condition_expr expressions.<br>
+//! The expression language supports string, number and boolean literals, access to the env,
+//! profile, os, platform, rust, crate, git and ci values, comparison operators (==, !=, <, <=, >, >=),
+//! logical operators (&&, ||, !), parentheses and a small set of builtin functions.
+//!
+
+#[cfg(test)]
+#[path = "condition_expr_test.rs"]
+mod condition_expr_test;
+
+use crate::environment;
+use crate::profile;
+use crate::types;
+use crate::types::FlowInfo;
+use regex::Regex;
+use rust_info::types::RustChannel;
+use std::cmp::Ordering;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenType {
+    Str(String),
+    Number(i64),
+    Identifier(String),
+    Dot,
+    Comma,
+    OpenParen,
+    CloseParen,
+    Not,
+    And,
+    Or,
+    Compare(CompareOperator),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    token_type: TokenType,
+    /// 1 based column of the token start
+    column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CompareOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a value produced while evaluating the expression
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(i64),
+    Str(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Function {
+    Exists,
+    IsSet,
+    Contains,
+    StartsWith,
+    EndsWith,
+    Matches,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The parsed expression tree
+pub(crate) enum Expression {
+    Literal(Value),
+    Variable(Vec<String>),
+    Not(Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Compare(CompareOperator, Box<Expression>, Box<Expression>),
+    Call(Function, Vec<Expression>),
+}
+
+static ROOT_VARIABLES: &[&str] = &["profile", "os", "platform", "ci"];
+static RUST_VARIABLES: &[&str] = &[
+    "version",
+    "channel",
+    "target_arch",
+    "target_env",
+    "target_os",
+    "target_pointer_width",
+    "target_vendor",
+    "target_triple",
+];
+static CRATE_VARIABLES: &[&str] = &[
+    "name",
+    "version",
+    "description",
+    "license",
+    "documentation",
+    "homepage",
+    "repository",
+];
+static GIT_VARIABLES: &[&str] = &[
+    "branch",
+    "user_name",
+    "user_email",
+    "dirty",
+    "last_commit_hash",
+    "last_commit_hash_short",
+];
+
+fn create_error(message: &str, column: usize) -> String {
+    format!("{} at column {}", message, column)
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let current = chars[index];
+        let column = index + 1;
+
+        if current.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        let next = chars.get(index + 1).copied();
+        let (token_type, size) = match current {
+            '(' => (TokenType::OpenParen, 1),
+            ')' => (TokenType::CloseParen, 1),
+            '.' => (TokenType::Dot, 1),
+            ',' => (TokenType::Comma, 1),
+            '&' if next == Some('&') => (TokenType::And, 2),
+            '|' if next == Some('|') => (TokenType::Or, 2),
+            '=' if next == Some('=') => (TokenType::Compare(CompareOperator::Equal), 2),
+            '!' if next == Some('=') => (TokenType::Compare(CompareOperator::NotEqual), 2),
+            '!' => (TokenType::Not, 1),
+            '<' if next == Some('=') => (TokenType::Compare(CompareOperator::LessOrEqual), 2),
+            '<' => (TokenType::Compare(CompareOperator::Less), 1),
+            '>' if next == Some('=') => (TokenType::Compare(CompareOperator::GreaterOrEqual), 2),
+            '>' => (TokenType::Compare(CompareOperator::Greater), 1),
+            '"' | '\'' => {
+                let mut value = String::new();
+                let mut end = index + 1;
+                let mut closed = false;
+
+                while end < chars.len() {
+                    let value_char = chars[end];
+                    if value_char == current {
+                        closed = true;
+                        break;
+                    } else if value_char == '\\' {
+                        end += 1;
+                        match chars.get(end) {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some('\\') => value.push('\\'),
+                            Some('"') => value.push('"'),
+                            Some('\'') => value.push('\''),
+                            Some(other) => {
+                                return Err(create_error(
+                                    &format!("Invalid escape sequence '\\{}'", other),
+                                    end,
+                                ));
+                            }
+                            None => break,
+                        }
+                    } else {
+                        value.push(value_char);
+                    }
+
+                    end += 1;
+                }
+
+                if !closed {
+                    return Err(create_error("Unterminated string literal", column));
+                }
+
+                (TokenType::Str(value), end + 1 - index)
+            }
+            _ if current.is_ascii_digit() => {
+                let mut end = index;
+                while end < chars.len() && (chars[end].is_ascii_digit() || chars[end] == '.') {
+                    end += 1;
+                }
+
+                let text: String = chars[index..end].iter().collect();
+                if text.contains('.') {
+                    // dotted literals (for example 1.10) are kept as text to be compared as versions
+                    if text.split('.').any(|part| part.is_empty()) {
+                        return Err(create_error(&format!("Invalid number '{}'", text), column));
+                    }
+
+                    (TokenType::Str(text), end - index)
+                } else {
+                    match text.parse::<i64>() {
+                        Ok(value) => (TokenType::Number(value), end - index),
+                        Err(_) => {
+                            return Err(create_error(&format!("Invalid number '{}'", text), column))
+                        }
+                    }
+                }
+            }
+            _ if current.is_alphabetic() || current == '_' => {
+                let mut end = index;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+
+                let text: String = chars[index..end].iter().collect();
+                (TokenType::Identifier(text), end - index)
+            }
+            _ => {
+                return Err(create_error(
+                    &format!("Unexpected character '{}'", current),
+                    column,
+                ))
+            }
+        };
+
+        tokens.push(Token { token_type, column });
+        index += size;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    end_column: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&TokenType> {
+        self.tokens.get(self.index).map(|token| &token.token_type)
+    }
+
+    fn column(&self) -> usize {
+        match self.tokens.get(self.index) {
+            Some(token) => token.column,
+            None => self.end_column,
+        }
+    }
+
+    fn unexpected(&self) -> String {
+        match self.tokens.get(self.index) {
+            Some(token) => create_error(
+                &format!("Unexpected token {}", describe_token(&token.token_type)),
+                token.column,
+            ),
+            None => create_error("Unexpected end of expression", self.end_column),
+        }
+    }
+
+    fn expect(&mut self, token_type: TokenType) -> Result<(), String> {
+        if self.peek() == Some(&token_type) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_and()?;
+
+        while self.peek() == Some(&TokenType::Or) {
+            self.index += 1;
+            let right = self.parse_and()?;
+            expression = Expression::Or(Box::new(expression), Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_unary()?;
+
+        while self.peek() == Some(&TokenType::And) {
+            self.index += 1;
+            let right = self.parse_unary()?;
+            expression = Expression::And(Box::new(expression), Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, String> {
+        if self.peek() == Some(&TokenType::Not) {
+            self.index += 1;
+            let expression = self.parse_unary()?;
+            Ok(Expression::Not(Box::new(expression)))
+        } else {
+            self.parse_comparison()
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expression, String> {
+        let left = self.parse_primary()?;
+
+        match self.peek() {
+            Some(TokenType::Compare(operator)) => {
+                let operator = *operator;
+                self.index += 1;
+                let right = self.parse_primary()?;
+                Ok(Expression::Compare(
+                    operator,
+                    Box::new(left),
+                    Box::new(right),
+                ))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, String> {
+        let column = self.column();
+        let token_type = match self.peek() {
+            Some(token_type) => token_type.clone(),
+            None => return Err(self.unexpected()),
+        };
+
+        match token_type {
+            TokenType::Str(value) => {
+                self.index += 1;
+                Ok(Expression::Literal(Value::Str(value)))
+            }
+            TokenType::Number(value) => {
+                self.index += 1;
+                Ok(Expression::Literal(Value::Number(value)))
+            }
+            TokenType::OpenParen => {
+                self.index += 1;
+                let expression = self.parse_or()?;
+                self.expect(TokenType::CloseParen)?;
+                Ok(expression)
+            }
+            TokenType::Identifier(name) => {
+                self.index += 1;
+
+                match name.as_str() {
+                    "true" => Ok(Expression::Literal(Value::Bool(true))),
+                    "false" => Ok(Expression::Literal(Value::Bool(false))),
+                    "null" => Ok(Expression::Literal(Value::Null)),
+                    _ => {
+                        if self.peek() == Some(&TokenType::OpenParen) {
+                            self.parse_call(&name, column)
+                        } else {
+                            self.parse_variable(name, column)
+                        }
+                    }
+                }
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_call(&mut self, name: &str, column: usize) -> Result<Expression, String> {
+        let (function, arity) = match name {
+            "exists" => (Function::Exists, 1),
+            "is_set" => (Function::IsSet, 1),
+            "contains" => (Function::Contains, 2),
+            "starts_with" => (Function::StartsWith, 2),
+            "ends_with" => (Function::EndsWith, 2),
+            "matches" => (Function::Matches, 2),
+            _ => {
+                return Err(create_error(
+                    &format!("Unknown function '{}'", name),
+                    column,
+                ))
+            }
+        };
+
+        self.expect(TokenType::OpenParen)?;
+
+        let mut arguments = vec![];
+        if self.peek() != Some(&TokenType::CloseParen) {
+            loop {
+                arguments.push(self.parse_or()?);
+
+                if self.peek() == Some(&TokenType::Comma) {
+                    self.index += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        self.expect(TokenType::CloseParen)?;
+
+        if arguments.len() != arity {
+            return Err(create_error(
+                &format!(
+                    "Function '{}' expects {} argument(s) but got {}",
+                    name,
+                    arity,
+                    arguments.len()
+                ),
+                column,
+            ));
+        }
+
+        Ok(Expression::Call(function, arguments))
+    }
+
+    fn parse_variable(&mut self, name: String, column: usize) -> Result<Expression, String> {
+        let mut path = vec![name];
+
+        while self.peek() == Some(&TokenType::Dot) {
+            self.index += 1;
+            match self.peek() {
+                Some(TokenType::Identifier(name)) => {
+                    path.push(name.clone());
+                    self.index += 1;
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+
+        let valid = match path[0].as_str() {
+            "env" => path.len() == 2,
+            "rust" => path.len() == 2 && RUST_VARIABLES.contains(&path[1].as_str()),
+            "crate" => path.len() == 2 && CRATE_VARIABLES.contains(&path[1].as_str()),
+            "git" => path.len() == 2 && GIT_VARIABLES.contains(&path[1].as_str()),
+            root => path.len() == 1 && ROOT_VARIABLES.contains(&root),
+        };
+
+        if valid {
+            Ok(Expression::Variable(path))
+        } else {
+            Err(create_error(
+                &format!("Unknown variable '{}'", path.join(".")),
+                column,
+            ))
+        }
+    }
+}
+
+fn describe_token(token_type: &TokenType) -> String {
+    match token_type {
+        TokenType::Str(value) => format!("\"{}\"", value),
+        TokenType::Number(value) => value.to_string(),
+        TokenType::Identifier(name) => format!("'{}'", name),
+        TokenType::Dot => "'.'".to_string(),
+        TokenType::Comma => "','".to_string(),
+        TokenType::OpenParen => "'('".to_string(),
+        TokenType::CloseParen => "')'".to_string(),
+        TokenType::Not => "'!'".to_string(),
+        TokenType::And => "'&&'".to_string(),
+        TokenType::Or => "'||'".to_string(),
+        TokenType::Compare(operator) => {
+            let text = match operator {
+                CompareOperator::Equal => "==",
+                CompareOperator::NotEqual => "!=",
+                CompareOperator::Less => "<",
+                CompareOperator::LessOrEqual => "<=",
+                CompareOperator::Greater => ">",
+                CompareOperator::GreaterOrEqual => ">=",
+            };
+            format!("'{}'", text)
+        }
+    }
+}
+
+/// Parses the provided expression text.<br>
+/// In case of an invalid expression, the error will describe the problem and its column.
+pub(crate) fn parse(expression: &str) -> Result<Expression, String> {
+    let tokens = tokenize(expression)?;
+
+    if tokens.is_empty() {
+        return Err(create_error("Empty expression", 1));
+    }
+
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        end_column: expression.chars().count() + 1,
+    };
+    let parsed = parser.parse_or()?;
+
+    if parser.index < parser.tokens.len() {
+        Err(parser.unexpected())
+    } else {
+        Ok(parsed)
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(value) => *value != 0,
+        Value::Str(value) => {
+            let lower_case = value.to_lowercase();
+            !(lower_case.is_empty()
+                || lower_case == "false"
+                || lower_case == "no"
+                || lower_case == "0")
+        }
+    }
+}
+
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Str(value) => value.clone(),
+    }
+}
+
+fn to_number(value: &Value) -> Option<i64> {
+    // only plain integers are numbers, anything with a dot is compared as a version or as text
+    match value {
+        Value::Number(value) => Some(*value),
+        Value::Str(value) => value.trim().parse::<i64>().ok(),
+        _ => None,
+    }
+}
+
+fn compare_versions(left: &str, right: &str) -> Option<Ordering> {
+    // only strings that look like versions are compared as versions
+    if !left.contains('.') || !right.contains('.') {
+        return None;
+    }
+
+    match (lenient_semver::parse(left), lenient_semver::parse(right)) {
+        (Ok(left_version), Ok(right_version)) => Some(
+            (left_version.major, left_version.minor, left_version.patch).cmp(&(
+                right_version.major,
+                right_version.minor,
+                right_version.patch,
+            )),
+        ),
+        _ => None,
+    }
+}
+
+fn compare_values(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        (Value::Null, _) | (_, Value::Null) => None,
+        (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(right)),
+        _ => {
+            let left_text = to_text(left);
+            let right_text = to_text(right);
+
+            match compare_versions(&left_text, &right_text) {
+                Some(ordering) => Some(ordering),
+                None => match (to_number(left), to_number(right)) {
+                    (Some(left), Some(right)) => Some(left.cmp(&right)),
+                    _ => Some(left_text.cmp(&right_text)),
+                },
+            }
+        }
+    }
+}
+
+fn evaluate_compare(operator: CompareOperator, left: &Value, right: &Value) -> bool {
+    let equal = match (left, right) {
+        (Value::Null, Value::Null) => true,
+        (Value::Null, _) | (_, Value::Null) => false,
+        _ => match (to_number(left), to_number(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => to_text(left) == to_text(right),
+        },
+    };
+
+    match operator {
+        CompareOperator::Equal => equal,
+        CompareOperator::NotEqual => !equal,
+        _ => match compare_values(left, right) {
+            Some(ordering) => match operator {
+                CompareOperator::Less => ordering == Ordering::Less,
+                CompareOperator::LessOrEqual => ordering != Ordering::Greater,
+                CompareOperator::Greater => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            },
+            None => false,
+        },
+    }
+}
+
+fn optional_value(value: &Option<String>) -> Value {
+    match value {
+        Some(value) => Value::Str(value.clone()),
+        None => Value::Null,
+    }
+}
+
+fn get_variable(flow_info: &FlowInfo, path: &[String]) -> Value {
+    let env_info = &flow_info.env_info;
+    let name = path.get(1).map(|value| value.as_str()).unwrap_or("");
+
+    match path[0].as_str() {
+        "env" => match envmnt::exists(name) {
+            true => Value::Str(envmnt::get_or(name, "")),
+            false => Value::Null,
+        },
+        "profile" => Value::Str(profile::get()),
+        "os" => optional_value(&env_info.rust_info.target_os),
+        "platform" => Value::Str(types::get_platform_name()),
+        "ci" => Value::Bool(env_info.ci_info.ci),
+        "rust" => {
+            let rust_info = &env_info.rust_info;
+            match name {
+                "version" => optional_value(&rust_info.version),
+                "channel" => match rust_info.channel {
+                    Some(RustChannel::Stable) => Value::Str("stable".to_string()),
+                    Some(RustChannel::Beta) => Value::Str("beta".to_string()),
+                    Some(RustChannel::Nightly) => Value::Str("nightly".to_string()),
+                    None => Value::Null,
+                },
+                "target_arch" => optional_value(&rust_info.target_arch),
+                "target_env" => optional_value(&rust_info.target_env),
+                "target_os" => optional_value(&rust_info.target_os),
+                "target_pointer_width" => optional_value(&rust_info.target_pointer_width),
+                "target_vendor" => optional_value(&rust_info.target_vendor),
+                _ => optional_value(&rust_info.target_triple),
+            }
+        }
+        "crate" => match env_info.crate_info.package {
+            Some(ref package) => match name {
+                "name" => optional_value(&package.name),
+                "version" => optional_value(&package.version),
+                "description" => optional_value(&package.description),
+                "license" => optional_value(&package.license),
+                "documentation" => optional_value(&package.documentation),
+                "homepage" => optional_value(&package.homepage),
+                _ => optional_value(&package.repository),
+            },
+            None => Value::Null,
+        },
+        _ => {
+            let git_info = &env_info.git_info;
+            match name {
+                "branch" => optional_value(&git_info.current_branch),
+                "user_name" => optional_value(&git_info.user_name),
+                "user_email" => optional_value(&git_info.user_email),
+                "dirty" => match git_info.dirty {
+                    Some(dirty) => Value::Bool(dirty),
+                    None => Value::Null,
+                },
+                "last_commit_hash" => optional_value(&git_info.head.last_commit_hash),
+                _ => optional_value(&git_info.head.last_commit_hash_short),
+            }
+        }
+    }
+}
+
+fn call_function(function: Function, arguments: &[Value]) -> Result<Value, String> {
+    let result = match function {
+        Function::Exists => {
+            let file_path = environment::expand_value(&to_text(&arguments[0]));
+            !file_path.is_empty() && Path::new(&file_path).exists()
+        }
+        Function::IsSet => arguments[0] != Value::Null,
+        Function::Contains => to_text(&arguments[0]).contains(&to_text(&arguments[1])),
+        Function::StartsWith => to_text(&arguments[0]).starts_with(&to_text(&arguments[1])),
+        Function::EndsWith => to_text(&arguments[0]).ends_with(&to_text(&arguments[1])),
+        Function::Matches => {
+            let pattern = to_text(&arguments[1]);
+            match Regex::new(&pattern) {
+                Ok(regex) => regex.is_match(&to_text(&arguments[0])),
+                Err(error) => {
+                    return Err(format!(
+                        "Invalid regular expression '{}': {}",
+                        pattern, error
+                    ))
+                }
+            }
+        }
+    };
+
+    Ok(Value::Bool(result))
+}
+
+fn evaluate_expression(flow_info: &FlowInfo, expression: &Expression) -> Result<Value, String> {
+    match expression {
+        Expression::Literal(value) => Ok(value.clone()),
+        Expression::Variable(path) => Ok(get_variable(flow_info, path)),
+        Expression::Not(inner) => {
+            let value = evaluate_expression(flow_info, inner)?;
+            Ok(Value::Bool(!is_truthy(&value)))
+        }
+        Expression::And(left, right) => {
+            let value = evaluate_expression(flow_info, left)?;
+            if is_truthy(&value) {
+                let value = evaluate_expression(flow_info, right)?;
+                Ok(Value::Bool(is_truthy(&value)))
+            } else {
+                Ok(Value::Bool(false))
+            }
+        }
+        Expression::Or(left, right) => {
+            let value = evaluate_expression(flow_info, left)?;
+            if is_truthy(&value) {
+                Ok(Value::Bool(true))
+            } else {
+                let value = evaluate_expression(flow_info, right)?;
+                Ok(Value::Bool(is_truthy(&value)))
+            }
+        }
+        Expression::Compare(operator, left, right) => {
+            let left_value = evaluate_expression(flow_info, left)?;
+            let right_value = evaluate_expression(flow_info, right)?;
+            Ok(Value::Bool(evaluate_compare(
+                *operator,
+                &left_value,
+                &right_value,
+            )))
+        }
+        Expression::Call(function, arguments) => {
+            let mut values = vec![];
+            for argument in arguments {
+                values.push(evaluate_expression(flow_info, argument)?);
+            }
+
+            call_function(*function, &values)
+        }
+    }
+}
+
+/// Parses and evaluates the provided expression and returns true if the condition is met.
+pub(crate) fn evaluate(flow_info: &FlowInfo, expression: &str) -> Result<bool, String> {
+    let parsed = parse(expression)?;
+    let value = evaluate_expression(flow_info, &parsed)?;
+
+    Ok(is_truthy(&value))
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::test::create_empty_flow_info;
+use crate::types::PackageInfo;
+
+fn evaluate_with_empty_flow(expression: &str) -> Result<bool, String> {
+    let flow_info = create_empty_flow_info();
+
+    evaluate(&flow_info, expression)
+}
+
+#[test]
+fn parse_literals() {
+    assert_eq!(
+        parse("\"test\"").unwrap(),
+        Expression::Literal(Value::Str("test".to_string()))
+    );
+    assert_eq!(
+        parse("'test'").unwrap(),
+        Expression::Literal(Value::Str("test".to_string()))
+    );
+    assert_eq!(parse("12").unwrap(), Expression::Literal(Value::Number(12)));
+    assert_eq!(
+        parse("1.10").unwrap(),
+        Expression::Literal(Value::Str("1.10".to_string()))
+    );
+    assert_eq!(
+        parse("true").unwrap(),
+        Expression::Literal(Value::Bool(true))
+    );
+    assert_eq!(parse("null").unwrap(), Expression::Literal(Value::Null));
+}
+
+#[test]
+fn parse_string_escaping() {
+    assert_eq!(
+        parse(r#""a\"b\\c\n""#).unwrap(),
+        Expression::Literal(Value::Str("a\"b\\c\n".to_string()))
+    );
+}
+
+#[test]
+fn parse_precedence() {
+    let expression = parse("env.A == \"1\" || env.B == \"2\" && !env.C").unwrap();
+
+    let expected = Expression::Or(
+        Box::new(Expression::Compare(
+            CompareOperator::Equal,
+            Box::new(Expression::Variable(vec![
+                "env".to_string(),
+                "A".to_string(),
+            ])),
+            Box::new(Expression::Literal(Value::Str("1".to_string()))),
+        )),
+        Box::new(Expression::And(
+            Box::new(Expression::Compare(
+                CompareOperator::Equal,
+                Box::new(Expression::Variable(vec![
+                    "env".to_string(),
+                    "B".to_string(),
+                ])),
+                Box::new(Expression::Literal(Value::Str("2".to_string()))),
+            )),
+            Box::new(Expression::Not(Box::new(Expression::Variable(vec![
+                "env".to_string(),
+                "C".to_string(),
+            ])))),
+        )),
+    );
+
+    assert_eq!(expression, expected);
+}
+
+#[test]
+fn parse_function_call() {
+    let expression = parse("exists(\"target/skip\")").unwrap();
+
+    assert_eq!(
+        expression,
+        Expression::Call(
+            Function::Exists,
+            vec![Expression::Literal(Value::Str("target/skip".to_string()))]
+        )
+    );
+}
+
+#[test]
+fn parse_empty() {
+    let error = parse("   ").unwrap_err();
+
+    assert_eq!(error, "Empty expression at column 1");
+}
+
+#[test]
+fn parse_unterminated_string() {
+    let error = parse("env.A == \"test").unwrap_err();
+
+    assert_eq!(error, "Unterminated string literal at column 10");
+}
+
+#[test]
+fn parse_invalid_character() {
+    let error = parse("env.A = \"test\"").unwrap_err();
+
+    assert_eq!(error, "Unexpected character '=' at column 7");
+}
+
+#[test]
+fn parse_missing_close_paren() {
+    let error = parse("(os == \"linux\"").unwrap_err();
+
+    assert_eq!(error, "Unexpected end of expression at column 15");
+}
+
+#[test]
+fn parse_trailing_tokens() {
+    let error = parse("os == \"linux\" \"mac\"").unwrap_err();
+
+    assert_eq!(error, "Unexpected token \"mac\" at column 15");
+}
+
+#[test]
+fn parse_unknown_variable() {
+    let error = parse("rust.unknown == \"1\"").unwrap_err();
+
+    assert_eq!(error, "Unknown variable 'rust.unknown' at column 1");
+}
+
+#[test]
+fn parse_env_without_name() {
+    let error = parse("env == \"1\"").unwrap_err();
+
+    assert_eq!(error, "Unknown variable 'env' at column 1");
+}
+
+#[test]
+fn parse_unknown_function() {
+    let error = parse("os == \"linux\" && bad(1)").unwrap_err();
+
+    assert_eq!(error, "Unknown function 'bad' at column 18");
+}
+
+#[test]
+fn parse_function_wrong_arity() {
+    let error = parse("contains(\"abc\")").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Function 'contains' expects 2 argument(s) but got 1 at column 1"
+    );
+}
+
+#[test]
+fn evaluate_literals() {
+    assert!(evaluate_with_empty_flow("true").unwrap());
+    assert!(!evaluate_with_empty_flow("false").unwrap());
+    assert!(!evaluate_with_empty_flow("null").unwrap());
+    assert!(!evaluate_with_empty_flow("\"\"").unwrap());
+    assert!(!evaluate_with_empty_flow("\"no\"").unwrap());
+    assert!(evaluate_with_empty_flow("\"yes\"").unwrap());
+    assert!(!evaluate_with_empty_flow("0").unwrap());
+    assert!(evaluate_with_empty_flow("1").unwrap());
+}
+
+#[test]
+fn evaluate_logical_operators() {
+    assert!(evaluate_with_empty_flow("true && true").unwrap());
+    assert!(!evaluate_with_empty_flow("true && false").unwrap());
+    assert!(evaluate_with_empty_flow("false || true").unwrap());
+    assert!(!evaluate_with_empty_flow("false || false").unwrap());
+    assert!(evaluate_with_empty_flow("!false").unwrap());
+    assert!(evaluate_with_empty_flow("!(true && false)").unwrap());
+}
+
+#[test]
+fn evaluate_short_circuit() {
+    // the invalid regex is never evaluated
+    assert!(!evaluate_with_empty_flow("false && matches(\"a\", \"(\")").unwrap());
+    assert!(evaluate_with_empty_flow("true || matches(\"a\", \"(\")").unwrap());
+}
+
+#[test]
+fn evaluate_compare_operators() {
+    assert!(evaluate_with_empty_flow("\"a\" == \"a\"").unwrap());
+    assert!(evaluate_with_empty_flow("\"a\" != \"b\"").unwrap());
+    assert!(evaluate_with_empty_flow("2 > 10 == false").is_err());
+    assert!(evaluate_with_empty_flow("10 > 2").unwrap());
+    assert!(evaluate_with_empty_flow("\"10\" > 2").unwrap());
+    assert!(evaluate_with_empty_flow("2 <= 2").unwrap());
+    assert!(evaluate_with_empty_flow("\"1.70.0\" < \"1.100.0\"").unwrap());
+    assert!(evaluate_with_empty_flow("\"1.70.0\" >= \"1.70\"").unwrap());
+    assert!(evaluate_with_empty_flow("\"1.10\" > \"1.9\"").unwrap());
+    assert!(evaluate_with_empty_flow("1.10 > 1.9").unwrap());
+    assert!(!evaluate_with_empty_flow("1.10 == 1.1").unwrap());
+    assert!(evaluate_with_empty_flow("\"b\" > \"a\"").unwrap());
+    assert!(evaluate_with_empty_flow("null == null").unwrap());
+    assert!(!evaluate_with_empty_flow("null == \"\"").unwrap());
+    assert!(!evaluate_with_empty_flow("null < 1").unwrap());
+    assert!(evaluate_with_empty_flow("true == \"true\"").unwrap());
+}
+
+#[test]
+fn evaluate_functions() {
+    assert!(evaluate_with_empty_flow("contains(\"abcd\", \"bc\")").unwrap());
+    assert!(!evaluate_with_empty_flow("contains(\"abcd\", \"e\")").unwrap());
+    assert!(evaluate_with_empty_flow("starts_with(\"abcd\", \"ab\")").unwrap());
+    assert!(evaluate_with_empty_flow("ends_with(\"abcd\", \"cd\")").unwrap());
+    assert!(evaluate_with_empty_flow("matches(\"v1.2.3\", \"^v[0-9]+\")").unwrap());
+    assert!(evaluate_with_empty_flow("exists(\"./Cargo.toml\")").unwrap());
+    assert!(!evaluate_with_empty_flow("exists(\"./bad/file.txt\")").unwrap());
+    assert!(!evaluate_with_empty_flow("is_set(null)").unwrap());
+}
+
+#[test]
+fn evaluate_invalid_regex() {
+    let error = evaluate_with_empty_flow("matches(\"a\", \"(\")").unwrap_err();
+
+    assert!(error.starts_with("Invalid regular expression '('"));
+}
+
+#[test]
+fn evaluate_env() {
+    envmnt::set("CONDITION_EXPR_TEST_EVALUATE_ENV", "ci");
+    envmnt::remove("CONDITION_EXPR_TEST_EVALUATE_ENV_NOT_SET");
+
+    assert!(evaluate_with_empty_flow("env.CONDITION_EXPR_TEST_EVALUATE_ENV == \"ci\"").unwrap());
+    assert!(evaluate_with_empty_flow("is_set(env.CONDITION_EXPR_TEST_EVALUATE_ENV)").unwrap());
+    assert!(
+        !evaluate_with_empty_flow("is_set(env.CONDITION_EXPR_TEST_EVALUATE_ENV_NOT_SET)").unwrap()
+    );
+    assert!(
+        evaluate_with_empty_flow("env.CONDITION_EXPR_TEST_EVALUATE_ENV_NOT_SET == null").unwrap()
+    );
+    assert!(!evaluate_with_empty_flow("env.CONDITION_EXPR_TEST_EVALUATE_ENV_NOT_SET").unwrap());
+}
+
+#[test]
+fn evaluate_platform() {
+    let expression = format!("platform == \"{}\"", types::get_platform_name());
+
+    assert!(evaluate_with_empty_flow(&expression).unwrap());
+}
+
+#[test]
+fn evaluate_flow_info_values() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.rust_info.channel = Some(RustChannel::Nightly);
+    flow_info.env_info.rust_info.version = Some("1.80.1".to_string());
+    let mut package = PackageInfo::new();
+    package.name = Some("my_crate".to_string());
+    flow_info.env_info.crate_info.package = Some(package);
+    flow_info.env_info.git_info.current_branch = Some("main".to_string());
+    flow_info.env_info.git_info.dirty = Some(false);
+    flow_info.env_info.ci_info.ci = true;
+
+    assert!(evaluate(&flow_info, "rust.channel == \"nightly\"").unwrap());
+    assert!(evaluate(&flow_info, "rust.version >= \"1.70.0\"").unwrap());
+    assert!(evaluate(&flow_info, "crate.name == \"my_crate\"").unwrap());
+    assert!(!evaluate(&flow_info, "is_set(crate.version)").unwrap());
+    assert!(evaluate(&flow_info, "git.branch == \"main\" && !git.dirty").unwrap());
+    assert!(evaluate(&flow_info, "ci").unwrap());
+}
+
+#[test]
+fn evaluate_os_from_rust_info() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.rust_info.target_os = Some("test_os".to_string());
+
+    assert!(evaluate(&flow_info, "os == \"test_os\"").unwrap());
+    assert!(!evaluate_with_empty_flow("is_set(os)").unwrap());
+}
+
+#[test]
+fn evaluate_empty_flow_info_values() {
+    assert!(!evaluate_with_empty_flow("is_set(rust.channel)").unwrap());
+    assert!(!evaluate_with_empty_flow("is_set(crate.name)").unwrap());
+    assert!(!evaluate_with_empty_flow("is_set(git.branch)").unwrap());
+    assert!(!evaluate_with_empty_flow("ci").unwrap());
+}
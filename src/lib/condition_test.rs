@@ -1,6 +1,9 @@
 use super::*;
-use crate::test::{get_temp_test_directory, should_test_unstable};
-use crate::types::{Config, ConfigSection, CrateInfo, EnvInfo, FilesFilesModifiedCondition, Task};
+use crate::test::{create_empty_flow_info, get_temp_test_directory, should_test_unstable};
+use crate::types::{
+    Config, ConfigSection, CrateInfo, EnvInfo, FilesFilesModifiedCondition, GitChangedFilesOptions,
+    Task,
+};
 use git_info::types::GitInfo;
 use std::{thread, time::Duration};
 
@@ -50,6 +53,16 @@ fn validate_env_set_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -76,6 +89,16 @@ fn validate_env_set_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -106,6 +129,16 @@ fn validate_env_set_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -139,6 +172,16 @@ fn validate_env_set_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -165,6 +208,16 @@ fn validate_env_set_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -191,6 +244,16 @@ fn validate_env_set_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -225,6 +288,16 @@ fn validate_env_set_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -259,6 +332,16 @@ fn validate_env_set_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -285,6 +368,16 @@ fn validate_env_not_set_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -311,6 +404,16 @@ fn validate_env_not_set_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -338,6 +441,16 @@ fn validate_env_not_set_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -365,6 +478,16 @@ fn validate_env_not_set_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -395,6 +518,16 @@ fn validate_env_not_set_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -425,6 +558,16 @@ fn validate_env_not_set_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -459,6 +602,16 @@ fn validate_env_not_set_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -493,6 +646,16 @@ fn validate_env_not_set_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -519,6 +682,16 @@ fn validate_env_bool_true_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -545,6 +718,16 @@ fn validate_env_bool_true_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -575,6 +758,16 @@ fn validate_env_bool_true_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -605,6 +798,16 @@ fn validate_env_bool_true_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -635,6 +838,16 @@ fn validate_env_bool_true_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -665,6 +878,16 @@ fn validate_env_bool_true_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -695,6 +918,16 @@ fn validate_env_bool_true_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -725,6 +958,16 @@ fn validate_env_bool_true_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -755,6 +998,16 @@ fn validate_env_bool_true_invalid_partial_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -785,6 +1038,16 @@ fn validate_env_bool_true_invalid_partial_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -811,6 +1074,16 @@ fn validate_env_bool_false_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -837,6 +1110,16 @@ fn validate_env_bool_false_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -867,6 +1150,16 @@ fn validate_env_bool_false_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -897,6 +1190,16 @@ fn validate_env_bool_false_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -927,6 +1230,16 @@ fn validate_env_bool_false_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -957,6 +1270,16 @@ fn validate_env_bool_false_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -987,6 +1310,16 @@ fn validate_env_bool_false_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -1017,6 +1350,16 @@ fn validate_env_bool_false_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -1047,6 +1390,16 @@ fn validate_env_bool_false_invalid_partial_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -1077,6 +1430,16 @@ fn validate_env_bool_false_invalid_partial_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -1103,6 +1466,16 @@ fn validate_env_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1129,6 +1502,16 @@ fn validate_env_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1163,6 +1546,16 @@ fn validate_env_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1197,6 +1590,16 @@ fn validate_env_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1228,6 +1631,16 @@ fn validate_env_invalid_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1259,6 +1672,16 @@ fn validate_env_invalid_not_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1291,6 +1714,16 @@ fn validate_env_invalid_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1323,6 +1756,16 @@ fn validate_env_invalid_not_equal_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1357,6 +1800,16 @@ fn validate_env_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1391,6 +1844,16 @@ fn validate_env_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1425,6 +1888,16 @@ fn validate_env_contains_valid_same() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1459,6 +1932,16 @@ fn validate_env_contains_valid_same_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1493,6 +1976,16 @@ fn validate_env_contains_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1527,6 +2020,16 @@ fn validate_env_contains_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1558,6 +2061,16 @@ fn validate_env_contains_invalid_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1589,6 +2102,16 @@ fn validate_env_contains_invalid_not_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1621,6 +2144,16 @@ fn validate_env_contains_invalid_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1653,6 +2186,16 @@ fn validate_env_contains_invalid_not_equal_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1687,6 +2230,16 @@ fn validate_env_contains_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1721,6 +2274,16 @@ fn validate_env_contains_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1813,6 +2376,16 @@ fn validate_profile_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_profile(&condition);
@@ -1842,6 +2415,16 @@ fn validate_profile_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_profile(&condition);
@@ -1872,6 +2455,16 @@ fn validate_os_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_os(&condition);
@@ -1898,6 +2491,16 @@ fn validate_os_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_os(&condition);
@@ -1928,6 +2531,16 @@ fn validate_platform_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_platform(&condition);
@@ -1954,6 +2567,16 @@ fn validate_platform_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_platform(&condition);
@@ -1968,8 +2591,10 @@ fn validate_channel_valid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -2010,6 +2635,16 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
     let mut enabled = validate_channel(&condition, Some(&flow_info));
     assert!(enabled);
@@ -2036,6 +2671,16 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
@@ -2063,6 +2708,16 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
@@ -2076,8 +2731,10 @@ fn validate_channel_invalid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -2114,6 +2771,16 @@ fn validate_channel_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
     let enabled = validate_channel(&condition, Some(&flow_info));
 
@@ -2141,6 +2808,16 @@ fn validate_file_exists_valid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2169,6 +2846,16 @@ fn validate_file_exists_valid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2198,6 +2885,16 @@ fn validate_file_exists_partial_invalid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2227,6 +2924,16 @@ fn validate_file_exists_partial_invalid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2255,6 +2962,16 @@ fn validate_file_exists_invalid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2283,6 +3000,16 @@ fn validate_file_exists_invalid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2311,6 +3038,16 @@ fn validate_file_not_exists_valid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2339,6 +3076,16 @@ fn validate_file_not_exists_valid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2368,6 +3115,16 @@ fn validate_file_not_exists_partial_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2397,6 +3154,16 @@ fn validate_file_not_exists_partial_invalid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2425,6 +3192,16 @@ fn validate_file_not_exists_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2453,6 +3230,16 @@ fn validate_file_not_exists_invalid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2482,6 +3269,16 @@ fn validate_files_modified_all_empty() {
             input: vec![],
             output: vec![],
         }),
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_modified(&condition);
@@ -2511,6 +3308,16 @@ fn validate_files_modified_only_input() {
             input: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
             output: vec![],
         }),
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_modified(&condition);
@@ -2540,6 +3347,16 @@ fn validate_files_modified_only_output() {
             input: vec![],
             output: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
         }),
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_modified(&condition);
@@ -2569,6 +3386,16 @@ fn validate_files_modified_same_timestamp() {
             input: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
             output: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
         }),
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_files_modified(&condition);
@@ -2606,6 +3433,16 @@ fn validate_files_modified_output_newer() {
                 input: vec![src_glob],
                 output: vec![target_glob],
             }),
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         };
 
         let enabled = validate_files_modified(&condition);
@@ -2644,6 +3481,16 @@ fn validate_files_modified_input_newer() {
                 input: vec![target_glob],
                 output: vec![src_glob],
             }),
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         };
 
         let enabled = validate_files_modified(&condition);
@@ -2679,6 +3526,16 @@ fn validate_files_modified_output_newer_env() {
                 input: vec!["${DIR}/src/**/*".to_owned()],
                 output: vec!["${DIR}/target/**/*".to_owned()],
             }),
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         };
 
         let enabled = validate_files_modified(&condition);
@@ -2714,6 +3571,16 @@ fn validate_files_modified_input_newer_env() {
                 input: vec!["${DIR}/target/**/*".to_owned()],
                 output: vec!["${DIR}/src/**/*".to_owned()],
             }),
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         };
 
         let enabled = validate_files_modified(&condition);
@@ -2729,8 +3596,10 @@ fn validate_criteria_empty() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2768,6 +3637,16 @@ fn validate_criteria_empty() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -2781,8 +3660,10 @@ fn validate_criteria_valid_os() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2824,6 +3705,16 @@ fn validate_criteria_valid_os() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -2837,8 +3728,10 @@ fn validate_criteria_invalid_os() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2876,6 +3769,16 @@ fn validate_criteria_invalid_os() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -2889,8 +3792,10 @@ fn validate_criteria_valid_platform() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2932,6 +3837,16 @@ fn validate_criteria_valid_platform() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -2945,8 +3860,10 @@ fn validate_criteria_invalid_platform() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2984,6 +3901,16 @@ fn validate_criteria_invalid_platform() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -2998,8 +3925,10 @@ fn validate_criteria_valid_profile() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3037,6 +3966,16 @@ fn validate_criteria_valid_profile() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3051,8 +3990,10 @@ fn validate_criteria_invalid_profile() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3090,6 +4031,16 @@ fn validate_criteria_invalid_profile() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3103,8 +4054,10 @@ fn validate_criteria_valid_channel() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -3147,6 +4100,16 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3176,6 +4139,16 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3205,6 +4178,16 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3218,8 +4201,10 @@ fn validate_criteria_invalid_channel() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -3258,6 +4243,16 @@ fn validate_criteria_invalid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3271,8 +4266,10 @@ fn validate_criteria_valid_file_exists() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3312,6 +4309,16 @@ fn validate_criteria_valid_file_exists() {
             ]),
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3325,8 +4332,10 @@ fn validate_criteria_invalid_file_exists() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3366,6 +4375,16 @@ fn validate_criteria_invalid_file_exists() {
             ]),
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3379,8 +4398,10 @@ fn validate_criteria_valid_file_not_exists() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3420,6 +4441,16 @@ fn validate_criteria_valid_file_not_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
             ]),
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3433,8 +4464,10 @@ fn validate_criteria_invalid_file_not_exists() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3474,6 +4507,16 @@ fn validate_criteria_invalid_file_not_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
             ]),
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     );
 
@@ -3492,8 +4535,10 @@ fn validate_condition_for_step_both_valid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3533,6 +4578,16 @@ fn validate_condition_for_step_both_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3553,8 +4608,10 @@ fn validate_condition_for_step_valid_script_invalid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3594,6 +4651,16 @@ fn validate_condition_for_step_valid_script_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 1".to_string()]));
 
@@ -3614,8 +4681,10 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3655,6 +4724,16 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 1".to_string()]));
 
@@ -3675,8 +4754,10 @@ fn validate_condition_for_step_invalid_script_valid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3712,6 +4793,16 @@ fn validate_condition_for_step_invalid_script_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3733,8 +4824,10 @@ fn validate_condition_for_step_invalid_env_set() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3770,6 +4863,16 @@ fn validate_condition_for_step_invalid_env_set() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3791,8 +4894,10 @@ fn validate_condition_for_step_invalid_env_not_set() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3830,6 +4935,16 @@ fn validate_condition_for_step_invalid_env_not_set() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3851,8 +4966,10 @@ fn validate_condition_for_step_valid_env() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3895,6 +5012,16 @@ fn validate_condition_for_step_valid_env() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3916,8 +5043,10 @@ fn validate_condition_for_step_invalid_env_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3957,6 +5086,16 @@ fn validate_condition_for_step_invalid_env_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -3978,8 +5117,10 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4022,6 +5163,16 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4043,8 +5194,10 @@ fn validate_condition_for_step_valid_env_contains_same() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4087,6 +5240,16 @@ fn validate_condition_for_step_valid_env_contains_same() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4108,8 +5271,10 @@ fn validate_condition_for_step_valid_env_contains() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4152,6 +5317,16 @@ fn validate_condition_for_step_valid_env_contains() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4173,8 +5348,10 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4214,6 +5391,16 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4235,8 +5422,10 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4279,6 +5468,16 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4299,8 +5498,10 @@ fn validate_condition_for_step_valid_rust_version() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4343,6 +5544,16 @@ fn validate_condition_for_step_valid_rust_version() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
 
     let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
@@ -4362,8 +5573,10 @@ fn validate_condition_for_step_invalid_rust_version() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4407,6 +5620,16 @@ fn validate_condition_for_step_invalid_rust_version() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
 
     let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
@@ -4426,8 +5649,10 @@ fn validate_condition_for_step_both_valid_and() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4467,6 +5692,16 @@ fn validate_condition_for_step_both_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4487,8 +5722,10 @@ fn validate_condition_for_step_value_partial_valid_and() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4531,6 +5768,16 @@ fn validate_condition_for_step_value_partial_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4551,8 +5798,10 @@ fn validate_condition_for_step_group_partial_valid_and() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4592,6 +5841,16 @@ fn validate_condition_for_step_group_partial_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4612,8 +5871,10 @@ fn validate_condition_for_step_both_valid_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4653,6 +5914,16 @@ fn validate_condition_for_step_both_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4673,8 +5944,10 @@ fn validate_condition_for_step_value_partial_valid_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4717,6 +5990,16 @@ fn validate_condition_for_step_value_partial_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4737,8 +6020,10 @@ fn validate_condition_for_step_group_partial_valid_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4778,6 +6063,16 @@ fn validate_condition_for_step_group_partial_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4798,8 +6093,10 @@ fn validate_condition_for_step_both_valid_group_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4839,6 +6136,16 @@ fn validate_condition_for_step_both_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4859,8 +6166,10 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4903,6 +6212,16 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
@@ -4923,8 +6242,10 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4963,6 +6284,16 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
 
     let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
@@ -5379,6 +6710,16 @@ fn validate_rust_version_no_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -5412,6 +6753,16 @@ fn validate_rust_version_with_valid_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -5446,6 +6797,16 @@ fn validate_rust_version_with_invalid_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -5470,3 +6831,564 @@ fn get_script_text_vector() {
 
     assert_eq!(output, "line 1\nline 2");
 }
+
+#[test]
+fn validate_condition_for_step_expr_valid() {
+    let mut step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+    step.config.condition_expr = Some(format!(
+        "platform == \"{}\" && !exists(\"./bad/file.txt\")",
+        types::get_platform_name()
+    ));
+
+    let flow_info = create_empty_flow_info();
+
+    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_condition_for_step_expr_invalid() {
+    let mut step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+    step.config.condition_expr = Some("platform == \"bad\"".to_string());
+
+    let flow_info = create_empty_flow_info();
+
+    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_condition_for_step_expr_valid_script_invalid() {
+    let mut step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+    step.config.condition_expr = Some("true".to_string());
+    step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 1".to_string()]));
+
+    let flow_info = create_empty_flow_info();
+
+    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_condition_for_step_expr_parse_error() {
+    let mut step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+    step.config.condition_expr = Some("platform ==".to_string());
+
+    let flow_info = create_empty_flow_info();
+
+    let error = validate_condition_for_step(&flow_info, &step).unwrap_err();
+
+    match error {
+        CargoMakeError::InvalidConditionExpr(task, _, message) => {
+            assert_eq!(task, "test");
+            assert_eq!(message, "Unexpected end of expression at column 12");
+        }
+        _ => panic!("Invalid error type: {:?}", error),
+    }
+}
+
+fn create_flow_info_with_git_info(branch: Option<&str>, dirty: Option<bool>) -> FlowInfo {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.git_info.current_branch = branch.map(|value| value.to_string());
+    flow_info.env_info.git_info.dirty = dirty;
+
+    flow_info
+}
+
+#[test]
+fn validate_git_branch_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_git_branch(&condition, None);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_branch_valid() {
+    let flow_info = create_flow_info_with_git_info(Some("feature/test"), Some(false));
+    let mut condition = TaskCondition::default();
+    condition.git_branch = Some(vec!["main".to_string(), "feature/*".to_string()]);
+
+    let enabled = validate_git_branch(&condition, Some(&flow_info));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_branch_invalid() {
+    let flow_info = create_flow_info_with_git_info(Some("feature/test"), Some(false));
+    let mut condition = TaskCondition::default();
+    condition.git_branch = Some(vec!["main".to_string(), "release/*".to_string()]);
+
+    let enabled = validate_git_branch(&condition, Some(&flow_info));
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_git_branch_detached_ci_branch() {
+    let mut flow_info = create_flow_info_with_git_info(None, Some(false));
+    flow_info.env_info.ci_info.branch_name = Some("release/1.0".to_string());
+    let mut condition = TaskCondition::default();
+    condition.git_branch = Some(vec!["release/*".to_string()]);
+
+    let enabled = validate_git_branch(&condition, Some(&flow_info));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_dirty_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_git_dirty(&condition, None);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_dirty_valid() {
+    let flow_info = create_flow_info_with_git_info(Some("main"), Some(true));
+    let mut condition = TaskCondition::default();
+    condition.git_dirty = Some(true);
+
+    let enabled = validate_git_dirty(&condition, Some(&flow_info));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_dirty_invalid() {
+    let flow_info = create_flow_info_with_git_info(Some("main"), Some(true));
+    let mut condition = TaskCondition::default();
+    condition.git_dirty = Some(false);
+
+    let enabled = validate_git_dirty(&condition, Some(&flow_info));
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_git_changed_files_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_git_changed_files(&condition);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_git_changed_files_no_match() {
+    let mut condition = TaskCondition::default();
+    condition.git_changed_files = Some(GitChangedFilesCondition::Options(GitChangedFilesOptions {
+        files: vec!["**/not_existing_file.unknown_extension".to_string()],
+        base_ref: Some("HEAD".to_string()),
+    }));
+
+    let enabled = validate_git_changed_files(&condition);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_git_changed_files_invalid_base_ref() {
+    let mut condition = TaskCondition::default();
+    condition.git_changed_files = Some(GitChangedFilesCondition::Options(GitChangedFilesOptions {
+        files: vec!["**/*".to_string()],
+        base_ref: Some("bad_git_ref_for_validate_git_changed_files".to_string()),
+    }));
+
+    let enabled = validate_git_changed_files(&condition);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_git_tag_on_head_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_git_tag_on_head(&condition);
+
+    assert!(enabled);
+}
+
+#[test]
+#[ignore]
+fn validate_git_conditions_in_repository() {
+    let directory = get_temp_test_directory("condition_validate_git_conditions");
+    let cwd = std::env::current_dir().unwrap();
+    std::env::set_current_dir(&directory).unwrap();
+
+    get_git_command_output(&["init", "-q"]).unwrap();
+    get_git_command_output(&["config", "user.email", "test@test.com"]).unwrap();
+    get_git_command_output(&["config", "user.name", "test"]).unwrap();
+    fsio::file::write_text_file(&directory.join("src/lib.rs"), "").unwrap();
+    get_git_command_output(&["add", "-A"]).unwrap();
+    get_git_command_output(&["commit", "-q", "-m", "test"]).unwrap();
+
+    let mut condition = TaskCondition::default();
+    condition.git_changed_files = Some(GitChangedFilesCondition::Files(vec![
+        "src/**/*.rs".to_string()
+    ]));
+    condition.git_tag_on_head = Some(true);
+    let clean_changed_files = validate_git_changed_files(&condition);
+    let no_tag = validate_git_tag_on_head(&condition);
+
+    fsio::file::write_text_file(&directory.join("src/main.rs"), "").unwrap();
+    get_git_command_output(&["tag", "v1.0.0"]).unwrap();
+    let untracked_changed_files = validate_git_changed_files(&condition);
+    let tag = validate_git_tag_on_head(&condition);
+
+    std::env::set_current_dir(&cwd).unwrap();
+
+    assert!(!clean_changed_files);
+    assert!(!no_tag);
+    assert!(untracked_changed_files);
+    assert!(tag);
+}
+
+#[test]
+#[ignore]
+fn validate_git_changed_files_diverged_base_ref() {
+    let directory = get_temp_test_directory("condition_validate_git_changed_files_diverged");
+    let cwd = std::env::current_dir().unwrap();
+    std::env::set_current_dir(&directory).unwrap();
+
+    get_git_command_output(&["init", "-q"]).unwrap();
+    get_git_command_output(&["config", "user.email", "test@test.com"]).unwrap();
+    get_git_command_output(&["config", "user.name", "test"]).unwrap();
+    fsio::file::write_text_file(&directory.join("src/lib.rs"), "").unwrap();
+    get_git_command_output(&["add", "-A"]).unwrap();
+    get_git_command_output(&["commit", "-q", "-m", "initial"]).unwrap();
+    get_git_command_output(&["branch", "base"]).unwrap();
+    get_git_command_output(&["checkout", "-q", "-b", "feature"]).unwrap();
+    fsio::file::write_text_file(&directory.join("src/feature.rs"), "").unwrap();
+    get_git_command_output(&["add", "-A"]).unwrap();
+    get_git_command_output(&["commit", "-q", "-m", "feature"]).unwrap();
+    get_git_command_output(&["checkout", "-q", "base"]).unwrap();
+    fsio::file::write_text_file(&directory.join("src/base.rs"), "").unwrap();
+    get_git_command_output(&["add", "-A"]).unwrap();
+    get_git_command_output(&["commit", "-q", "-m", "base"]).unwrap();
+    get_git_command_output(&["checkout", "-q", "feature"]).unwrap();
+
+    let mut condition = TaskCondition::default();
+    condition.git_changed_files = Some(GitChangedFilesCondition::Options(GitChangedFilesOptions {
+        files: vec!["src/feature.rs".to_string()],
+        base_ref: Some("base".to_string()),
+    }));
+    let feature_changed = validate_git_changed_files(&condition);
+    condition.git_changed_files = Some(GitChangedFilesCondition::Options(GitChangedFilesOptions {
+        files: vec!["src/base.rs".to_string()],
+        base_ref: Some("base".to_string()),
+    }));
+    let base_changed = validate_git_changed_files(&condition);
+
+    std::env::set_current_dir(&cwd).unwrap();
+
+    assert!(feature_changed);
+    assert!(!base_changed);
+}
+
+#[test]
+fn validate_commands_exist_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_commands_exist(&condition, false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_commands_exist_valid() {
+    let mut condition = TaskCondition::default();
+    condition.commands_exist = Some(vec!["cargo".to_string()]);
+
+    let enabled = validate_commands_exist(&condition, false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_commands_exist_invalid() {
+    let mut condition = TaskCondition::default();
+    condition.commands_exist = Some(vec![
+        "cargo".to_string(),
+        "cargo_make_bad_command_for_test".to_string(),
+    ]);
+
+    let enabled = validate_commands_exist(&condition, false);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_commands_exist_partial_invalid_with_any() {
+    let mut condition = TaskCondition::default();
+    condition.commands_exist = Some(vec![
+        "cargo_make_bad_command_for_test".to_string(),
+        "cargo".to_string(),
+    ]);
+
+    let enabled = validate_commands_exist(&condition, true);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_target_triples_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_target_triples(&condition, None);
+
+    assert!(enabled);
+}
+
+#[test]
+#[ignore]
+fn validate_target_triples_crate_target_triple() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.rust_info.target_triple = Some("x86_64-unknown-linux-gnu".to_string());
+    let mut condition = TaskCondition::default();
+    condition.target_triples = Some(vec!["wasm32-*".to_string()]);
+
+    envmnt::set("CARGO_MAKE_CRATE_TARGET_TRIPLE", "wasm32-unknown-unknown");
+    let valid = validate_target_triples(&condition, Some(&flow_info));
+    envmnt::remove("CARGO_MAKE_CRATE_TARGET_TRIPLE");
+    let invalid = validate_target_triples(&condition, Some(&flow_info));
+
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+#[ignore]
+fn validate_target_triples_rust_target_triple() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.rust_info.target_triple = Some("x86_64-unknown-linux-gnu".to_string());
+    let mut condition = TaskCondition::default();
+    condition.target_triples = Some(vec![
+        "wasm32-*".to_string(),
+        "x86_64-unknown-linux-gnu".to_string(),
+    ]);
+
+    envmnt::remove("CARGO_MAKE_CRATE_TARGET_TRIPLE");
+    let enabled = validate_target_triples(&condition, Some(&flow_info));
+
+    assert!(enabled);
+}
+
+fn create_flow_info_with_features() -> FlowInfo {
+    let mut flow_info = create_empty_flow_info();
+    let mut features = IndexMap::new();
+    features.insert("default".to_string(), vec!["feature1".to_string()]);
+    features.insert("feature1".to_string(), vec![]);
+    flow_info.env_info.crate_info.features = Some(features);
+
+    flow_info
+}
+
+#[test]
+fn validate_crate_features_none() {
+    let condition = TaskCondition::default();
+
+    let enabled = validate_crate_features(&condition, None, false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_features_valid() {
+    let flow_info = create_flow_info_with_features();
+    let mut condition = TaskCondition::default();
+    condition.crate_features = Some(vec!["default".to_string(), "feature1".to_string()]);
+
+    let enabled = validate_crate_features(&condition, Some(&flow_info), false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_features_invalid() {
+    let flow_info = create_flow_info_with_features();
+    let mut condition = TaskCondition::default();
+    condition.crate_features = Some(vec!["feature1".to_string(), "feature2".to_string()]);
+
+    let enabled = validate_crate_features(&condition, Some(&flow_info), false);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_crate_features_partial_invalid_with_any() {
+    let flow_info = create_flow_info_with_features();
+    let mut condition = TaskCondition::default();
+    condition.crate_features = Some(vec!["feature2".to_string(), "feature1".to_string()]);
+
+    let enabled = validate_crate_features(&condition, Some(&flow_info), true);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_features_without_context() {
+    let mut condition = TaskCondition::default();
+    condition.crate_features = Some(vec!["tls-rustls".to_string()]);
+
+    let enabled = validate_crate_features(&condition, None, false);
+
+    assert!(enabled);
+}
+
+fn create_platform_condition(platform: &str) -> TaskCondition {
+    let mut condition = TaskCondition::default();
+    condition.platforms = Some(vec![platform.to_string()]);
+
+    condition
+}
+
+fn create_env_condition(key: &str, value: &str) -> TaskCondition {
+    let mut condition = TaskCondition::default();
+    let mut env = IndexMap::new();
+    env.insert(key.to_string(), value.to_string());
+    condition.env = Some(env);
+
+    condition
+}
+
+#[test]
+fn validate_criteria_nested_all_valid() {
+    let mut condition = TaskCondition::default();
+    condition.all = Some(vec![
+        create_platform_condition(&types::get_platform_name()),
+        TaskCondition::default(),
+    ]);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_criteria_nested_all_invalid() {
+    let mut condition = TaskCondition::default();
+    condition.all = Some(vec![
+        create_platform_condition(&types::get_platform_name()),
+        create_platform_condition("bad"),
+    ]);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_criteria_nested_any_valid() {
+    let mut condition = TaskCondition::default();
+    condition.any = Some(vec![
+        create_platform_condition("bad"),
+        create_platform_condition(&types::get_platform_name()),
+    ]);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_criteria_nested_any_invalid() {
+    let mut condition = TaskCondition::default();
+    condition.any = Some(vec![
+        create_platform_condition("bad1"),
+        create_platform_condition("bad2"),
+    ]);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_criteria_nested_any_empty() {
+    let mut condition = TaskCondition::default();
+    condition.any = Some(vec![]);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_criteria_nested_not() {
+    let mut condition = TaskCondition::default();
+    condition.not = Some(Box::new(create_platform_condition("bad")));
+
+    let enabled = validate_criteria(None, &Some(condition.clone()));
+    assert!(enabled);
+
+    condition.not = Some(Box::new(create_platform_condition(
+        &types::get_platform_name(),
+    )));
+
+    let enabled = validate_criteria(None, &Some(condition));
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_criteria_nested_mixed() {
+    envmnt::set("CONDITION_TEST_NESTED_MIXED_A", "false");
+    envmnt::set("CONDITION_TEST_NESTED_MIXED_B", "true");
+
+    // platform AND (env A OR env B)
+    let mut condition = create_platform_condition(&types::get_platform_name());
+    condition.any = Some(vec![
+        create_env_condition("CONDITION_TEST_NESTED_MIXED_A", "true"),
+        create_env_condition("CONDITION_TEST_NESTED_MIXED_B", "true"),
+    ]);
+
+    let enabled = validate_criteria(None, &Some(condition.clone()));
+    assert!(enabled);
+
+    condition.platforms = Some(vec!["bad".to_string()]);
+    let enabled = validate_criteria(None, &Some(condition));
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_criteria_nested_with_or_condition_type() {
+    let mut condition = create_platform_condition("bad");
+    condition.condition_type = Some(ConditionType::Or);
+    condition.not = Some(Box::new(create_platform_condition("bad")));
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_conditions_without_context_nested() {
+    let mut condition = TaskCondition::default();
+    condition.all = Some(vec![TaskCondition {
+        not: Some(Box::new(create_platform_condition("bad"))),
+        ..TaskCondition::default()
+    }]);
+
+    let enabled = validate_conditions_without_context(condition);
+
+    assert!(enabled);
+}
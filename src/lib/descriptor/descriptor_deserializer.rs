@@ -1,6 +1,7 @@
 //! # descriptor_deserializer
 //!
-//! Deserializes and validates the configs.
+//! Deserializes and validates the configs.<br>
+//! External makefiles can be written in TOML, YAML or JSON formats (chosen by the file extension).
 
 #[cfg(test)]
 #[path = "descriptor_deserializer_test.rs"]
@@ -8,6 +9,93 @@ mod descriptor_deserializer_test;
 
 use crate::error::CargoMakeError;
 use crate::types::{Config, ExternalConfig};
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// The unknown keys (file and key path) found while loading the external makefiles
+static UNKNOWN_KEYS: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(vec![]));
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The makefile format
+pub(crate) enum Format {
+    /// TOML format (default)
+    Toml,
+    /// YAML format (yaml/yml extensions)
+    Yaml,
+    /// JSON format (json extension)
+    Json,
+}
+
+impl Format {
+    /// Returns the makefile format based on the file extension
+    pub(crate) fn from_file(file: &str) -> Format {
+        let extension = Path::new(file)
+            .extension()
+            .and_then(|value| value.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        match extension.as_str() {
+            "yaml" | "yml" => Format::Yaml,
+            "json" => Format::Json,
+            _ => Format::Toml,
+        }
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(
+    descriptor_string: &str,
+    format: Format,
+    on_unknown_key: impl FnMut(serde_ignored::Path),
+) -> Result<T, String> {
+    match format {
+        Format::Toml => {
+            let deserializer = toml::de::Deserializer::new(descriptor_string);
+            serde_ignored::deserialize(deserializer, on_unknown_key)
+                .map_err(|error| error.to_string())
+        }
+        Format::Yaml => {
+            let deserializer = serde_norway::Deserializer::from_str(descriptor_string);
+            serde_ignored::deserialize(deserializer, on_unknown_key)
+                .map_err(|error| error.to_string())
+        }
+        Format::Json => {
+            let mut deserializer = serde_json::Deserializer::from_str(descriptor_string);
+            let value = serde_ignored::deserialize(&mut deserializer, on_unknown_key)
+                .map_err(|error| error.to_string())?;
+            deserializer.end().map_err(|error| error.to_string())?;
+
+            Ok(value)
+        }
+    }
+}
+
+/// Clears all recorded unknown keys
+pub(crate) fn clear_unknown_keys() {
+    UNKNOWN_KEYS.lock().unwrap().clear();
+}
+
+/// Returns the unknown keys (file and key path) found while loading the external makefiles
+pub(crate) fn get_unknown_keys() -> Vec<(String, String)> {
+    UNKNOWN_KEYS.lock().unwrap().clone()
+}
+
+fn record_unknown_key(file: &str, key: String) {
+    // optional values are shown as '?' segments in the key path
+    let key = key
+        .split('.')
+        .filter(|segment| *segment != "?")
+        .collect::<Vec<&str>>()
+        .join(".");
+    let entry = (file.to_string(), key);
+
+    let mut unknown_keys = UNKNOWN_KEYS.lock().unwrap();
+    if !unknown_keys.contains(&entry) {
+        unknown_keys.push(entry);
+    }
+}
 
 pub(crate) fn load_config(
     descriptor_string: &str,
@@ -42,18 +130,34 @@ pub(crate) fn load_external_config(
     descriptor_string: &str,
     file: &str,
 ) -> Result<ExternalConfig, CargoMakeError> {
-    let deserializer = toml::de::Deserializer::new(descriptor_string);
-
-    match serde_ignored::deserialize(deserializer, |path| {
+    match deserialize(descriptor_string, Format::from_file(file), |path| {
         warn!("Found unknown key: {} in file: {}", path, file);
+        record_unknown_key(file, path.to_string());
     }) {
         Ok(value) => Ok(value),
         Err(error) => {
             error!("Unable to parse external file: {:#?}, {}", &file, error);
-            return Err(CargoMakeError::ParseFileFailed(
-                String::from(file),
-                error.to_string(),
-            ));
+            Err(CargoMakeError::ParseFileFailed(String::from(file), error))
         }
     }
 }
+
+/// Returns the config.min_version value of the provided makefile (if defined)
+pub(crate) fn get_min_version(descriptor_string: &str, file: &str) -> Option<String> {
+    let value: serde_json::Value = match Format::from_file(file) {
+        Format::Toml => {
+            serde_json::to_value(toml::from_str::<toml::Value>(descriptor_string).ok()?)
+        }
+        Format::Yaml => serde_json::to_value(
+            serde_norway::from_str::<serde_norway::Value>(descriptor_string).ok()?,
+        ),
+        Format::Json => serde_json::from_str(descriptor_string),
+    }
+    .ok()?;
+
+    value
+        .get("config")
+        .and_then(|config| config.get("min_version"))
+        .and_then(|min_version| min_version.as_str())
+        .map(|min_version| min_version.to_string())
+}
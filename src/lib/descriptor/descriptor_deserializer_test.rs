@@ -1,5 +1,6 @@
 use super::*;
 use crate::descriptor::makefiles;
+use crate::types::{EnvRequired, EnvRequiredInfo, EnvScope, ScriptValue};
 
 #[test]
 fn load_config_base() {
@@ -77,3 +78,254 @@ category2 = "Tools"
 
     assert!(config.tasks.unwrap().contains_key("empty"));
 }
+
+#[test]
+fn load_external_config_nested_conditions() {
+    let config = load_external_config(
+        r#"
+[tasks.nested]
+condition = { platforms = ["linux"], any = [
+    { env_true = ["A"] },
+    { not = { env_set = ["B"] } },
+] }
+    "#,
+        "somefile",
+    )
+    .unwrap();
+
+    let tasks = config.tasks.unwrap();
+    let condition = tasks.get("nested").unwrap().condition.clone().unwrap();
+    assert_eq!(condition.platforms.unwrap(), vec!["linux".to_string()]);
+    let any = condition.any.unwrap();
+    assert_eq!(any.len(), 2);
+    assert_eq!(any[0].env_true.clone().unwrap(), vec!["A".to_string()]);
+    let not = any[1].not.clone().unwrap();
+    assert_eq!(not.env_set.unwrap(), vec!["B".to_string()]);
+}
+
+#[test]
+fn load_external_config_isolated_task_env() {
+    let config = load_external_config(
+        r#"
+[config]
+isolate_task_env = true
+
+[tasks.isolated]
+env_scope = "task"
+
+[tasks.global]
+env_scope = "global"
+    "#,
+        "somefile",
+    )
+    .unwrap();
+
+    assert!(config.config.unwrap().isolate_task_env.unwrap());
+    let tasks = config.tasks.unwrap();
+    assert_eq!(
+        tasks.get("isolated").unwrap().env_scope,
+        Some(EnvScope::Task)
+    );
+    assert_eq!(
+        tasks.get("global").unwrap().env_scope,
+        Some(EnvScope::Global)
+    );
+}
+
+#[test]
+fn load_external_config_env_required() {
+    let config = load_external_config(
+        r#"
+[env_required]
+DEPLOY_TARGET = { allowed_values = ["staging", "production"], help = "The deploy target" }
+DEPLOY_TOKEN = true
+
+[tasks.deploy.env_required]
+DEPLOY_VERSION = { pattern = "^\\d+\\.\\d+\\.\\d+$" }
+    "#,
+        "somefile",
+    )
+    .unwrap();
+
+    let env_required = config.env_required.unwrap();
+    assert_eq!(env_required.len(), 2);
+    assert_eq!(
+        env_required.get("DEPLOY_TARGET").unwrap(),
+        &EnvRequired::Info(EnvRequiredInfo {
+            pattern: None,
+            allowed_values: Some(vec!["staging".to_string(), "production".to_string()]),
+            help: Some("The deploy target".to_string()),
+        })
+    );
+    assert_eq!(
+        env_required.get("DEPLOY_TOKEN").unwrap(),
+        &EnvRequired::Boolean(true)
+    );
+
+    let tasks = config.tasks.unwrap();
+    let task_env_required = tasks.get("deploy").unwrap().env_required.clone().unwrap();
+    assert_eq!(
+        task_env_required.get("DEPLOY_VERSION").unwrap(),
+        &EnvRequired::Info(EnvRequiredInfo {
+            pattern: Some(r"^\d+\.\d+\.\d+$".to_string()),
+            allowed_values: None,
+            help: None,
+        })
+    );
+}
+
+#[test]
+fn load_external_config_functions() {
+    let config = load_external_config(
+        r#"
+[functions.artifact_name]
+description = "Returns the artifact name"
+script = ["output = set ${1}.tar.gz"]
+
+[functions.single_line]
+script = "output = set value"
+    "#,
+        "somefile",
+    )
+    .unwrap();
+
+    let functions = config.functions.unwrap();
+    assert_eq!(functions.len(), 2);
+    let artifact_name = functions.get("artifact_name").unwrap();
+    assert_eq!(
+        artifact_name.description.clone().unwrap(),
+        "Returns the artifact name"
+    );
+    match artifact_name.script {
+        ScriptValue::Text(ref lines) => assert_eq!(lines, &vec!["output = set ${1}.tar.gz"]),
+        _ => panic!("invalid script type"),
+    }
+    match functions.get("single_line").unwrap().script {
+        ScriptValue::SingleLine(ref line) => assert_eq!(line, "output = set value"),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn format_from_file() {
+    assert_eq!(Format::from_file("Makefile.toml"), Format::Toml);
+    assert_eq!(Format::from_file("somefile"), Format::Toml);
+    assert_eq!(Format::from_file("./dir/Makefile.yaml"), Format::Yaml);
+    assert_eq!(Format::from_file("Makefile.YML"), Format::Yaml);
+    assert_eq!(Format::from_file("Makefile.json"), Format::Json);
+}
+
+#[test]
+fn load_external_config_yaml() {
+    let config = load_external_config(
+        r#"
+extend: common.yaml
+
+env:
+  VALUE: 1
+
+tasks:
+  build:
+    description: Build
+    command: cargo
+    args: ["build"]
+    unknown_key: true
+    "#,
+        "Makefile.yaml",
+    )
+    .unwrap();
+
+    assert!(config.extend.is_some());
+    assert!(config.env.unwrap().contains_key("VALUE"));
+    let tasks = config.tasks.unwrap();
+    let task = tasks.get("build").unwrap();
+    assert_eq!(task.command.clone().unwrap(), "cargo");
+    assert_eq!(task.args.clone().unwrap(), vec!["build"]);
+}
+
+#[test]
+fn load_external_config_json() {
+    let config = load_external_config(
+        r#"
+{
+  "config": { "skip_core_tasks": true },
+  "tasks": {
+    "build": { "command": "cargo", "args": ["build"], "unknown_key": true }
+  }
+}
+    "#,
+        "Makefile.json",
+    )
+    .unwrap();
+
+    assert!(config.config.unwrap().skip_core_tasks.unwrap());
+    let tasks = config.tasks.unwrap();
+    let task = tasks.get("build").unwrap();
+    assert_eq!(task.command.clone().unwrap(), "cargo");
+}
+
+#[test]
+#[should_panic]
+fn load_external_config_yaml_invalid() {
+    load_external_config("tasks: [", "Makefile.yaml").unwrap();
+}
+
+#[test]
+#[should_panic]
+fn load_external_config_json_trailing_text() {
+    load_external_config("{} {}", "Makefile.json").unwrap();
+}
+
+#[test]
+fn get_min_version_toml() {
+    let output = get_min_version("[config]\nmin_version = \"1.2.3\"", "Makefile.toml");
+
+    assert_eq!(output.unwrap(), "1.2.3");
+}
+
+#[test]
+fn load_external_config_unknown_keys_recorded() {
+    load_external_config(
+        r#"
+[config]
+bad_config = true
+
+[tasks.test]
+bad_task = true
+"#,
+        "unknown_keys_recorded.toml",
+    )
+    .unwrap();
+
+    let unknown_keys: Vec<String> = get_unknown_keys()
+        .into_iter()
+        .filter(|(file, _)| file == "unknown_keys_recorded.toml")
+        .map(|(_, key)| key)
+        .collect();
+
+    assert_eq!(
+        unknown_keys,
+        vec!["config.bad_config", "tasks.test.bad_task"]
+    );
+}
+
+#[test]
+fn get_min_version_yaml() {
+    let output = get_min_version("config:\n  min_version: 1.2.3", "Makefile.yaml");
+
+    assert_eq!(output.unwrap(), "1.2.3");
+}
+
+#[test]
+fn get_min_version_json() {
+    let output = get_min_version(r#"{"config": {"min_version": "1.2.3"}}"#, "Makefile.json");
+
+    assert_eq!(output.unwrap(), "1.2.3");
+}
+
+#[test]
+fn get_min_version_invalid() {
+    let output = get_min_version("{", "Makefile.json");
+
+    assert!(output.is_none());
+}
@@ -1,6 +1,7 @@
 use crate::error::CargoMakeError;
 use crate::types::{
-    EnvFile, EnvValue, EnvValueConditioned, EnvValueDecode, EnvValuePathGlob, EnvValueScript,
+    EnvFile, EnvRequired, EnvValue, EnvValueCommand, EnvValueConditioned, EnvValueDataPath,
+    EnvValueDecode, EnvValueFile, EnvValuePathGlob, EnvValueScript, EnvValueSecret,
 };
 use indexmap::{IndexMap, IndexSet};
 use once_cell::sync::Lazy;
@@ -115,8 +116,33 @@ fn env_depends_on(val: &EnvValue) -> Vec<&str> {
                 acc
             })
             .unwrap_or_default(),
+        EnvValue::Secret(EnvValueSecret { value, .. }) => env_depends_on_find(value),
         EnvValue::Conditional(EnvValueConditioned { value, .. }) => env_depends_on_find(value),
         EnvValue::PathGlob(EnvValuePathGlob { glob, .. }) => env_depends_on_find(glob),
+        EnvValue::File(EnvValueFile { from_file, .. }) => env_depends_on_find(from_file),
+        EnvValue::Command(EnvValueCommand { command, args, .. }) => {
+            let mut depends_on = env_depends_on_find(command);
+            for arg in args.iter().flatten() {
+                depends_on.append(&mut env_depends_on_find(arg));
+            }
+
+            depends_on
+        }
+        EnvValue::DataPath(EnvValueDataPath {
+            toml,
+            json,
+            yaml,
+            path,
+            default_value,
+            ..
+        }) => {
+            let mut depends_on = env_depends_on_find(path);
+            for value in [toml, json, yaml, default_value].into_iter().flatten() {
+                depends_on.append(&mut env_depends_on_find(value));
+            }
+
+            depends_on
+        }
         EnvValue::Script(script) => env_depends_on_find_script(script),
         _ => vec![],
     }
@@ -223,3 +249,17 @@ pub(crate) fn merge_env_files(
 pub(crate) fn merge_env_scripts(base: &mut Vec<String>, extended: &mut Vec<String>) -> Vec<String> {
     [&extended[..], &base[..]].concat()
 }
+
+pub(crate) fn merge_env_required(
+    base: Option<IndexMap<String, EnvRequired>>,
+    extended: Option<IndexMap<String, EnvRequired>>,
+) -> Option<IndexMap<String, EnvRequired>> {
+    match (base, extended) {
+        (Some(mut base_env_required), Some(extended_env_required)) => {
+            base_env_required.extend(extended_env_required);
+            Some(base_env_required)
+        }
+        (base_env_required, None) => base_env_required,
+        (None, extended_env_required) => extended_env_required,
+    }
+}
@@ -1,7 +1,10 @@
-use crate::descriptor::env::merge_env;
+use crate::descriptor::env::{merge_env, merge_env_required};
 use crate::descriptor::load;
 use crate::environment;
-use crate::types::{EnvValue, EnvValueConditioned, EnvValuePathGlob, EnvValueScript};
+use crate::types::{
+    EnvRequired, EnvValue, EnvValueCommand, EnvValueConditioned, EnvValueDataPath, EnvValueFile,
+    EnvValuePathGlob, EnvValueScript,
+};
 use indexmap::IndexMap;
 
 #[test]
@@ -138,6 +141,109 @@ fn merge_env_reorder_conditional() {
     assert_eq!(output.get_index(1).unwrap().0.as_str(), "env2");
 }
 
+#[test]
+fn merge_env_reorder_file() {
+    let mut map1 = IndexMap::new();
+    map1.insert(
+        "env2".to_owned(),
+        EnvValue::File(EnvValueFile {
+            from_file: "${env1}/VERSION".to_string(),
+            trim: None,
+            condition: None,
+        }),
+    );
+    map1.insert("env1".to_owned(), EnvValue::Value("4".to_owned()));
+
+    let map2 = IndexMap::new();
+
+    let output = merge_env(&map1, &map2).expect("should have no cycle");
+    assert_eq!(output.len(), 2);
+    assert_eq!(output.get_index(0).unwrap().0.as_str(), "env1");
+    assert_eq!(output.get_index(1).unwrap().0.as_str(), "env2");
+}
+
+#[test]
+fn merge_env_reorder_data_path() {
+    let mut map1 = IndexMap::new();
+    map1.insert(
+        "env3".to_owned(),
+        EnvValue::DataPath(EnvValueDataPath {
+            toml: Some("${env1}/Cargo.toml".to_string()),
+            json: None,
+            yaml: None,
+            path: "package.${env2}".to_string(),
+            default_value: None,
+            condition: None,
+        }),
+    );
+    map1.insert("env2".to_owned(), EnvValue::Value("${env1}".to_owned()));
+    map1.insert("env1".to_owned(), EnvValue::Value("4".to_owned()));
+
+    let map2 = IndexMap::new();
+
+    let output = merge_env(&map1, &map2).expect("should have no cycle");
+    assert_eq!(output.len(), 3);
+    assert_eq!(output.get_index(0).unwrap().0.as_str(), "env1");
+    assert_eq!(output.get_index(1).unwrap().0.as_str(), "env2");
+    assert_eq!(output.get_index(2).unwrap().0.as_str(), "env3");
+}
+
+#[test]
+fn merge_env_cycle_data_path() {
+    let mut map1 = IndexMap::new();
+    map1.insert(
+        "env2".to_owned(),
+        EnvValue::DataPath(EnvValueDataPath {
+            toml: None,
+            json: Some("./data.json".to_string()),
+            yaml: None,
+            path: "key".to_string(),
+            default_value: Some("${env1}".to_string()),
+            condition: None,
+        }),
+    );
+
+    let mut map2 = IndexMap::new();
+    map2.insert(
+        "env1".to_owned(),
+        EnvValue::File(EnvValueFile {
+            from_file: "${env2}".to_string(),
+            trim: None,
+            condition: None,
+        }),
+    );
+
+    let output = merge_env(&map1, &map2)
+        .expect_err("should have cycle")
+        .to_string();
+    assert!(output.ends_with("env2 -> env1 -> env2.") || output.ends_with("env1 -> env2 -> env1."));
+}
+
+#[test]
+fn merge_env_reorder_command() {
+    let mut map1 = IndexMap::new();
+    map1.insert(
+        "env3".to_owned(),
+        EnvValue::Command(EnvValueCommand {
+            command: "${env1}".to_string(),
+            args: Some(vec!["--flag".to_string(), "${env2}".to_string()]),
+            trim: None,
+            allow_failure: None,
+            default: None,
+        }),
+    );
+    map1.insert("env2".to_owned(), EnvValue::Value("${env1}".to_owned()));
+    map1.insert("env1".to_owned(), EnvValue::Value("4".to_owned()));
+
+    let map2 = IndexMap::new();
+
+    let output = merge_env(&map1, &map2).expect("should have no cycle");
+    assert_eq!(output.len(), 3);
+    assert_eq!(output.get_index(0).unwrap().0.as_str(), "env1");
+    assert_eq!(output.get_index(1).unwrap().0.as_str(), "env2");
+    assert_eq!(output.get_index(2).unwrap().0.as_str(), "env3");
+}
+
 #[test]
 fn merge_env_reorder_internal() {
     let mut map1 = IndexMap::new();
@@ -440,3 +546,47 @@ fn load_env_reorder_extend() {
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4", "ENV5", "ENV6"]);
 }
+
+#[test]
+fn merge_env_required_both_none() {
+    let output = merge_env_required(None, None);
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn merge_env_required_base_only() {
+    let mut base = IndexMap::new();
+    base.insert("BASE".to_string(), EnvRequired::Boolean(true));
+
+    let output = merge_env_required(Some(base.clone()), None);
+
+    assert_eq!(output, Some(base));
+}
+
+#[test]
+fn merge_env_required_extended_only() {
+    let mut extended = IndexMap::new();
+    extended.insert("EXTENDED".to_string(), EnvRequired::Boolean(true));
+
+    let output = merge_env_required(None, Some(extended.clone()));
+
+    assert_eq!(output, Some(extended));
+}
+
+#[test]
+fn merge_env_required_both() {
+    let mut base = IndexMap::new();
+    base.insert("BASE".to_string(), EnvRequired::Boolean(true));
+    base.insert("BOTH".to_string(), EnvRequired::Boolean(true));
+    let mut extended = IndexMap::new();
+    extended.insert("BOTH".to_string(), EnvRequired::Boolean(false));
+    extended.insert("EXTENDED".to_string(), EnvRequired::Boolean(true));
+
+    let output = merge_env_required(Some(base), Some(extended)).unwrap();
+
+    assert_eq!(output.len(), 3);
+    assert_eq!(output.get("BASE").unwrap(), &EnvRequired::Boolean(true));
+    assert_eq!(output.get("BOTH").unwrap(), &EnvRequired::Boolean(false));
+    assert_eq!(output.get("EXTENDED").unwrap(), &EnvRequired::Boolean(true));
+}
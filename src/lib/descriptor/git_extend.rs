@@ -0,0 +1,205 @@
+//! # git_extend
+//!
+//! Provides makefiles from git repositories for the extend attribute.<br>
+//! Each repository revision is cloned once into a cache under the storage directory.<br>
+//! Cached commit SHAs are reused as is, while cached tags and branches are refreshed from the
+//! remote (unless running in offline mode).
+//!
+
+#[cfg(test)]
+#[path = "git_extend_test.rs"]
+mod git_extend_test;
+
+use crate::error::CargoMakeError;
+use crate::storage;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+static CACHE_DIRECTORY: &str = "extend";
+
+/// Returns true if offline mode is enabled (git repositories are only loaded from the cache)
+pub(crate) fn is_offline() -> bool {
+    envmnt::is("CARGO_MAKE_OFFLINE")
+}
+
+fn get_cache_directory() -> Option<PathBuf> {
+    let os_directory = dirs_next::cache_dir();
+    storage::get_storage_directory(os_directory, CACHE_DIRECTORY, false)
+        .map(|directory| directory.join(CACHE_DIRECTORY))
+}
+
+fn get_repository_key(url: &str) -> String {
+    let hash = format!("{:x}", Sha256::digest(url.as_bytes()));
+    hash[..16].to_string()
+}
+
+fn get_revision_key(rev: &str) -> String {
+    rev.chars()
+        .map(|value| {
+            if value.is_alphanumeric() || value == '-' || value == '_' || value == '.' {
+                value
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Returns true if the revision is a (possibly abbreviated) commit SHA which can not move once cached
+fn is_commit_sha(rev: &str) -> bool {
+    (7..=64).contains(&rev.len()) && rev.chars().all(|value| value.is_ascii_hexdigit())
+}
+
+fn run_git(args: &[&str], directory: Option<&Path>) -> Result<(), String> {
+    let mut command = Command::new("git");
+    if let Some(directory) = directory {
+        command.current_dir(directory);
+    }
+
+    match command.args(args).output() {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+            }
+        }
+        Err(error) => Err(format!("unable to run git, {}", error)),
+    }
+}
+
+fn checkout_revision(rev: &str, directory: &Path) -> Result<(), String> {
+    run_git(
+        &[
+            "-c",
+            "advice.detachedHead=false",
+            "checkout",
+            "--quiet",
+            "--detach",
+            rev,
+            "--",
+        ],
+        Some(directory),
+    )
+}
+
+fn refresh_repository(rev: &str, directory: &Path) -> Result<(), String> {
+    // fetch the revision from the remote so moved tags and branches are picked up
+    run_git(
+        &["fetch", "--quiet", "--force", "--tags", "origin", "--", rev],
+        Some(directory),
+    )?;
+    checkout_revision("FETCH_HEAD", directory)
+}
+
+fn clone_repository(url: &str, rev: &str, directory: &Path) -> Result<(), String> {
+    let directory_string = directory.to_string_lossy();
+    run_git(
+        &[
+            "clone",
+            "--quiet",
+            "--no-checkout",
+            "--",
+            url,
+            &directory_string,
+        ],
+        None,
+    )?;
+
+    if is_commit_sha(rev) {
+        checkout_revision(rev, directory)
+    } else {
+        refresh_repository(rev, directory)
+    }
+}
+
+/// Returns the directory of the repository checked out at the requested revision.<br>
+/// The repository is cloned into the provided cache directory in case it is not already cached.<br>
+/// Cached revisions which are not commit SHAs are refreshed from the remote when not offline.
+pub(crate) fn get_repository_directory(
+    cache_directory: &Path,
+    url: &str,
+    rev: &str,
+    offline: bool,
+) -> Result<PathBuf, CargoMakeError> {
+    let repository_directory = cache_directory.join(get_repository_key(url));
+    let directory = repository_directory.join(get_revision_key(rev));
+
+    if directory.join(".git").exists() {
+        if !offline && !is_commit_sha(rev) {
+            info!("Refreshing git repository: {} revision: {}", url, rev);
+
+            if let Err(error) = refresh_repository(rev, &directory) {
+                warn!(
+                    "Unable to refresh git repository: {} revision: {}, using cached version. {}",
+                    url, rev, error
+                );
+            }
+        }
+
+        debug!(
+            "Using cached git repository: {} revision: {} directory: {:?}",
+            url, rev, &directory
+        );
+        return Ok(directory);
+    }
+
+    if offline {
+        return Err(CargoMakeError::GitExtend(
+            url.to_string(),
+            format!(
+                "revision: {} is not cached and offline mode is enabled",
+                rev
+            ),
+        ));
+    }
+
+    info!("Fetching git repository: {} revision: {}", url, rev);
+
+    // clone into a temporary directory so failed clones do not leave a partial cache entry
+    let temp_directory = repository_directory.join(format!("{}.tmp", get_revision_key(rev)));
+    if temp_directory.exists() {
+        fs::remove_dir_all(&temp_directory)?;
+    }
+    fs::create_dir_all(&repository_directory)?;
+
+    match clone_repository(url, rev, &temp_directory) {
+        Ok(_) => {
+            fs::rename(&temp_directory, &directory)?;
+            Ok(directory)
+        }
+        Err(error) => {
+            if temp_directory.exists() {
+                fs::remove_dir_all(&temp_directory)?;
+            }
+
+            Err(CargoMakeError::GitExtend(
+                url.to_string(),
+                format!("unable to fetch revision: {}, {}", rev, error),
+            ))
+        }
+    }
+}
+
+/// Returns the cached directory of the git repository checked out at the requested revision
+pub(crate) fn get_directory(url: &str, rev: &Option<String>) -> Result<PathBuf, CargoMakeError> {
+    let rev = match rev {
+        Some(rev) if !rev.is_empty() => rev,
+        _ => {
+            return Err(CargoMakeError::GitExtend(
+                url.to_string(),
+                "missing rev (commit sha or tag) to pin the repository to".to_string(),
+            ))
+        }
+    };
+
+    match get_cache_directory() {
+        Some(cache_directory) => get_repository_directory(&cache_directory, url, rev, is_offline()),
+        None => Err(CargoMakeError::GitExtend(
+            url.to_string(),
+            "unable to find the cache directory".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,225 @@
+use super::*;
+use crate::test;
+use fsio::file::{read_text_file, write_text_file};
+use std::process::Command;
+
+fn git(directory: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .current_dir(directory)
+        .args(&["-c", "user.name=test", "-c", "user.email=test@test.com"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn commit_makefile(directory: &Path, content: &str) -> String {
+    write_text_file(&directory.join("makefiles/rust.toml"), content).unwrap();
+    git(directory, &["add", "-A"]);
+    git(directory, &["commit", "--quiet", "-m", content]);
+
+    git(directory, &["rev-parse", "HEAD"])
+}
+
+fn create_repository(name: &str) -> (PathBuf, String) {
+    let directory = test::get_temp_test_directory(name);
+    let repository = directory.join("repository");
+    fsio::directory::create(&repository).unwrap();
+
+    git(&repository, &["init", "--quiet"]);
+
+    let url = format!("file://{}", fsio::path::canonicalize_or(&repository, ""));
+
+    (directory, url)
+}
+
+fn read_makefile(directory: &Path) -> String {
+    read_text_file(&directory.join("makefiles/rust.toml")).unwrap()
+}
+
+#[test]
+fn get_revision_key_valid() {
+    assert_eq!(get_revision_key("v1.0.0"), "v1.0.0");
+    assert_eq!(get_revision_key("release/v1"), "release_v1");
+}
+
+#[test]
+fn is_commit_sha_valid() {
+    assert!(is_commit_sha("3a5f1c9d2e4b6a8c0f1e3d5b7a9c2e4f6a8b0c1d"));
+    assert!(is_commit_sha("3a5f1c9"));
+    assert!(!is_commit_sha("3a5f"));
+    assert!(!is_commit_sha("v1.0.0"));
+    assert!(!is_commit_sha("main"));
+}
+
+#[test]
+fn get_repository_key_same_url() {
+    assert_eq!(
+        get_repository_key("https://example.com/repo.git"),
+        get_repository_key("https://example.com/repo.git")
+    );
+    assert_ne!(
+        get_repository_key("https://example.com/repo.git"),
+        get_repository_key("https://example.com/other.git")
+    );
+}
+
+#[test]
+fn get_repository_directory_tag() {
+    let (directory, url) = create_repository("git_extend_tag");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["tag", "v1"]);
+    commit_makefile(&repository, "second");
+
+    let output = get_repository_directory(&directory.join("cache"), &url, "v1", false).unwrap();
+
+    assert_eq!(read_makefile(&output), "first");
+}
+
+#[test]
+fn get_repository_directory_sha() {
+    let (directory, url) = create_repository("git_extend_sha");
+    let repository = directory.join("repository");
+    let sha = commit_makefile(&repository, "first");
+    commit_makefile(&repository, "second");
+
+    let output = get_repository_directory(&directory.join("cache"), &url, &sha, false).unwrap();
+
+    assert_eq!(read_makefile(&output), "first");
+}
+
+#[test]
+fn get_repository_directory_cached() {
+    let (directory, url) = create_repository("git_extend_cached");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["tag", "v1"]);
+    let cache_directory = directory.join("cache");
+
+    let first = get_repository_directory(&cache_directory, &url, "v1", false).unwrap();
+
+    // the cache is used even if the repository is no longer available
+    fsio::directory::delete(&repository).unwrap();
+    let second = get_repository_directory(&cache_directory, &url, "v1", true).unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(read_makefile(&second), "first");
+}
+
+#[test]
+fn get_repository_directory_cached_tag_refreshed() {
+    let (directory, url) = create_repository("git_extend_cached_tag_refreshed");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["tag", "v1"]);
+    let cache_directory = directory.join("cache");
+
+    let first = get_repository_directory(&cache_directory, &url, "v1", false).unwrap();
+    assert_eq!(read_makefile(&first), "first");
+
+    commit_makefile(&repository, "second");
+    git(&repository, &["tag", "--force", "v1"]);
+
+    let offline = get_repository_directory(&cache_directory, &url, "v1", true).unwrap();
+    assert_eq!(read_makefile(&offline), "first");
+
+    let second = get_repository_directory(&cache_directory, &url, "v1", false).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(read_makefile(&second), "second");
+}
+
+#[test]
+fn get_repository_directory_cached_branch_refreshed() {
+    let (directory, url) = create_repository("git_extend_cached_branch_refreshed");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["branch", "--force", "release"]);
+    let cache_directory = directory.join("cache");
+
+    let first = get_repository_directory(&cache_directory, &url, "release", false).unwrap();
+    assert_eq!(read_makefile(&first), "first");
+
+    commit_makefile(&repository, "second");
+    git(&repository, &["branch", "--force", "release"]);
+
+    let second = get_repository_directory(&cache_directory, &url, "release", false).unwrap();
+    assert_eq!(read_makefile(&second), "second");
+}
+
+#[test]
+fn get_repository_directory_cached_refresh_failed() {
+    let (directory, url) = create_repository("git_extend_cached_refresh_failed");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["tag", "v1"]);
+    let cache_directory = directory.join("cache");
+
+    get_repository_directory(&cache_directory, &url, "v1", false).unwrap();
+    fsio::directory::delete(&repository).unwrap();
+
+    // the cached revision is used in case the remote is not available
+    let output = get_repository_directory(&cache_directory, &url, "v1", false).unwrap();
+
+    assert_eq!(read_makefile(&output), "first");
+}
+
+#[test]
+fn get_repository_directory_option_url() {
+    let directory = test::get_temp_test_directory("git_extend_option_url");
+
+    let output = get_repository_directory(
+        &directory.join("cache"),
+        "--upload-pack=touch injected",
+        "v1",
+        false,
+    );
+
+    assert!(output.is_err());
+    assert!(!Path::new("injected").exists());
+}
+
+#[test]
+fn get_repository_directory_offline_not_cached() {
+    let (directory, url) = create_repository("git_extend_offline_not_cached");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    git(&repository, &["tag", "v1"]);
+
+    let output = get_repository_directory(&directory.join("cache"), &url, "v1", true);
+
+    match output {
+        Err(CargoMakeError::GitExtend(error_url, message)) => {
+            assert_eq!(error_url, url);
+            assert!(message.contains("offline"));
+        }
+        _ => panic!("expected offline error"),
+    }
+}
+
+#[test]
+fn get_repository_directory_invalid_rev() {
+    let (directory, url) = create_repository("git_extend_invalid_rev");
+    let repository = directory.join("repository");
+    commit_makefile(&repository, "first");
+    let cache_directory = directory.join("cache");
+
+    let output = get_repository_directory(&cache_directory, &url, "v1", false);
+
+    assert!(output.is_err());
+    let repository_directory = cache_directory.join(get_repository_key(&url));
+    assert!(!repository_directory.join("v1").exists());
+    assert!(!repository_directory.join("v1.tmp").exists());
+}
+
+#[test]
+fn get_directory_missing_rev() {
+    let output = get_directory("https://example.com/repo.git", &None);
+
+    match output {
+        Err(CargoMakeError::GitExtend(_, message)) => assert!(message.contains("missing rev")),
+        _ => panic!("expected missing rev error"),
+    }
+}
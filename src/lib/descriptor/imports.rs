@@ -0,0 +1,206 @@
+//! # imports
+//!
+//! Applies the namespace of extended makefiles and keeps track of tasks which are defined in
+//! multiple makefiles (task collisions).<br>
+//! A namespaced makefile has all its task names prefixed with the namespace, as well as all
+//! references (dependencies, run_task, alias, extend and config tasks) to its own tasks.
+//!
+
+#[cfg(test)]
+#[path = "imports_test.rs"]
+mod imports_test;
+
+use crate::types::{
+    ConfigSection, DependencyIdentifier, EnvValue, ExternalConfig, ModifyConfig,
+    PlatformOverrideTask, RunTaskInfo, RunTaskName, Task,
+};
+use indexmap::{IndexMap, IndexSet};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A task defined in multiple makefiles
+pub(crate) struct TaskCollision {
+    /// The task name
+    pub(crate) task: String,
+    /// The makefile which overrides the task
+    pub(crate) file: String,
+    /// The makefile in which the overridden task was defined
+    pub(crate) overridden_file: String,
+}
+
+static COLLISIONS: Lazy<Mutex<Vec<TaskCollision>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// Clears all recorded task collisions
+pub(crate) fn clear_collisions() {
+    COLLISIONS.lock().unwrap().clear();
+}
+
+/// Returns all recorded task collisions
+pub(crate) fn get_collisions() -> Vec<TaskCollision> {
+    COLLISIONS.lock().unwrap().clone()
+}
+
+fn get_makefile(task: &Task) -> Option<String> {
+    match task.env {
+        Some(ref env) => match env.get("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE") {
+            Some(EnvValue::Value(file)) => Some(file.to_string()),
+            _ => None,
+        },
+        None => None,
+    }
+}
+
+/// Records (and warns about) the tasks of the extending makefile which override tasks of another
+/// makefile
+pub(crate) fn record_collisions(
+    parent_tasks: &IndexMap<String, Task>,
+    extended_tasks: &IndexMap<String, Task>,
+) {
+    let mut collisions = COLLISIONS.lock().unwrap();
+
+    for (name, task) in extended_tasks {
+        let parent_file = parent_tasks.get(name).and_then(get_makefile);
+
+        if let (Some(file), Some(overridden_file)) = (get_makefile(task), parent_file) {
+            if file != overridden_file {
+                warn!(
+                    "Task: {} defined in: {} overrides the task defined in: {}, use the extend namespace attribute to avoid the collision.",
+                    name, &file, &overridden_file
+                );
+
+                collisions.push(TaskCollision {
+                    task: name.to_string(),
+                    file,
+                    overridden_file,
+                });
+            }
+        }
+    }
+}
+
+fn rename_option(name: &mut Option<String>, rename: &impl Fn(&str) -> String) {
+    if let Some(value) = name {
+        *value = rename(value);
+    }
+}
+
+fn rename_run_task_name(name: &mut RunTaskName, rename: &impl Fn(&str) -> String) {
+    match name {
+        RunTaskName::Single(value) => *value = rename(value),
+        RunTaskName::Multiple(values) => {
+            for value in values.iter_mut() {
+                *value = rename(value);
+            }
+        }
+    }
+}
+
+fn rename_references(
+    dependencies: &mut Option<Vec<DependencyIdentifier>>,
+    run_task: &mut Option<RunTaskInfo>,
+    extend: &mut Option<String>,
+    rename: &impl Fn(&str) -> String,
+) {
+    if let Some(dependencies) = dependencies {
+        for dependency in dependencies.iter_mut() {
+            match dependency {
+                DependencyIdentifier::Name(name) => *name = rename(name),
+                // dependencies from other makefiles are not part of the namespace
+                DependencyIdentifier::Definition(identifier) if identifier.path.is_none() => {
+                    identifier.name = rename(&identifier.name)
+                }
+                DependencyIdentifier::Definition(_) => (),
+            }
+        }
+    }
+
+    if let Some(run_task) = run_task {
+        match run_task {
+            RunTaskInfo::Name(name) => *name = rename(name),
+            RunTaskInfo::Details(details) => {
+                rename_run_task_name(&mut details.name, rename);
+                rename_option(&mut details.cleanup_task, rename);
+            }
+            RunTaskInfo::Routing(routing_info) => {
+                for info in routing_info.iter_mut() {
+                    rename_run_task_name(&mut info.name, rename);
+                    rename_option(&mut info.cleanup_task, rename);
+                }
+            }
+        }
+    }
+
+    rename_option(extend, rename);
+}
+
+fn rename_override_task(task: &mut Option<PlatformOverrideTask>, rename: &impl Fn(&str) -> String) {
+    if let Some(task) = task {
+        rename_references(
+            &mut task.dependencies,
+            &mut task.run_task,
+            &mut task.extend,
+            rename,
+        );
+    }
+}
+
+fn rename_task(task: &mut Task, rename: &impl Fn(&str) -> String) {
+    rename_references(
+        &mut task.dependencies,
+        &mut task.run_task,
+        &mut task.extend,
+        rename,
+    );
+    rename_override_task(&mut task.linux, rename);
+    rename_override_task(&mut task.windows, rename);
+    rename_override_task(&mut task.mac, rename);
+
+    rename_option(&mut task.alias, rename);
+    rename_option(&mut task.linux_alias, rename);
+    rename_option(&mut task.windows_alias, rename);
+    rename_option(&mut task.mac_alias, rename);
+}
+
+fn rename_config(config: &mut ConfigSection, rename: &impl Fn(&str) -> String) {
+    rename_option(&mut config.init_task, rename);
+    rename_option(&mut config.end_task, rename);
+    rename_option(&mut config.on_error_task, rename);
+    rename_option(&mut config.legacy_migration_task, rename);
+}
+
+/// Prefixes all tasks of the provided makefile config, and all references to them, with the namespace
+pub(crate) fn apply_namespace(config: &mut ExternalConfig, namespace: &str) {
+    if namespace.is_empty() {
+        return;
+    }
+
+    let prefix = ModifyConfig {
+        private: None,
+        namespace: Some(namespace.to_string()),
+    }
+    .get_namespace_prefix();
+
+    let tasks = config.tasks.take().unwrap_or_default();
+    let task_names: IndexSet<String> = tasks.keys().cloned().collect();
+
+    // references to tasks which are not defined in the makefile (for example core tasks) are kept
+    let rename = |name: &str| {
+        if task_names.contains(name) {
+            format!("{}{}", &prefix, name)
+        } else {
+            name.to_string()
+        }
+    };
+
+    let mut namespaced_tasks = IndexMap::new();
+    for (name, mut task) in tasks {
+        rename_task(&mut task, &rename);
+        namespaced_tasks.insert(rename(&name), task);
+    }
+    config.tasks = Some(namespaced_tasks);
+
+    if let Some(ref mut config_section) = config.config {
+        rename_config(config_section, &rename);
+    }
+}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::types::{RunTaskDetails, TaskIdentifier};
+
+fn create_task(file: &str) -> Task {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value(file.to_string()),
+    );
+
+    let mut task = Task::new();
+    task.env = Some(env);
+    task
+}
+
+#[test]
+fn apply_namespace_empty() {
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), Task::new());
+    let mut config = ExternalConfig::new();
+    config.tasks = Some(tasks);
+
+    apply_namespace(&mut config, "");
+
+    assert!(config.tasks.unwrap().contains_key("build"));
+}
+
+#[test]
+fn apply_namespace_tasks_and_references() {
+    let mut build = Task::new();
+    build.dependencies = Some(vec![
+        "clean".into(),
+        "core".into(),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "clean".to_string(),
+            path: Some("other.toml".to_string()),
+        }),
+    ]);
+    build.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(vec!["clean".to_string(), "core".to_string()]),
+        fork: None,
+        parallel: None,
+        cleanup_task: Some("clean".to_string()),
+    }));
+    build.alias = Some("clean".to_string());
+    build.extend = Some("core".to_string());
+    let mut linux: PlatformOverrideTask = serde_json::from_str("{}").unwrap();
+    linux.run_task = Some(RunTaskInfo::Name("clean".to_string()));
+    build.linux = Some(linux);
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), build);
+    tasks.insert("clean".to_string(), Task::new());
+    let mut config_section = ConfigSection::new();
+    config_section.init_task = Some("clean".to_string());
+    config_section.end_task = Some("end".to_string());
+    let mut config = ExternalConfig::new();
+    config.tasks = Some(tasks);
+    config.config = Some(config_section);
+
+    apply_namespace(&mut config, "shared");
+
+    let tasks = config.tasks.unwrap();
+    assert_eq!(
+        tasks.keys().collect::<Vec<_>>(),
+        vec!["shared::build", "shared::clean"]
+    );
+    let build = &tasks["shared::build"];
+    assert_eq!(
+        build.dependencies.clone().unwrap(),
+        vec![
+            "shared::clean".into(),
+            "core".into(),
+            DependencyIdentifier::Definition(TaskIdentifier {
+                name: "clean".to_string(),
+                path: Some("other.toml".to_string()),
+            }),
+        ]
+    );
+    match build.run_task.clone().unwrap() {
+        RunTaskInfo::Details(details) => {
+            assert_eq!(
+                details.name,
+                RunTaskName::Multiple(vec!["shared::clean".to_string(), "core".to_string()])
+            );
+            assert_eq!(details.cleanup_task.unwrap(), "shared::clean");
+        }
+        _ => panic!("invalid run task"),
+    }
+    assert_eq!(build.alias.clone().unwrap(), "shared::clean");
+    assert_eq!(build.extend.clone().unwrap(), "core");
+    match build.linux.clone().unwrap().run_task.unwrap() {
+        RunTaskInfo::Name(name) => assert_eq!(name, "shared::clean"),
+        _ => panic!("invalid run task"),
+    }
+    let config_section = config.config.unwrap();
+    assert_eq!(config_section.init_task.unwrap(), "shared::clean");
+    assert_eq!(config_section.end_task.unwrap(), "end");
+}
+
+#[test]
+#[ignore]
+fn record_collisions_different_files() {
+    let mut parent_tasks = IndexMap::new();
+    parent_tasks.insert("build".to_string(), create_task("shared.toml"));
+    parent_tasks.insert("clean".to_string(), create_task("Makefile.toml"));
+    parent_tasks.insert("core".to_string(), Task::new());
+    let mut extended_tasks = IndexMap::new();
+    extended_tasks.insert("build".to_string(), create_task("Makefile.toml"));
+    extended_tasks.insert("clean".to_string(), create_task("Makefile.toml"));
+    extended_tasks.insert("core".to_string(), create_task("Makefile.toml"));
+    extended_tasks.insert("test".to_string(), create_task("Makefile.toml"));
+
+    clear_collisions();
+    record_collisions(&parent_tasks, &extended_tasks);
+
+    assert_eq!(
+        get_collisions(),
+        vec![TaskCollision {
+            task: "build".to_string(),
+            file: "Makefile.toml".to_string(),
+            overridden_file: "shared.toml".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,266 @@
+//! # lock
+//!
+//! Keeps track of the makefiles loaded through the extend attribute and validates them against
+//! the makefile lock file (for example Makefile.lock) which is stored next to the root makefile.<br>
+//! The lock file holds the source and the SHA-256 of each extended makefile, so changes in shared
+//! makefiles can be detected.
+//!
+
+#[cfg(test)]
+#[path = "lock_test.rs"]
+mod lock_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use fsio::path::from_path::FromPath;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+static LOCK_FILE_HEADER: &str =
+    "# This file is automatically generated by cargo-make.\n# It is not intended for manual editing.\n\n";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// A single locked makefile
+pub(crate) struct LockEntry {
+    /// The makefile path (relative to the root makefile) or git source
+    pub(crate) source: String,
+    /// The SHA-256 of the makefile content
+    pub(crate) sha256: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct LockFile {
+    #[serde(default)]
+    makefile: Vec<LockEntry>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    /// loaded makefile absolute path to its SHA-256
+    makefiles: IndexMap<PathBuf, String>,
+    /// git repository cache directory to its source prefix
+    git_sources: Vec<(PathBuf, String)>,
+}
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| Mutex::new(Registry::default()));
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn to_source_path(path: &Path) -> String {
+    let value: String = FromPath::from_path(path);
+    value.replace('\\', "/")
+}
+
+fn get_relative_path(base: &Path, path: &Path) -> String {
+    let base_components: Vec<Component> = base.components().collect();
+    let path_components: Vec<Component> = path.components().collect();
+
+    let common = base_components
+        .iter()
+        .zip(path_components.iter())
+        .take_while(|(base_component, path_component)| base_component == path_component)
+        .count();
+
+    if common == 0 {
+        // no common root (for example different windows drives)
+        return to_source_path(path);
+    }
+
+    let mut relative_path = PathBuf::new();
+    for _ in common..base_components.len() {
+        relative_path.push("..");
+    }
+    for component in &path_components[common..] {
+        relative_path.push(component);
+    }
+
+    to_source_path(&relative_path)
+}
+
+/// Clears all recorded makefiles and git sources
+pub(crate) fn clear() {
+    let mut registry = REGISTRY.lock().unwrap();
+    registry.makefiles.clear();
+    registry.git_sources.clear();
+}
+
+/// Registers the git repository directory so makefiles loaded from it are locked by their git source
+pub(crate) fn add_git_source(directory: &Path, url: &str, rev: &str) {
+    let mut registry = REGISTRY.lock().unwrap();
+    registry
+        .git_sources
+        .push((canonicalize(directory), format!("git+{}?rev={}", url, rev)));
+}
+
+/// Records the loaded makefile content
+pub(crate) fn record(file: &str, content: &str) {
+    let hash = format!("{:x}", Sha256::digest(content.as_bytes()));
+
+    let mut registry = REGISTRY.lock().unwrap();
+    registry
+        .makefiles
+        .insert(canonicalize(Path::new(file)), hash);
+}
+
+fn get_source(registry: &Registry, root_directory: &Path, file: &Path) -> String {
+    for (directory, prefix) in &registry.git_sources {
+        if let Ok(repository_path) = file.strip_prefix(directory) {
+            return format!("{}#{}", prefix, to_source_path(repository_path));
+        }
+    }
+
+    get_relative_path(root_directory, file)
+}
+
+fn get_entries(root_directory: &Path) -> Vec<LockEntry> {
+    let root_directory = canonicalize(root_directory);
+    let registry = REGISTRY.lock().unwrap();
+
+    registry
+        .makefiles
+        .iter()
+        .map(|(file, hash)| LockEntry {
+            source: get_source(&registry, &root_directory, file),
+            sha256: hash.to_string(),
+        })
+        .collect()
+}
+
+/// Returns the lock file path for the provided root makefile
+pub(crate) fn get_lock_file(makefile: &Path) -> PathBuf {
+    let stem = makefile
+        .file_stem()
+        .and_then(|value| value.to_str())
+        .unwrap_or("Makefile");
+
+    makefile.with_file_name(format!("{}.lock", stem))
+}
+
+fn read_lock_file(lock_file: &Path) -> Result<Option<Vec<LockEntry>>, CargoMakeError> {
+    if !lock_file.exists() {
+        return Ok(None);
+    }
+
+    let text = io::read_text_file(&lock_file.to_path_buf())?;
+    match toml::from_str::<LockFile>(&text) {
+        Ok(lock) => Ok(Some(lock.makefile)),
+        Err(error) => Err(CargoMakeError::ParseFileFailed(
+            FromPath::from_path(lock_file),
+            error.to_string(),
+        )),
+    }
+}
+
+fn write_lock_file(lock_file: &Path, entries: &[LockEntry]) -> Result<(), CargoMakeError> {
+    let lock = LockFile {
+        makefile: entries.to_vec(),
+    };
+    let text = match toml::to_string(&lock) {
+        Ok(text) => text,
+        Err(error) => {
+            return Err(CargoMakeError::MakefileLock(
+                FromPath::from_path(lock_file),
+                error.to_string(),
+            ))
+        }
+    };
+
+    fsio::file::write_text_file(
+        &lock_file.to_path_buf(),
+        &format!("{}{}", LOCK_FILE_HEADER, text),
+    )?;
+
+    Ok(())
+}
+
+fn get_differences(locked: &[LockEntry], current: &[LockEntry]) -> Vec<String> {
+    let locked_map: IndexMap<&String, &String> = locked
+        .iter()
+        .map(|entry| (&entry.source, &entry.sha256))
+        .collect();
+    let current_map: IndexMap<&String, &String> = current
+        .iter()
+        .map(|entry| (&entry.source, &entry.sha256))
+        .collect();
+
+    let mut differences = vec![];
+    for (source, hash) in &current_map {
+        match locked_map.get(source) {
+            Some(locked_hash) if locked_hash == hash => (),
+            Some(_) => differences.push(format!("{}: changed", source)),
+            None => differences.push(format!("{}: not locked", source)),
+        }
+    }
+    for source in locked_map.keys() {
+        if !current_map.contains_key(source) {
+            differences.push(format!("{}: no longer loaded", source));
+        }
+    }
+
+    differences
+}
+
+fn apply(
+    lock_file: &Path,
+    entries: &[LockEntry],
+    locked: bool,
+    update: bool,
+) -> Result<(), CargoMakeError> {
+    let lock_file_string: String = FromPath::from_path(lock_file);
+    let locked_entries = read_lock_file(lock_file)?;
+
+    let differences = match locked_entries {
+        Some(ref locked_entries) => get_differences(locked_entries, entries),
+        None if entries.is_empty() => vec![],
+        None => vec!["lock file not found".to_string()],
+    };
+
+    if differences.is_empty() {
+        Ok(())
+    } else if update {
+        info!("Updating makefile lock file: {}", &lock_file_string);
+        write_lock_file(lock_file, entries)
+    } else if locked {
+        Err(CargoMakeError::MakefileLock(
+            lock_file_string,
+            differences.join("\n"),
+        ))
+    } else if locked_entries.is_none() {
+        // the lock file is optional unless --locked or --update-lock is provided
+        Ok(())
+    } else {
+        warn!(
+            "Makefile lock file: {} is not up to date, run with --update-lock to update it.\n{}",
+            &lock_file_string,
+            differences.join("\n")
+        );
+        Ok(())
+    }
+}
+
+/// Validates or updates the lock file of the provided root makefile based on the
+/// CARGO_MAKE_LOCKED and CARGO_MAKE_UPDATE_LOCK env vars
+pub(crate) fn update_or_validate(makefile: &str) -> Result<(), CargoMakeError> {
+    let makefile_path = Path::new(makefile);
+    if !makefile_path.is_file() {
+        return Ok(());
+    }
+
+    let root_directory = match makefile_path.parent() {
+        Some(directory) if !directory.as_os_str().is_empty() => directory.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let entries = get_entries(&root_directory);
+
+    apply(
+        &get_lock_file(makefile_path),
+        &entries,
+        envmnt::is("CARGO_MAKE_LOCKED"),
+        envmnt::is("CARGO_MAKE_UPDATE_LOCK"),
+    )
+}
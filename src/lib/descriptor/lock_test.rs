@@ -0,0 +1,238 @@
+use super::*;
+use crate::test;
+use fsio::file::{read_text_file, write_text_file};
+
+fn entry(source: &str, sha256: &str) -> LockEntry {
+    LockEntry {
+        source: source.to_string(),
+        sha256: sha256.to_string(),
+    }
+}
+
+#[test]
+fn get_relative_path_same_directory() {
+    let output = get_relative_path(Path::new("/project"), Path::new("/project/common.toml"));
+
+    assert_eq!(output, "common.toml");
+}
+
+#[test]
+fn get_relative_path_sub_directory() {
+    let output = get_relative_path(
+        Path::new("/project"),
+        Path::new("/project/makefiles/rust.toml"),
+    );
+
+    assert_eq!(output, "makefiles/rust.toml");
+}
+
+#[test]
+fn get_relative_path_parent_directory() {
+    let output = get_relative_path(
+        Path::new("/workspace/project"),
+        Path::new("/workspace/shared/common.toml"),
+    );
+
+    assert_eq!(output, "../shared/common.toml");
+}
+
+#[test]
+fn get_lock_file_default() {
+    let output = get_lock_file(Path::new("Makefile.toml"));
+
+    assert_eq!(output, PathBuf::from("Makefile.lock"));
+}
+
+#[test]
+fn get_lock_file_custom() {
+    let output = get_lock_file(Path::new("build/custom.toml"));
+
+    assert_eq!(output, PathBuf::from("build/custom.lock"));
+}
+
+#[test]
+fn get_differences_same() {
+    let output = get_differences(&vec![entry("a.toml", "1")], &vec![entry("a.toml", "1")]);
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn get_differences_all() {
+    let output = get_differences(
+        &vec![entry("changed.toml", "1"), entry("removed.toml", "1")],
+        &vec![entry("changed.toml", "2"), entry("added.toml", "1")],
+    );
+
+    assert_eq!(
+        output,
+        vec![
+            "changed.toml: changed",
+            "added.toml: not locked",
+            "removed.toml: no longer loaded"
+        ]
+    );
+}
+
+#[test]
+fn apply_no_entries_no_lock_file() {
+    let directory = test::get_temp_test_directory("lock_apply_no_entries_no_lock_file");
+    let lock_file = directory.join("Makefile.lock");
+
+    apply(&lock_file, &vec![], false, false).unwrap();
+
+    assert!(!lock_file.exists());
+}
+
+#[test]
+fn apply_missing_lock_file_not_created() {
+    let directory = test::get_temp_test_directory("lock_apply_missing_lock_file_not_created");
+    let lock_file = directory.join("Makefile.lock");
+
+    apply(&lock_file, &vec![entry("common.toml", "1")], false, false).unwrap();
+
+    assert!(!lock_file.exists());
+}
+
+#[test]
+fn apply_update_creates_missing_lock_file() {
+    let directory = test::get_temp_test_directory("lock_apply_update_creates_missing_lock_file");
+    let lock_file = directory.join("Makefile.lock");
+    let entries = vec![entry("common.toml", "1")];
+
+    apply(&lock_file, &entries, false, true).unwrap();
+
+    let output = read_lock_file(&lock_file).unwrap().unwrap();
+    assert_eq!(output, entries);
+    assert!(read_text_file(&lock_file)
+        .unwrap()
+        .starts_with(LOCK_FILE_HEADER));
+}
+
+#[test]
+fn apply_locked_missing_lock_file() {
+    let directory = test::get_temp_test_directory("lock_apply_locked_missing_lock_file");
+    let lock_file = directory.join("Makefile.lock");
+
+    let output = apply(&lock_file, &vec![entry("common.toml", "1")], true, false);
+
+    assert!(output.is_err());
+    assert!(!lock_file.exists());
+}
+
+#[test]
+fn apply_locked_valid() {
+    let directory = test::get_temp_test_directory("lock_apply_locked_valid");
+    let lock_file = directory.join("Makefile.lock");
+    let entries = vec![entry("common.toml", "1")];
+    write_lock_file(&lock_file, &entries).unwrap();
+
+    apply(&lock_file, &entries, true, false).unwrap();
+}
+
+#[test]
+fn apply_locked_changed() {
+    let directory = test::get_temp_test_directory("lock_apply_locked_changed");
+    let lock_file = directory.join("Makefile.lock");
+    write_lock_file(&lock_file, &vec![entry("common.toml", "1")]).unwrap();
+
+    let output = apply(&lock_file, &vec![entry("common.toml", "2")], true, false);
+
+    match output {
+        Err(CargoMakeError::MakefileLock(_, differences)) => {
+            assert_eq!(differences, "common.toml: changed")
+        }
+        _ => panic!("expected lock error"),
+    }
+}
+
+#[test]
+fn apply_changed_not_locked() {
+    let directory = test::get_temp_test_directory("lock_apply_changed_not_locked");
+    let lock_file = directory.join("Makefile.lock");
+    let entries = vec![entry("common.toml", "1")];
+    write_lock_file(&lock_file, &entries).unwrap();
+
+    apply(&lock_file, &vec![entry("common.toml", "2")], false, false).unwrap();
+
+    // the lock file is only updated when explicitly requested
+    let output = read_lock_file(&lock_file).unwrap().unwrap();
+    assert_eq!(output, entries);
+}
+
+#[test]
+fn apply_update() {
+    let directory = test::get_temp_test_directory("lock_apply_update");
+    let lock_file = directory.join("Makefile.lock");
+    write_lock_file(&lock_file, &vec![entry("common.toml", "1")]).unwrap();
+    let entries = vec![entry("common.toml", "2")];
+
+    apply(&lock_file, &entries, true, true).unwrap();
+
+    let output = read_lock_file(&lock_file).unwrap().unwrap();
+    assert_eq!(output, entries);
+}
+
+#[test]
+fn read_lock_file_invalid() {
+    let directory = test::get_temp_test_directory("lock_read_lock_file_invalid");
+    let lock_file = directory.join("Makefile.lock");
+    write_text_file(&lock_file, "[[makefile]]\nsource = 1").unwrap();
+
+    let output = read_lock_file(&lock_file);
+
+    assert!(output.is_err());
+}
+
+#[test]
+#[ignore]
+fn get_entries_recorded() {
+    let directory = test::get_temp_test_directory("lock_get_entries_recorded");
+    let repository = directory.join("repository");
+    fsio::directory::create(&repository).unwrap();
+    let common = directory.join("common.toml");
+    let git_makefile = repository.join("rust.toml");
+    write_text_file(&common, "common").unwrap();
+    write_text_file(&git_makefile, "git").unwrap();
+
+    clear();
+    add_git_source(&repository, "https://example.com/repo.git", "v1");
+    record(&common.to_string_lossy(), "common");
+    record(&git_makefile.to_string_lossy(), "git");
+
+    let output = get_entries(&directory.join("project"));
+
+    assert_eq!(
+        output,
+        vec![
+            entry(
+                "../common.toml",
+                &format!("{:x}", Sha256::digest("common".as_bytes()))
+            ),
+            entry(
+                "git+https://example.com/repo.git?rev=v1#rust.toml",
+                &format!("{:x}", Sha256::digest("git".as_bytes()))
+            ),
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn clear_git_sources() {
+    let directory = test::get_temp_test_directory("lock_clear_git_sources");
+    let repository = directory.join("repository");
+    fsio::directory::create(&repository).unwrap();
+    let git_makefile = repository.join("rust.toml");
+    write_text_file(&git_makefile, "git").unwrap();
+
+    clear();
+    add_git_source(&repository, "https://example.com/repo.git", "v1");
+    clear();
+    record(&git_makefile.to_string_lossy(), "git");
+
+    let output = get_entries(&directory);
+
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0].source, "repository/rust.toml");
+}
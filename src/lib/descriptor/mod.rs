@@ -13,7 +13,11 @@ mod mod_test;
 mod cargo_alias;
 pub(crate) mod descriptor_deserializer;
 mod env;
+mod git_extend;
+pub(crate) mod imports;
+mod lock;
 mod makefiles;
+mod templates;
 
 use std::path::{Path, PathBuf};
 
@@ -21,14 +25,25 @@ use fsio::path::as_path::AsPath;
 use fsio::path::from_path::FromPath;
 use indexmap::IndexMap;
 
-use crate::descriptor::env::{merge_env, merge_env_files, merge_env_scripts};
+use crate::cli::DEFAULT_TOML;
+use crate::descriptor::env::{merge_env, merge_env_files, merge_env_required, merge_env_scripts};
+use crate::environment::provenance;
 use crate::error::CargoMakeError;
+use crate::functions::user_functions;
 use crate::plugin::descriptor::merge_plugins_config;
 use crate::types::{
     Config, ConfigSection, EnvFile, EnvFileInfo, EnvValue, Extend, ExternalConfig, ModifyConfig,
     Task,
 };
-use crate::{io, scriptengine, version};
+use crate::{condition_expr, io, recursion_level, scriptengine, version};
+
+/// The default makefile names in the order they are searched for
+static DEFAULT_MAKEFILES: [&str; 4] = [
+    "Makefile.toml",
+    "Makefile.yaml",
+    "Makefile.yml",
+    "Makefile.json",
+];
 
 fn merge_tasks(
     base: &mut IndexMap<String, Task>,
@@ -143,6 +158,35 @@ fn add_file_location_info(
     external_config
 }
 
+fn validate_condition_expressions(
+    external_config: &ExternalConfig,
+    file_path_string: &str,
+) -> Result<(), CargoMakeError> {
+    if let Some(ref tasks) = external_config.tasks {
+        for (task_name, task) in tasks {
+            let mut expressions = vec![task.condition_expr.clone()];
+            for platform_task in [&task.linux, &task.windows, &task.mac]
+                .into_iter()
+                .flatten()
+            {
+                expressions.push(platform_task.condition_expr.clone());
+            }
+
+            for expression in expressions.iter().flatten() {
+                if let Err(error) = condition_expr::parse(expression) {
+                    return Err(CargoMakeError::InvalidConditionExpr(
+                        task_name.to_string(),
+                        file_path_string.to_string(),
+                        error,
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn run_load_script(external_config: &ExternalConfig) -> Result<bool, CargoMakeError> {
     match external_config.config {
         Some(ref config) => {
@@ -215,6 +259,12 @@ fn merge_external_configs(
         Some(tasks) => tasks,
         None => IndexMap::new(),
     };
+    // templates are expanded before the tasks are merged so parent templates can be instantiated
+    let all_templates = templates::merge(parent_config.templates, config.templates);
+    if let Some(ref all_templates) = all_templates {
+        templates::expand(&mut extended_tasks, all_templates)?;
+    }
+    imports::record_collisions(&parent_tasks, &extended_tasks);
     let all_tasks = merge_tasks(&mut parent_tasks, &mut extended_tasks, false);
 
     let mut config_section = ConfigSection::new();
@@ -229,16 +279,23 @@ fn merge_external_configs(
         config_section.extend(&mut config_section_data);
     }
 
+    let env_required = merge_env_required(parent_config.env_required, config.env_required);
+
     let plugins = merge_plugins_config(parent_config.plugins, config.plugins);
 
+    let functions = user_functions::merge(parent_config.functions, config.functions);
+
     let config = ExternalConfig {
         extend: None,
         config: Some(config_section),
         env_files: Some(all_env_files),
         env: Some(all_env),
         env_scripts: Some(all_env_scripts),
+        env_required,
+        templates: all_templates,
         tasks: Some(all_tasks),
         plugins,
+        functions,
     };
 
     Ok(config)
@@ -249,10 +306,48 @@ fn load_descriptor_extended_makefiles(
     extend_struct: &Extend,
 ) -> Result<ExternalConfig, CargoMakeError> {
     match extend_struct {
-        Extend::Path(base_file) => load_external_descriptor(parent_path, &base_file, true, false),
+        Extend::Path(base_file) => {
+            load_external_descriptor(parent_path, &base_file, true, false, true)
+        }
         Extend::Options(extend_options) => {
             let force = !extend_options.optional.unwrap_or(false);
-            load_external_descriptor(parent_path, &extend_options.path, force, false)
+
+            let mut config = match extend_options.git {
+                Some(ref url) => match git_extend::get_directory(url, &extend_options.rev) {
+                    Ok(repository_directory) => {
+                        lock::add_git_source(
+                            &repository_directory,
+                            url,
+                            extend_options.rev.as_deref().unwrap_or_default(),
+                        );
+                        let repository_path: String = FromPath::from_path(&repository_directory);
+
+                        load_external_descriptor(
+                            &repository_path,
+                            &extend_options.path,
+                            force,
+                            false,
+                            true,
+                        )
+                    }
+                    // optional git makefiles are skipped just like missing optional files
+                    Err(error) if !force => {
+                        warn!("Skipping optional git extended makefile. {}", error);
+
+                        Ok(ExternalConfig::new())
+                    }
+                    Err(error) => Err(error),
+                },
+                None => {
+                    load_external_descriptor(parent_path, &extend_options.path, force, false, true)
+                }
+            }?;
+
+            if let Some(ref namespace) = extend_options.namespace {
+                imports::apply_namespace(&mut config, namespace);
+            }
+
+            Ok(config)
         }
         Extend::List(extend_list) => {
             let mut ordered_list_config = ExternalConfig::new();
@@ -275,18 +370,9 @@ fn load_descriptor_extended_makefiles(
 
 /// Ensure the Makefile's min_version, if present, is older than cargo-make's
 /// currently running version.
-fn check_makefile_min_version(external_descriptor: &str) -> Result<(), CargoMakeError> {
-    let value: toml::Value = match toml::from_str(&external_descriptor) {
-        Ok(value) => value,
-        // If there's an error parsing the file, let the caller function figure
-        // it out
-        Err(_) => return Ok(()),
-    };
-
-    let min_version = value
-        .get("config")
-        .and_then(|config| config.get("min_version"))
-        .and_then(|min_ver| min_ver.as_str());
+fn check_makefile_min_version(external_descriptor: &str, file: &str) -> Result<(), CargoMakeError> {
+    // If there's an error parsing the file, let the caller function figure it out
+    let min_version = descriptor_deserializer::get_min_version(external_descriptor, file);
 
     if let Some(ref min_version) = min_version {
         if version::is_newer_found(&min_version) {
@@ -297,11 +383,18 @@ fn check_makefile_min_version(external_descriptor: &str) -> Result<(), CargoMake
     Ok(())
 }
 
+fn declare_env(external_config: &ExternalConfig, file: &str) {
+    if let Some(ref env) = external_config.env {
+        provenance::declare(env, &format!("makefile {}", file));
+    }
+}
+
 fn load_external_descriptor(
     base_path: &str,
     file_name: &str,
     force: bool,
     set_env: bool,
+    extended: bool,
 ) -> Result<ExternalConfig, CargoMakeError> {
     debug!(
         "Loading tasks from file: {} base directory: {}",
@@ -320,7 +413,13 @@ fn load_external_descriptor(
 
         let external_descriptor = io::read_text_file(&file_path)?;
 
-        check_makefile_min_version(&external_descriptor)?;
+        // only makefiles loaded via the extend attribute are locked (not the root and
+        // workspace makefiles)
+        if extended {
+            lock::record(&absolute_file_path, &external_descriptor);
+        }
+
+        check_makefile_min_version(&external_descriptor, &file_path_string)?;
 
         let mut file_config =
             descriptor_deserializer::load_external_config(&external_descriptor, &file_path_string)?;
@@ -328,6 +427,8 @@ fn load_external_descriptor(
 
         file_config = add_file_location_info(file_config, &absolute_file_path);
 
+        validate_condition_expressions(&file_config, &absolute_file_path)?;
+
         run_load_script(&file_config)?;
 
         match file_config.extend {
@@ -343,9 +444,21 @@ fn load_external_descriptor(
                 let base_file_config =
                     load_descriptor_extended_makefiles(&parent_path, extend_struct)?;
 
+                declare_env(&file_config, &absolute_file_path);
+
                 merge_external_configs(file_config.clone(), base_file_config)
             }
-            None => Ok(file_config),
+            None => {
+                declare_env(&file_config, &absolute_file_path);
+
+                if let (Some(ref mut tasks), Some(ref file_templates)) =
+                    (&mut file_config.tasks, &file_config.templates)
+                {
+                    templates::expand(tasks, file_templates)?;
+                }
+
+                Ok(file_config)
+            }
         }
     } else if force {
         error!("Descriptor file: {:#?} not found.", &file_path);
@@ -412,6 +525,8 @@ pub(crate) fn load_internal_descriptors(
         None => (),
     };
 
+    provenance::declare(&base_config.env, "core makefile");
+
     Ok(base_config)
 }
 
@@ -454,6 +569,7 @@ fn merge_base_config_and_external_config(
                 let env_parts: Option<(&str, &str)> = split_once(env_pair, '=');
 
                 if let Some((key, value)) = env_parts {
+                    provenance::declare_key(key, "--env");
                     cli_env.insert(key.to_string(), EnvValue::Value(value.to_string()));
                 }
             }
@@ -477,15 +593,21 @@ fn merge_base_config_and_external_config(
     let mut config_section = base_config.config.clone();
     config_section.extend(&mut external_config.config.unwrap_or(ConfigSection::new()));
 
+    let env_required = merge_env_required(base_config.env_required, external_config.env_required);
+
     let plugins = merge_plugins_config(base_config.plugins, external_config.plugins);
 
+    let functions = user_functions::merge(base_config.functions, external_config.functions);
+
     let config = Config {
         config: config_section,
         env_files,
         env: all_env,
         env_scripts,
+        env_required,
         tasks: all_tasks,
         plugins,
+        functions,
     };
 
     Ok(config)
@@ -515,7 +637,11 @@ fn load_descriptors(
 ) -> Result<Config, CargoMakeError> {
     let default_config = load_internal_descriptors(stable, experimental, modify_core_tasks)?;
 
-    let mut external_config = load_external_descriptor(".", file_name, force, true)?;
+    lock::clear();
+    descriptor_deserializer::clear_unknown_keys();
+    imports::clear_collisions();
+    let external_declarations_start = provenance::get_declarations_count();
+    let mut external_config = load_external_descriptor(".", file_name, force, true, false)?;
 
     external_config = match std::env::var("CARGO_MAKE_WORKSPACE_MAKEFILE") {
         Ok(workspace_makefile) => {
@@ -527,12 +653,20 @@ fn load_descriptors(
 
                         match pathbuf.to_str() {
                             Some(directory) => {
+                                let workspace_declarations_start =
+                                    provenance::get_declarations_count();
                                 let workspace_config = load_external_descriptor(
                                     directory,
                                     workspace_file_name_str,
                                     false,
                                     false,
+                                    false,
                                 )?;
+                                // the workspace makefile is overridden by the member makefile
+                                provenance::lower_declarations_priority(
+                                    workspace_declarations_start,
+                                    external_declarations_start,
+                                );
                                 merge_external_configs(external_config, workspace_config)?
                             }
                             _ => external_config,
@@ -554,6 +688,16 @@ fn load_descriptors(
     Ok(config)
 }
 
+/// Returns the default makefile name found in the provided directory.<br>
+/// Makefile.toml is preferred over the YAML and JSON formats and is returned in case none exist.
+pub(crate) fn get_default_makefile(directory: &Path) -> String {
+    DEFAULT_MAKEFILES
+        .iter()
+        .find(|file_name| directory.join(file_name).is_file())
+        .unwrap_or(&DEFAULT_TOML)
+        .to_string()
+}
+
 fn load_cargo_aliases(config: &mut Config) -> Result<(), CargoMakeError> {
     if let Some(load_cargo_aliases) = config.config.load_cargo_aliases {
         if load_cargo_aliases {
@@ -614,8 +758,11 @@ pub fn load(
                     env_files: Some(config.env_files),
                     env: Some(config.env),
                     env_scripts: Some(config.env_scripts),
+                    env_required: config.env_required,
+                    templates: None,
                     tasks: Some(config.tasks),
                     plugins: config.plugins,
+                    functions: config.functions,
                 };
 
                 config = merge_base_config_and_external_config(
@@ -628,6 +775,11 @@ pub fn load(
         };
     }
 
+    // workspace members (and nested invocations) do not validate or update the lock file
+    if recursion_level::is_top() {
+        lock::update_or_validate(file_name)?;
+    }
+
     load_cargo_aliases(&mut config)?;
 
     if let Some(unstable_features) = &config.config.unstable_features {
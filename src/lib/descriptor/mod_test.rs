@@ -1,7 +1,9 @@
 use super::*;
 use crate::environment;
 use crate::environment::setup_cwd;
+use crate::test;
 use crate::types::{ExtendOptions, InstallCrate, ScriptValue};
+use std::process::Command;
 
 #[test]
 fn merge_tasks_both_empty() {
@@ -198,6 +200,67 @@ fn load_descriptors_load_workspace_makefile_no_exists() {
     assert!(task.is_none());
 }
 
+#[test]
+#[ignore]
+fn load_workspace_makefile_not_locked() {
+    let directory = test::get_temp_test_directory("descriptor_load_workspace_makefile_not_locked");
+    let workspace_makefile = directory.join("Workspace.toml");
+    let makefile = directory.join("Makefile.toml");
+    fsio::file::write_text_file(
+        &workspace_makefile,
+        "[tasks.workspace-lock-echo]\ncommand = \"echo\"\n",
+    )
+    .unwrap();
+    fsio::file::write_text_file(&makefile, "[tasks.member-lock-echo]\ncommand = \"echo\"\n")
+        .unwrap();
+    let workspace_makefile_string: String = FromPath::from_path(&workspace_makefile);
+    let makefile_string: String = FromPath::from_path(&makefile);
+
+    envmnt::set("CARGO_MAKE_WORKSPACE_MAKEFILE", &workspace_makefile_string);
+    envmnt::set_bool("CARGO_MAKE_LOCKED", true);
+    let config = load(&makefile_string, true, None, false);
+    envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
+    envmnt::remove("CARGO_MAKE_LOCKED");
+
+    // the workspace makefile is not locked so no lock file is required
+    let config = config.unwrap();
+    assert!(config.tasks.contains_key("workspace-lock-echo"));
+    assert!(!directory.join("Makefile.lock").exists());
+}
+
+#[test]
+#[ignore]
+fn load_workspace_member_lock_not_updated() {
+    let directory = test::get_temp_test_directory("descriptor_load_workspace_member_lock");
+    let makefile = directory.join("Makefile.toml");
+    fsio::file::write_text_file(
+        &directory.join("common.toml"),
+        "[tasks.common-lock-echo]\ncommand = \"echo\"\n",
+    )
+    .unwrap();
+    fsio::file::write_text_file(&makefile, "extend = \"common.toml\"\n").unwrap();
+    let makefile_string: String = FromPath::from_path(&makefile);
+
+    envmnt::set(
+        "CARGO_MAKE_WORKSPACE_MAKEFILE",
+        "./examples/workspace/Makefile.toml",
+    );
+    envmnt::set_u32("CARGO_MAKE_INTERNAL_RECURSION_LEVEL", 1);
+    envmnt::set_bool("CARGO_MAKE_LOCKED", true);
+    envmnt::set_bool("CARGO_MAKE_UPDATE_LOCK", true);
+    let config = load(&makefile_string, true, None, false);
+    envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
+    envmnt::remove("CARGO_MAKE_INTERNAL_RECURSION_LEVEL");
+    envmnt::remove("CARGO_MAKE_LOCKED");
+    envmnt::remove("CARGO_MAKE_UPDATE_LOCK");
+
+    // workspace members neither validate nor write the lock file
+    let config = config.unwrap();
+    assert!(config.tasks.contains_key("common-lock-echo"));
+    assert!(config.tasks.contains_key("workspace-echo"));
+    assert!(!directory.join("Makefile.lock").exists());
+}
+
 #[test]
 #[ignore]
 fn load_descriptors_no_load_workspace_makefile() {
@@ -492,7 +555,7 @@ fn load_internal_descriptors_modify_namespace() {
 #[test]
 #[ignore]
 fn load_external_descriptor_no_file() {
-    let config = load_external_descriptor(".", "bad_file.toml2", false, false).unwrap();
+    let config = load_external_descriptor(".", "bad_file.toml2", false, false, false).unwrap();
 
     assert!(config.config.is_none());
     assert!(config.env.is_none());
@@ -502,19 +565,20 @@ fn load_external_descriptor_no_file() {
 #[test]
 #[should_panic]
 fn load_external_descriptor_no_file_force() {
-    load_external_descriptor(".", "bad_file.toml2", true, false).unwrap();
+    load_external_descriptor(".", "bad_file.toml2", true, false, false).unwrap();
 }
 
 #[test]
 #[should_panic]
 fn load_external_descriptor_extended_not_found_force() {
-    load_external_descriptor(".", "./examples/extends_not_found.toml", true, false).unwrap();
+    load_external_descriptor(".", "./examples/extends_not_found.toml", true, false, false).unwrap();
 }
 
 #[test]
 #[ignore]
 fn load_external_descriptor_simple_file() {
-    let config = load_external_descriptor(".", "./examples/alias.toml", true, false).unwrap();
+    let config =
+        load_external_descriptor(".", "./examples/alias.toml", true, false, false).unwrap();
 
     assert!(config.config.is_none());
     assert!(config.env.is_none());
@@ -529,7 +593,8 @@ fn load_external_descriptor_simple_file() {
 #[test]
 #[ignore]
 fn load_external_descriptor_extending_file() {
-    let config = load_external_descriptor(".", "examples/extending.toml", true, false).unwrap();
+    let config =
+        load_external_descriptor(".", "examples/extending.toml", true, false, false).unwrap();
 
     assert!(config.config.is_some());
     assert!(config.env.is_some());
@@ -551,7 +616,7 @@ fn load_external_descriptor_extending_file() {
 #[ignore]
 fn load_external_descriptor_extending_file_sub_folder() {
     let config =
-        load_external_descriptor(".", "examples/files/extending.toml", true, false).unwrap();
+        load_external_descriptor(".", "examples/files/extending.toml", true, false, false).unwrap();
 
     assert!(config.config.is_some());
     assert!(config.env.is_some());
@@ -584,7 +649,7 @@ fn load_external_descriptor_set_env() {
     envmnt::set("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_MAKEFILE_PATH"), "EMPTY");
 
-    load_external_descriptor(".", "./examples/alias.toml", true, true).unwrap();
+    load_external_descriptor(".", "./examples/alias.toml", true, true, false).unwrap();
 
     assert!(envmnt::get_or_panic("CARGO_MAKE_MAKEFILE_PATH").ends_with("alias.toml"));
 }
@@ -598,6 +663,7 @@ fn load_external_descriptor_min_version_broken_makefile_nopanic() {
             ".",
             "src/lib/test/makefiles/broken_makefile_minversion.toml",
             false,
+            false,
             false
         )
         .err()
@@ -618,6 +684,7 @@ fn load_external_descriptor_broken_makefile_panic() {
         "src/lib/test/makefiles/broken_makefile.toml",
         false,
         false,
+        false,
     )
     .unwrap();
 }
@@ -726,6 +793,9 @@ fn load_descriptor_extended_makefiles_options_exists() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: None,
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -744,6 +814,9 @@ fn load_descriptor_extended_makefiles_options_not_exists() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: None,
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -758,6 +831,9 @@ fn load_descriptor_extended_makefiles_options_exists_optional() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(true),
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -775,6 +851,9 @@ fn load_descriptor_extended_makefiles_options_exists_not_optional() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -793,6 +872,9 @@ fn load_descriptor_extended_makefiles_options_not_exists_optional() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(true),
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -811,6 +893,9 @@ fn load_descriptor_extended_makefiles_options_not_exists_not_optional() {
         &Extend::Options(ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         }),
     )
     .unwrap();
@@ -824,10 +909,16 @@ fn load_descriptor_extended_makefiles_list_exists() {
         ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/test2.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -846,10 +937,16 @@ fn load_descriptor_extended_makefiles_list_not_exists() {
         ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         },
     ];
     load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -863,10 +960,16 @@ fn load_descriptor_extended_makefiles_list_exists_optional() {
         ExtendOptions {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
+            git: None,
+            rev: None,
+            namespace: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(true),
+            git: None,
+            rev: None,
+            namespace: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -876,10 +979,176 @@ fn load_descriptor_extended_makefiles_list_exists_optional() {
     assert!(!tasks.contains_key("test2"));
 }
 
+#[test]
+#[ignore]
+fn load_descriptor_extended_makefiles_git() {
+    let directory = test::get_temp_test_directory("load_descriptor_extended_makefiles_git");
+    let repository = directory.join("repository");
+    fsio::directory::create(&repository).unwrap();
+    fsio::file::write_text_file(
+        &repository.join("makefiles/rust.toml"),
+        "[tasks.git-extended]\ncommand = \"echo\"\n",
+    )
+    .unwrap();
+    for args in [
+        vec!["init", "--quiet"],
+        vec!["add", "-A"],
+        vec!["commit", "--quiet", "-m", "makefile"],
+        vec!["tag", "v1"],
+    ] {
+        let status = Command::new("git")
+            .current_dir(&repository)
+            .args(&["-c", "user.name=test", "-c", "user.email=test@test.com"])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+    let url = format!("file://{}", fsio::path::canonicalize_or(&repository, ""));
+
+    envmnt::set("CARGO_MAKE_HOME", directory.join("home").to_str().unwrap());
+    let descriptor = load_descriptor_extended_makefiles(
+        ".",
+        &Extend::Options(ExtendOptions {
+            path: "makefiles/rust.toml".to_string(),
+            optional: None,
+            git: Some(url),
+            rev: Some("v1".to_string()),
+            namespace: None,
+        }),
+    );
+    envmnt::remove("CARGO_MAKE_HOME");
+
+    let tasks = descriptor.unwrap().tasks.unwrap();
+    assert!(tasks.contains_key("git-extended"));
+}
+
+#[test]
+#[ignore]
+fn load_descriptor_extended_makefiles_git_optional_failed() {
+    let directory =
+        test::get_temp_test_directory("load_descriptor_extended_makefiles_git_optional_failed");
+    let url = format!(
+        "file://{}/missing",
+        fsio::path::canonicalize_or(&directory, "")
+    );
+
+    envmnt::set("CARGO_MAKE_HOME", directory.join("home").to_str().unwrap());
+    let optional = load_descriptor_extended_makefiles(
+        ".",
+        &Extend::Options(ExtendOptions {
+            path: "makefiles/rust.toml".to_string(),
+            optional: Some(true),
+            git: Some(url.clone()),
+            rev: Some("v1".to_string()),
+            namespace: None,
+        }),
+    );
+    let required = load_descriptor_extended_makefiles(
+        ".",
+        &Extend::Options(ExtendOptions {
+            path: "makefiles/rust.toml".to_string(),
+            optional: None,
+            git: Some(url),
+            rev: Some("v1".to_string()),
+            namespace: None,
+        }),
+    );
+    envmnt::remove("CARGO_MAKE_HOME");
+
+    assert!(optional.unwrap().tasks.is_none());
+    assert!(required.is_err());
+}
+
+#[test]
+#[ignore]
+fn load_external_descriptor_yaml_extending_json() {
+    let config = load_external_descriptor(".", "examples/yaml.yaml", true, false, false).unwrap();
+
+    assert_eq!(config.env.unwrap().len(), 2);
+    let tasks = config.tasks.unwrap();
+    assert!(tasks.contains_key("yaml"));
+    assert!(tasks.contains_key("json"));
+}
+
+#[test]
+fn load_external_descriptor_templates_from_extended() {
+    let directory =
+        test::get_temp_test_directory("load_external_descriptor_templates_from_extended");
+    fsio::file::write_text_file(
+        &directory.join("base.toml"),
+        r#"
+[templates.cross-build]
+command = "cargo"
+args = ["build", "--target", "${target}"]
+"#,
+    )
+    .unwrap();
+    fsio::file::write_text_file(
+        &directory.join("Makefile.toml"),
+        r#"
+extend = "base.toml"
+
+[tasks.build-arm]
+instantiate = { template = "cross-build", params = { target = "aarch64" } }
+"#,
+    )
+    .unwrap();
+
+    let config = load_external_descriptor(
+        directory.to_str().unwrap(),
+        "Makefile.toml",
+        true,
+        false,
+        false,
+    )
+    .unwrap();
+
+    let task = &config.tasks.unwrap()["build-arm"];
+    assert!(task.instantiate.is_none());
+    assert_eq!(task.command.clone().unwrap(), "cargo");
+    assert_eq!(
+        task.args.clone().unwrap(),
+        vec!["build", "--target", "aarch64"]
+    );
+    assert!(config.templates.unwrap().contains_key("cross-build"));
+}
+
+#[test]
+fn get_default_makefile_none() {
+    let directory = test::get_temp_test_directory("get_default_makefile_none");
+
+    let output = get_default_makefile(&directory);
+
+    assert_eq!(output, "Makefile.toml");
+}
+
+#[test]
+fn get_default_makefile_yaml() {
+    let directory = test::get_temp_test_directory("get_default_makefile_yaml");
+    fsio::file::write_text_file(&directory.join("Makefile.yaml"), "").unwrap();
+    fsio::file::write_text_file(&directory.join("Makefile.json"), "").unwrap();
+
+    let output = get_default_makefile(&directory);
+
+    assert_eq!(output, "Makefile.yaml");
+}
+
+#[test]
+fn get_default_makefile_toml_preferred() {
+    let directory = test::get_temp_test_directory("get_default_makefile_toml_preferred");
+    fsio::file::write_text_file(&directory.join("Makefile.toml"), "").unwrap();
+    fsio::file::write_text_file(&directory.join("Makefile.json"), "").unwrap();
+
+    let output = get_default_makefile(&directory);
+
+    assert_eq!(output, "Makefile.toml");
+}
+
 #[test]
 fn check_makefile_min_version_empty() {
     let toml_string = "";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -887,7 +1156,7 @@ fn check_makefile_min_version_empty() {
 #[test]
 fn check_makefile_min_version_invalid_format() {
     let toml_string = "123";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -895,7 +1164,7 @@ fn check_makefile_min_version_invalid_format() {
 #[test]
 fn check_makefile_min_version_no_config() {
     let toml_string = "test = true";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -906,7 +1175,7 @@ fn check_makefile_min_version_no_min_version() {
     [config]
     test = true
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -917,7 +1186,7 @@ fn check_makefile_min_version_smaller_min_version() {
     [config]
     min_version = "0.0.1"
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -928,7 +1197,7 @@ fn check_makefile_min_version_bigger_min_version() {
     [config]
     min_version = "999.999.999"
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_err());
     assert_eq!(
@@ -946,7 +1215,7 @@ fn check_makefile_min_version_same_min_version() {
     buffer.push_str(env!("CARGO_PKG_VERSION"));
     buffer.push_str("\"");
     toml_string = buffer.as_str();
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, "Makefile.toml");
 
     assert!(result.is_ok());
 }
@@ -973,3 +1242,24 @@ fn load_cargo_aliases_found() {
 
     assert_eq!(count, config.tasks.len());
 }
+
+#[test]
+fn load_external_descriptor_invalid_condition_expr() {
+    let error = load_external_descriptor(
+        ".",
+        "./src/lib/test/makefiles/invalid_condition_expr.toml",
+        true,
+        false,
+        false,
+    )
+    .unwrap_err();
+
+    match error {
+        CargoMakeError::InvalidConditionExpr(task, makefile, message) => {
+            assert_eq!(task, "invalid");
+            assert!(makefile.ends_with("invalid_condition_expr.toml"));
+            assert_eq!(message, "Unexpected end of expression at column 38");
+        }
+        _ => panic!("Invalid error type: {:?}", error),
+    }
+}
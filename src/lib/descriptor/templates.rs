@@ -0,0 +1,133 @@
+//! # templates
+//!
+//! Expands the tasks which instantiate a task template.<br>
+//! The template is a regular task definition in which the ${param} placeholders are replaced
+//! with the instantiation params, while the instantiating task attributes override the
+//! template attributes.
+//!
+
+#[cfg(test)]
+#[path = "templates_test.rs"]
+mod templates_test;
+
+use crate::error::CargoMakeError;
+use crate::types::Task;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+/// Merges the templates, where the extended makefile templates override the parent templates
+pub(crate) fn merge(
+    parent: Option<IndexMap<String, Task>>,
+    extended: Option<IndexMap<String, Task>>,
+) -> Option<IndexMap<String, Task>> {
+    match (parent, extended) {
+        (Some(mut parent), Some(extended)) => {
+            parent.extend(extended);
+            Some(parent)
+        }
+        (Some(parent), None) => Some(parent),
+        (None, extended) => extended,
+    }
+}
+
+fn replace_params_in_text(text: &str, params: &IndexMap<String, String>) -> String {
+    let mut output = text.to_string();
+    for (name, value) in params {
+        output = output.replace(&format!("${{{}}}", name), value);
+    }
+
+    output
+}
+
+fn replace_params(value: Value, params: &IndexMap<String, String>) -> Value {
+    match value {
+        Value::String(text) => Value::String(replace_params_in_text(&text, params)),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| replace_params(value, params))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    (
+                        replace_params_in_text(&key, params),
+                        replace_params(value, params),
+                    )
+                })
+                .collect(),
+        ),
+        _ => value,
+    }
+}
+
+fn instantiate(
+    name: &str,
+    task: &Task,
+    templates: &IndexMap<String, Task>,
+) -> Result<Task, CargoMakeError> {
+    let info = match task.instantiate {
+        Some(ref info) => info,
+        None => return Ok(task.clone()),
+    };
+
+    let template = match templates.get(&info.template) {
+        Some(template) => template,
+        None => {
+            return Err(CargoMakeError::TemplateInstantiate(
+                name.to_string(),
+                format!("template: {} not found", &info.template),
+            ))
+        }
+    };
+    if template.instantiate.is_some() {
+        return Err(CargoMakeError::TemplateInstantiate(
+            name.to_string(),
+            format!(
+                "template: {} can not instantiate another template",
+                &info.template
+            ),
+        ));
+    }
+
+    // placeholders are replaced in all string values regardless of the attribute type
+    let params = info.params.clone().unwrap_or_default();
+    let template_value = serde_json::to_value(template).map_err(|error| {
+        CargoMakeError::TemplateInstantiate(name.to_string(), error.to_string())
+    })?;
+    let mut instance: Task = serde_json::from_value(replace_params(template_value, &params))
+        .map_err(|error| {
+            CargoMakeError::TemplateInstantiate(name.to_string(), error.to_string())
+        })?;
+
+    // the task env (which holds the makefile location) is merged with the template env
+    let mut env = instance.env.clone().unwrap_or_default();
+    if let Some(ref task_env) = task.env {
+        env.extend(task_env.clone());
+    }
+
+    let mut task_values = task.clone();
+    task_values.instantiate = None;
+    instance.extend(&task_values);
+    if !env.is_empty() {
+        instance.env = Some(env);
+    }
+
+    Ok(instance)
+}
+
+/// Replaces all tasks which instantiate a template with the expanded template
+pub(crate) fn expand(
+    tasks: &mut IndexMap<String, Task>,
+    templates: &IndexMap<String, Task>,
+) -> Result<(), CargoMakeError> {
+    for (name, task) in tasks.iter_mut() {
+        if task.instantiate.is_some() {
+            debug!("Instantiating task: {} from template.", name);
+            *task = instantiate(name, task, templates)?;
+        }
+    }
+
+    Ok(())
+}
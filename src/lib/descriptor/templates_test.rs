@@ -0,0 +1,171 @@
+use super::*;
+use crate::types::{EnvValue, InstantiateInfo};
+
+fn create_template() -> Task {
+    let mut env = IndexMap::new();
+    env.insert(
+        "TARGET_DIR".to_string(),
+        EnvValue::Value("target/${target}".to_string()),
+    );
+    env.insert("${target}_ENABLED".to_string(), EnvValue::Boolean(true));
+
+    let mut template = Task::new();
+    template.description = Some("Builds ${target}".to_string());
+    template.command = Some("cargo".to_string());
+    template.args = Some(vec![
+        "build".to_string(),
+        "--target".to_string(),
+        "${target}".to_string(),
+        "${OTHER}".to_string(),
+    ]);
+    template.env = Some(env);
+    template
+}
+
+fn create_instance(template: &str, target: Option<&str>) -> Task {
+    let params = target.map(|target| {
+        let mut params = IndexMap::new();
+        params.insert("target".to_string(), target.to_string());
+        params
+    });
+
+    let mut task = Task::new();
+    task.instantiate = Some(InstantiateInfo {
+        template: template.to_string(),
+        params,
+    });
+    task
+}
+
+fn get_env_value(env: &IndexMap<String, EnvValue>, key: &str) -> String {
+    match env[key] {
+        EnvValue::Value(ref value) => value.to_string(),
+        EnvValue::Boolean(value) => value.to_string(),
+        _ => panic!("unexpected env value"),
+    }
+}
+
+fn create_templates() -> IndexMap<String, Task> {
+    let mut templates = IndexMap::new();
+    templates.insert("cross-build".to_string(), create_template());
+    templates
+}
+
+#[test]
+fn merge_none() {
+    assert!(merge(None, None).is_none());
+}
+
+#[test]
+fn merge_override() {
+    let mut parent = IndexMap::new();
+    parent.insert("a".to_string(), Task::new());
+    parent.insert("b".to_string(), Task::new());
+    let mut override_task = Task::new();
+    override_task.command = Some("echo".to_string());
+    let mut extended = IndexMap::new();
+    extended.insert("b".to_string(), override_task);
+    extended.insert("c".to_string(), Task::new());
+
+    let output = merge(Some(parent), Some(extended)).unwrap();
+
+    assert_eq!(output.len(), 3);
+    assert_eq!(output["b"].command.clone().unwrap(), "echo");
+}
+
+#[test]
+fn replace_params_in_text_multiple() {
+    let mut params = IndexMap::new();
+    params.insert("a".to_string(), "1".to_string());
+    params.insert("b".to_string(), "2".to_string());
+
+    let output = replace_params_in_text("${a}-${b}-${c}-${a}", &params);
+
+    assert_eq!(output, "1-2-${c}-1");
+}
+
+#[test]
+fn expand_with_params() {
+    let mut tasks = IndexMap::new();
+    tasks.insert(
+        "build-arm".to_string(),
+        create_instance("cross-build", Some("aarch64")),
+    );
+    tasks.insert("other".to_string(), Task::new());
+
+    expand(&mut tasks, &create_templates()).unwrap();
+
+    let task = &tasks["build-arm"];
+    assert!(task.instantiate.is_none());
+    assert_eq!(task.description.clone().unwrap(), "Builds aarch64");
+    assert_eq!(task.command.clone().unwrap(), "cargo");
+    // unknown placeholders are left for the runtime env expansion
+    assert_eq!(
+        task.args.clone().unwrap(),
+        vec!["build", "--target", "aarch64", "${OTHER}"]
+    );
+    let env = task.env.clone().unwrap();
+    assert_eq!(get_env_value(&env, "TARGET_DIR"), "target/aarch64");
+    assert_eq!(get_env_value(&env, "aarch64_ENABLED"), "true");
+    assert!(tasks["other"].command.is_none());
+}
+
+#[test]
+fn expand_task_overrides_template() {
+    let mut task = create_instance("cross-build", Some("aarch64"));
+    task.description = Some("Own".to_string());
+    let mut env = IndexMap::new();
+    env.insert("EXTRA".to_string(), EnvValue::Value("1".to_string()));
+    task.env = Some(env);
+    let mut tasks = IndexMap::new();
+    tasks.insert("build-arm".to_string(), task);
+
+    expand(&mut tasks, &create_templates()).unwrap();
+
+    let task = &tasks["build-arm"];
+    assert_eq!(task.description.clone().unwrap(), "Own");
+    let env = task.env.clone().unwrap();
+    assert_eq!(env.len(), 3);
+    assert_eq!(get_env_value(&env, "EXTRA"), "1");
+}
+
+#[test]
+fn expand_without_params() {
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), create_instance("cross-build", None));
+
+    expand(&mut tasks, &create_templates()).unwrap();
+
+    assert_eq!(
+        tasks["build"].description.clone().unwrap(),
+        "Builds ${target}"
+    );
+}
+
+#[test]
+fn expand_template_not_found() {
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), create_instance("missing", None));
+
+    let output = expand(&mut tasks, &create_templates());
+
+    match output {
+        Err(CargoMakeError::TemplateInstantiate(task, message)) => {
+            assert_eq!(task, "build");
+            assert!(message.contains("missing"));
+        }
+        _ => panic!("expected template error"),
+    }
+}
+
+#[test]
+fn expand_nested_template() {
+    let mut templates = create_templates();
+    templates.insert("nested".to_string(), create_instance("cross-build", None));
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), create_instance("nested", None));
+
+    let output = expand(&mut tasks, &templates);
+
+    assert!(output.is_err());
+}
@@ -332,11 +332,36 @@ fn convert_metadata_to_crate_info(metadata: &Metadata) -> CrateInfo {
         package_info.repository = root_package.repository.clone();
 
         crate_info.package = Some(package_info);
+
+        let features = root_package
+            .features
+            .iter()
+            .map(|(name, values)| (name.clone(), values.clone()))
+            .collect();
+        crate_info.features = Some(features);
     }
 
     crate_info
 }
 
+/// Loads the crate features from the Cargo.toml found in the current working directory
+/// without invoking cargo metadata.
+pub(crate) fn load_features() -> Option<IndexMap<String, Vec<String>>> {
+    let file_path = Path::new("Cargo.toml");
+    if !file_path.exists() {
+        return None;
+    }
+
+    let crate_info_string = fsio::file::read_text_file(&file_path.to_path_buf()).ok()?;
+    match toml::from_str::<CrateInfo>(&crate_info_string) {
+        Ok(crate_info) => Some(crate_info.features.unwrap_or_default()),
+        Err(error) => {
+            debug!("Unable to parse Cargo.toml, {}", error);
+            None
+        }
+    }
+}
+
 fn get_cargo_config(home: Option<PathBuf>) -> Option<CargoConfig> {
     let path = env::current_dir().ok()?;
 
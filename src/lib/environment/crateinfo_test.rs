@@ -12,6 +12,21 @@ fn crate_info_load() {
 
     let package = crate_info.package.unwrap();
     assert_eq!(package.name.unwrap(), "cargo-make");
+
+    let features = crate_info.features.unwrap();
+    assert!(features.contains_key("default"));
+    assert!(features.contains_key("tls-rustls"));
+}
+
+#[test]
+fn load_features_valid() {
+    let features = load_features().unwrap();
+
+    assert!(features.contains_key("default"));
+    assert_eq!(
+        features.get("tls").unwrap(),
+        &vec!["tls-rustls".to_string()]
+    );
 }
 
 #[test]
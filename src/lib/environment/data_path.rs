@@ -0,0 +1,89 @@
+//! # data_path
+//!
+//! Extracts values from structured data files (TOML, JSON and YAML) by a key path.
+//!
+
+#[cfg(test)]
+#[path = "data_path_test.rs"]
+mod data_path_test;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The supported data file formats
+pub(crate) enum DataFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+fn parse(content: &str, format: DataFormat) -> Result<Value, String> {
+    match format {
+        DataFormat::Toml => toml::from_str(content).map_err(|error| error.to_string()),
+        DataFormat::Json => serde_json::from_str(content).map_err(|error| error.to_string()),
+        DataFormat::Yaml => serde_norway::from_str(content).map_err(|error| error.to_string()),
+    }
+}
+
+/// Splits the key path by dots, double quoted segments may contain dots.
+fn split_path(path: &str) -> Vec<String> {
+    let mut segments = vec![];
+    let mut segment = String::new();
+    let mut in_quotes = false;
+
+    for character in path.chars() {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            '.' if !in_quotes => {
+                segments.push(segment);
+                segment = String::new();
+            }
+            _ => segment.push(character),
+        }
+    }
+    segments.push(segment);
+
+    segments
+}
+
+fn get_value<'a>(data: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = data;
+
+    for segment in split_path(path) {
+        current = match current {
+            Value::Object(map) => map.get(&segment)?,
+            Value::Array(list) => list.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::String(string_value) => string_value.to_string(),
+        Value::Array(list)
+            if list
+                .iter()
+                .all(|item| !item.is_array() && !item.is_object()) =>
+        {
+            let items: Vec<String> = list.iter().map(value_to_string).collect();
+            items.join(";")
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Returns the value found in the data content under the provided key path.<br>
+/// Lists of simple values are joined using `;` while tables are returned as JSON.
+pub(crate) fn get_value_from_content(
+    content: &str,
+    format: DataFormat,
+    path: &str,
+) -> Result<Option<String>, String> {
+    let data = parse(content, format)?;
+
+    Ok(get_value(&data, path).map(value_to_string))
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+#[test]
+fn split_path_simple() {
+    let output = split_path("package.metadata.release");
+
+    assert_eq!(output, vec!["package", "metadata", "release"]);
+}
+
+#[test]
+fn split_path_quoted() {
+    let output = split_path("dependencies.\"my.crate\".version");
+
+    assert_eq!(output, vec!["dependencies", "my.crate", "version"]);
+}
+
+#[test]
+fn get_value_from_content_toml() {
+    let output = get_value_from_content(
+        r#"
+[package]
+name = "test"
+
+[package.metadata.release]
+channel = "beta"
+"#,
+        DataFormat::Toml,
+        "package.metadata.release.channel",
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), "beta");
+}
+
+#[test]
+fn get_value_from_content_json() {
+    let output = get_value_from_content(
+        r#"{ "version": "1.2.3", "count": 5, "enabled": true }"#,
+        DataFormat::Json,
+        "count",
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), "5");
+}
+
+#[test]
+fn get_value_from_content_yaml() {
+    let output = get_value_from_content(
+        r#"
+release:
+  targets:
+    - linux
+    - windows
+"#,
+        DataFormat::Yaml,
+        "release.targets.1",
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), "windows");
+}
+
+#[test]
+fn get_value_from_content_list() {
+    let output = get_value_from_content(
+        r#"{ "features": ["a", "b", 3] }"#,
+        DataFormat::Json,
+        "features",
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), "a;b;3");
+}
+
+#[test]
+fn get_value_from_content_table() {
+    let output = get_value_from_content(
+        r#"{ "metadata": { "key": "value" } }"#,
+        DataFormat::Json,
+        "metadata",
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), r#"{"key":"value"}"#);
+}
+
+#[test]
+fn get_value_from_content_not_found() {
+    let output = get_value_from_content(
+        r#"{ "version": "1.2.3" }"#,
+        DataFormat::Json,
+        "version.major",
+    )
+    .unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn get_value_from_content_invalid_index() {
+    let output = get_value_from_content(r#"{ "list": [1] }"#, DataFormat::Json, "list.a").unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn get_value_from_content_invalid_content() {
+    let output = get_value_from_content("[package", DataFormat::Toml, "package");
+
+    assert!(output.is_err());
+}
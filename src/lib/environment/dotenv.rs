@@ -0,0 +1,376 @@
+//! # dotenv
+//!
+//! Parses dotenv compatible env files.<br>
+//! Supports comments, `export` prefixes, single/double quoted (multi line) values and
+//! `${VAR}`, `${VAR:-default}`, `${VAR-default}` and `$VAR` interpolation.
+//!
+
+#[cfg(test)]
+#[path = "dotenv_test.rs"]
+mod dotenv_test;
+
+use indexmap::IndexMap;
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    values: IndexMap<String, String>,
+    lookup: &'a dyn Fn(&str) -> Option<String>,
+    prefer_lookup: bool,
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => chars.all(|character| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '.'
+        }),
+        _ => false,
+    }
+}
+
+fn is_name_char(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let character = self.peek();
+
+        if let Some(value) = character {
+            self.index += 1;
+
+            if value == '\n' {
+                self.line += 1;
+            }
+        }
+
+        character
+    }
+
+    fn skip_inline_whitespace(&mut self) {
+        while let Some(character) = self.peek() {
+            if character == ' ' || character == '\t' {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(character) = self.next() {
+            if character == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn read_line(&mut self) -> String {
+        let mut line = String::new();
+
+        while let Some(character) = self.peek() {
+            if character == '\n' {
+                break;
+            }
+
+            line.push(character);
+            self.next();
+        }
+
+        line
+    }
+
+    fn read_key(&mut self) -> String {
+        let mut key = String::new();
+
+        while let Some(character) = self.peek() {
+            if character.is_whitespace() || character == '=' {
+                break;
+            }
+
+            key.push(character);
+            self.next();
+        }
+
+        key
+    }
+
+    fn error(&self, message: &str, line: usize) -> String {
+        format!("{} at line {}", message, line)
+    }
+
+    fn resolve(&self, name: &str) -> Option<String> {
+        let parsed_value = || self.values.get(name).map(|value| value.to_string());
+
+        if self.prefer_lookup {
+            (self.lookup)(name).or_else(parsed_value)
+        } else {
+            parsed_value().or_else(|| (self.lookup)(name))
+        }
+    }
+
+    /// Expands the variable reference starting after the `$` character.
+    fn expand_reference(
+        &self,
+        chars: &[char],
+        index: &mut usize,
+        line: usize,
+    ) -> Result<Option<String>, String> {
+        match chars.get(*index) {
+            Some('{') => {
+                let start = *index + 1;
+                let mut end = start;
+                let mut depth = 1;
+
+                while end < chars.len() {
+                    match chars[end] {
+                        '{' => depth += 1,
+                        '}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => (),
+                    }
+
+                    end += 1;
+                }
+
+                if end >= chars.len() {
+                    return Err(self.error("Unterminated variable reference", line));
+                }
+
+                *index = end + 1;
+
+                let reference: String = chars[start..end].iter().collect();
+                let name_length = reference
+                    .chars()
+                    .take_while(|character| is_name_char(*character))
+                    .count();
+                let (name, modifier) = reference.split_at(name_length);
+
+                if name.is_empty() {
+                    return Err(self.error(
+                        &format!("Invalid variable reference '${{{}}}'", &reference),
+                        line,
+                    ));
+                }
+
+                let value = self.resolve(name);
+                let (use_default, default_value) = if modifier.is_empty() {
+                    (false, "")
+                } else if let Some(default_value) = modifier.strip_prefix(":-") {
+                    let empty = value.as_deref().unwrap_or_default().is_empty();
+                    (empty, default_value)
+                } else if let Some(default_value) = modifier.strip_prefix('-') {
+                    (value.is_none(), default_value)
+                } else {
+                    return Err(self.error(
+                        &format!("Invalid variable reference '${{{}}}'", &reference),
+                        line,
+                    ));
+                };
+
+                if use_default {
+                    Ok(Some(self.expand(default_value, false, line)?))
+                } else {
+                    Ok(Some(value.unwrap_or_default()))
+                }
+            }
+            Some(character) if character.is_ascii_alphabetic() || *character == '_' => {
+                let start = *index;
+                while *index < chars.len() && is_name_char(chars[*index]) {
+                    *index += 1;
+                }
+
+                let name: String = chars[start..*index].iter().collect();
+
+                Ok(Some(self.resolve(&name).unwrap_or_default()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Expands the variable references and (if enabled) escape sequences.
+    fn expand(&self, text: &str, escapes: bool, line: usize) -> Result<String, String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut output = String::new();
+        let mut index = 0;
+
+        while index < chars.len() {
+            let character = chars[index];
+            index += 1;
+
+            match character {
+                '\\' if escapes && index < chars.len() => {
+                    let escaped = chars[index];
+                    index += 1;
+
+                    match escaped {
+                        'n' => output.push('\n'),
+                        'r' => output.push('\r'),
+                        't' => output.push('\t'),
+                        '"' | '\\' | '$' => output.push(escaped),
+                        _ => {
+                            output.push('\\');
+                            output.push(escaped);
+                        }
+                    }
+                }
+                '$' => match self.expand_reference(&chars, &mut index, line)? {
+                    Some(value) => output.push_str(&value),
+                    None => output.push('$'),
+                },
+                _ => output.push(character),
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn read_quoted(&mut self, quote: char) -> Result<String, String> {
+        let start_line = self.line;
+        let mut value = String::new();
+
+        loop {
+            match self.next() {
+                Some(character) if character == quote => break,
+                Some('\\') if quote == '"' => {
+                    // keep escape sequences for the expansion phase
+                    value.push('\\');
+                    match self.next() {
+                        Some(character) => value.push(character),
+                        None => break,
+                    }
+                }
+                Some(character) => value.push(character),
+                None => {
+                    let message = if quote == '"' {
+                        "Unterminated double quoted value"
+                    } else {
+                        "Unterminated single quoted value"
+                    };
+
+                    return Err(self.error(message, start_line));
+                }
+            }
+        }
+
+        self.skip_inline_whitespace();
+        match self.peek() {
+            None | Some('\n') | Some('\r') => (),
+            Some('#') => self.skip_line(),
+            Some(_) => {
+                return Err(self.error("Unexpected characters after quoted value", self.line));
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn read_unquoted(&mut self) -> String {
+        let line = self.read_line();
+
+        // inline comments must be preceded by whitespace
+        let mut previous_whitespace = true;
+        let mut end = line.len();
+        for (index, character) in line.char_indices() {
+            if character == '#' && previous_whitespace {
+                end = index;
+                break;
+            }
+
+            previous_whitespace = character.is_whitespace();
+        }
+
+        line[..end].trim().to_string()
+    }
+
+    fn parse_entry(&mut self) -> Result<(), String> {
+        let line = self.line;
+        let mut key = self.read_key();
+
+        if key == "export" {
+            self.skip_inline_whitespace();
+
+            if self.peek() != Some('=') {
+                key = self.read_key();
+            }
+        }
+
+        if !is_valid_key(&key) {
+            return Err(self.error(&format!("Invalid key '{}'", &key), line));
+        }
+
+        self.skip_inline_whitespace();
+        if self.next() != Some('=') {
+            return Err(self.error(&format!("Missing '=' after key '{}'", &key), line));
+        }
+        self.skip_inline_whitespace();
+
+        let value = match self.peek() {
+            Some('\'') => {
+                self.next();
+                self.read_quoted('\'')?
+            }
+            Some('"') => {
+                self.next();
+                let raw_value = self.read_quoted('"')?;
+                self.expand(&raw_value, true, line)?
+            }
+            _ => {
+                let raw_value = self.read_unquoted();
+                self.expand(&raw_value, false, line)?
+            }
+        };
+
+        self.values.shift_remove(&key);
+        self.values.insert(key, value);
+
+        Ok(())
+    }
+
+    fn parse(&mut self) -> Result<(), String> {
+        while let Some(character) = self.peek() {
+            if character.is_whitespace() {
+                self.next();
+            } else if character == '#' {
+                self.skip_line();
+            } else {
+                self.parse_entry()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the env file content and returns the env vars in the order they were defined.<br>
+/// Variable references are resolved from the previous file entries and if not found, using the
+/// provided lookup function (unless prefer_lookup is true, in which case the lookup is used first).
+pub(crate) fn parse(
+    content: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    prefer_lookup: bool,
+) -> Result<Vec<(String, String)>, String> {
+    let mut parser = Parser {
+        chars: content.replace("\r\n", "\n").chars().collect(),
+        index: 0,
+        line: 1,
+        values: IndexMap::new(),
+        lookup,
+        prefer_lookup,
+    };
+
+    parser.parse()?;
+
+    Ok(parser.values.into_iter().collect())
+}
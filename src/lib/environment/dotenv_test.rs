@@ -0,0 +1,202 @@
+use super::*;
+
+fn lookup_none(_: &str) -> Option<String> {
+    None
+}
+
+fn lookup_env(name: &str) -> Option<String> {
+    match name {
+        "EXISTING" => Some("existing".to_string()),
+        "EMPTY" => Some("".to_string()),
+        _ => None,
+    }
+}
+
+fn parse_to_strings(content: &str) -> Vec<(String, String)> {
+    parse(content, &lookup_env, false).unwrap()
+}
+
+fn create_entries(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn parse_empty() {
+    let output = parse("", &lookup_none, false).unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn parse_simple() {
+    let output = parse_to_strings(
+        r#"
+# comment
+KEY1=value1
+KEY2 = value 2  
+  KEY3=
+KEY4=value#not-comment # comment
+"#,
+    );
+
+    assert_eq!(
+        output,
+        create_entries(&[
+            ("KEY1", "value1"),
+            ("KEY2", "value 2"),
+            ("KEY3", ""),
+            ("KEY4", "value#not-comment"),
+        ])
+    );
+}
+
+#[test]
+fn parse_export() {
+    let output = parse_to_strings("export KEY1=value1\nexport=value2\n");
+
+    assert_eq!(
+        output,
+        create_entries(&[("KEY1", "value1"), ("export", "value2")])
+    );
+}
+
+#[test]
+fn parse_single_quoted() {
+    let output = parse_to_strings("KEY1='value \\n ${EXISTING} # not comment' # comment\n");
+
+    assert_eq!(
+        output,
+        create_entries(&[("KEY1", "value \\n ${EXISTING} # not comment")])
+    );
+}
+
+#[test]
+fn parse_double_quoted() {
+    let output = parse_to_strings(r#"KEY1="a\"b\\c\nd\$e ${EXISTING} \w""#);
+
+    assert_eq!(
+        output,
+        create_entries(&[("KEY1", "a\"b\\c\nd$e existing \\w")])
+    );
+}
+
+#[test]
+fn parse_multi_line() {
+    let output = parse_to_strings("KEY1=\"line1\nline2\"\nKEY2='line3\r\nline4'\nKEY3=value3");
+
+    assert_eq!(
+        output,
+        create_entries(&[
+            ("KEY1", "line1\nline2"),
+            ("KEY2", "line3\nline4"),
+            ("KEY3", "value3"),
+        ])
+    );
+}
+
+#[test]
+fn parse_interpolation() {
+    let output = parse_to_strings(
+        r#"
+KEY1=${EXISTING}
+KEY2=$KEY1-$MISSING-${KEY1}
+KEY3=${MISSING:-default}
+KEY4=${EMPTY:-default}
+KEY5=${EMPTY-default}
+KEY6=${MISSING-${EXISTING}}
+KEY7=cost: $5
+"#,
+    );
+
+    assert_eq!(
+        output,
+        create_entries(&[
+            ("KEY1", "existing"),
+            ("KEY2", "existing--existing"),
+            ("KEY3", "default"),
+            ("KEY4", "default"),
+            ("KEY5", ""),
+            ("KEY6", "existing"),
+            ("KEY7", "cost: $5"),
+        ])
+    );
+}
+
+#[test]
+fn parse_redefined() {
+    let output = parse_to_strings("KEY1=1\nKEY2=2\nKEY1=${KEY1}3");
+
+    assert_eq!(output, create_entries(&[("KEY2", "2"), ("KEY1", "13")]));
+}
+
+#[test]
+fn parse_missing_equals() {
+    let error = parse("KEY1=1\n\nKEY2 value", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Missing '=' after key 'KEY2' at line 3");
+}
+
+#[test]
+fn parse_invalid_key() {
+    let error = parse("1KEY=1", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Invalid key '1KEY' at line 1");
+}
+
+#[test]
+fn parse_unterminated_double_quote() {
+    let error = parse("KEY1=1\nKEY2=\"value\nKEY3=3", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Unterminated double quoted value at line 2");
+}
+
+#[test]
+fn parse_unterminated_single_quote() {
+    let error = parse("KEY1='value", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Unterminated single quoted value at line 1");
+}
+
+#[test]
+fn parse_characters_after_quote() {
+    let error = parse("\nKEY1=\"value\" other", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Unexpected characters after quoted value at line 2");
+}
+
+#[test]
+fn parse_unterminated_reference() {
+    let error = parse("KEY1=1\nKEY2=${KEY1", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Unterminated variable reference at line 2");
+}
+
+#[test]
+fn parse_invalid_reference() {
+    let error = parse("KEY1=${-value}", &lookup_none, false).unwrap_err();
+
+    assert_eq!(error, "Invalid variable reference '${-value}' at line 1");
+}
+
+#[test]
+fn parse_colon_without_dash_not_default() {
+    let error = parse("KEY1=${MISSING:value}", &lookup_none, false).unwrap_err();
+
+    assert_eq!(
+        error,
+        "Invalid variable reference '${MISSING:value}' at line 1"
+    );
+}
+
+#[test]
+fn parse_prefer_lookup() {
+    let output = parse("EXISTING=new\nKEY1=${EXISTING}", &lookup_env, true).unwrap();
+
+    assert_eq!(
+        output,
+        create_entries(&[("EXISTING", "new"), ("KEY1", "existing")])
+    );
+}
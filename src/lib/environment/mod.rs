@@ -4,6 +4,10 @@
 //!
 
 pub(crate) mod crateinfo;
+pub(crate) mod data_path;
+mod dotenv;
+pub(crate) mod provenance;
+pub(crate) mod required;
 
 #[cfg(test)]
 #[path = "mod_test.rs"]
@@ -12,13 +16,16 @@ mod mod_test;
 use crate::command;
 use crate::condition;
 use crate::error::CargoMakeError;
+use crate::functions;
 use crate::io;
 use crate::profile;
 use crate::scriptengine;
+use crate::secrets;
 use crate::time_summary;
 use crate::types::{
-    CliArgs, Config, CrateInfo, EnvFile, EnvInfo, EnvValue, EnvValueConditioned, EnvValueDecode,
-    EnvValuePathGlob, EnvValueScript, PackageInfo, ScriptValue, Step, Task, Workspace,
+    CliArgs, Config, CrateInfo, EnvFile, EnvInfo, EnvValue, EnvValueCommand, EnvValueConditioned,
+    EnvValueDataPath, EnvValueDecode, EnvValueFile, EnvValuePathGlob, EnvValueScript,
+    EnvValueSecret, PackageInfo, ScriptValue, Step, Task, Workspace,
 };
 use ci_info::types::CiInfo;
 use envmnt::{ExpandOptions, ExpansionType};
@@ -26,10 +33,16 @@ use fsio::path::from_path::FromPath;
 use git_info::types::GitInfo;
 use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+/// The cached command outputs are stored in the environment so they are also used by child
+/// cargo-make processes
+static COMMAND_OUTPUT_ENV_VAR_PREFIX: &str = "CARGO_MAKE_INTERNAL_COMMAND_OUTPUT_";
+
 fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
     match command::run_script_get_output(&env_value.script, None, &vec![], true, Some(false)) {
         Ok(output) => {
@@ -86,7 +99,18 @@ pub(crate) fn expand_value(value: &str) -> String {
 }
 
 fn evaluate_and_set_env(key: &str, value: &str) {
-    let env_value = expand_value(&value);
+    // functions are evaluated before the env vars are expanded
+    let function_value = match functions::expand_env_value(key, value) {
+        Ok(function_value) => function_value,
+        Err(error) => {
+            warn!(
+                "Unable to evaluate functions in env: {}, keeping the value as is. Error: {}",
+                key, error
+            );
+            value.to_string()
+        }
+    };
+    let env_value = expand_value(&function_value);
 
     debug!("Setting Env: {} Value: {}", &key, &env_value);
     envmnt::set(&key, &env_value);
@@ -155,6 +179,23 @@ fn set_env_for_conditional_value(key: &str, conditional_value: &EnvValueConditio
     }
 }
 
+fn set_env_for_secret_value(key: &str, secret_value: &EnvValueSecret) {
+    let valid = match secret_value.condition {
+        Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
+        None => true,
+    };
+
+    if valid {
+        if secret_value.secret {
+            secrets::add_key(key);
+        }
+
+        let value = expand_value(&secret_value.value);
+
+        evaluate_and_set_env(key, &value);
+    }
+}
+
 fn set_env_for_path_glob(key: &str, path_glob: &EnvValuePathGlob) {
     let path_list = io::get_path_list(
         &path_glob.glob,
@@ -166,6 +207,169 @@ fn set_env_for_path_glob(key: &str, path_glob: &EnvValuePathGlob) {
     set_env_for_list(key, &path_list);
 }
 
+fn set_env_for_file(key: &str, file_value: &EnvValueFile) {
+    let valid = match file_value.condition {
+        Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
+        None => true,
+    };
+
+    if valid {
+        let file = expand_value(&file_value.from_file);
+
+        match fsio::file::read_text_file(&file) {
+            Ok(content) => {
+                let value = if file_value.trim.unwrap_or(true) {
+                    content.trim().to_string()
+                } else {
+                    content
+                };
+
+                debug!("Setting Env: {} Value: {}", key, &value);
+                envmnt::set(key, &value);
+            }
+            Err(error) => error!(
+                "Unable to read file: {} for env: {} Error: {}",
+                &file, key, error
+            ),
+        };
+    }
+}
+
+fn get_data_path_file(data_path: &EnvValueDataPath) -> Option<(String, data_path::DataFormat)> {
+    match (&data_path.toml, &data_path.json, &data_path.yaml) {
+        (Some(file), None, None) => Some((file.to_string(), data_path::DataFormat::Toml)),
+        (None, Some(file), None) => Some((file.to_string(), data_path::DataFormat::Json)),
+        (None, None, Some(file)) => Some((file.to_string(), data_path::DataFormat::Yaml)),
+        _ => None,
+    }
+}
+
+fn set_env_for_data_path(key: &str, data_path: &EnvValueDataPath) {
+    let valid = match data_path.condition {
+        Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
+        None => true,
+    };
+
+    if valid {
+        let (file, format) = match get_data_path_file(data_path) {
+            Some(file_info) => file_info,
+            None => {
+                error!(
+                    "Exactly one of toml, json or yaml files must be defined for env: {}",
+                    key
+                );
+                return;
+            }
+        };
+        let file = expand_value(&file);
+        let path = expand_value(&data_path.path);
+
+        let content = match fsio::file::read_text_file(&file) {
+            Ok(content) => content,
+            Err(error) => {
+                match data_path.default_value {
+                    Some(ref value) => {
+                        debug!(
+                            "Unable to read file: {} for env: {} Error: {}, using default value.",
+                            &file, key, error
+                        );
+                        evaluate_and_set_env(key, value);
+                    }
+                    None => error!(
+                        "Unable to read file: {} for env: {} Error: {}",
+                        &file, key, error
+                    ),
+                };
+                return;
+            }
+        };
+
+        match data_path::get_value_from_content(&content, format, &path) {
+            Ok(Some(value)) => {
+                debug!("Setting Env: {} Value: {}", key, &value);
+                envmnt::set(key, &value);
+            }
+            Ok(None) => match data_path.default_value {
+                Some(ref value) => evaluate_and_set_env(key, value),
+                None => error!(
+                    "Key path: {} not found in file: {} for env: {}",
+                    &path, &file, key
+                ),
+            },
+            Err(error) => error!(
+                "Unable to parse file: {} for env: {} Error: {}",
+                &file, key, error
+            ),
+        };
+    }
+}
+
+fn get_command_output_env_var_name(cache_key: &str) -> String {
+    let hash = format!("{:x}", Sha256::digest(cache_key.as_bytes()));
+
+    format!("{}{}", COMMAND_OUTPUT_ENV_VAR_PREFIX, &hash[..16])
+}
+
+fn get_command_output(command: &str, args: &Option<Vec<String>>) -> Option<String> {
+    // the output may depend on the working directory (for example in workspace members)
+    let cwd = match env::current_dir() {
+        Ok(directory) => FromPath::from_path(&directory),
+        Err(_) => "".to_string(),
+    };
+    let cache_key = match args {
+        Some(args_vec) => format!("{}:{} {:?}", cwd, command, args_vec),
+        None => format!("{}:{}", cwd, command),
+    };
+
+    let cache_env_var = get_command_output_env_var_name(&cache_key);
+
+    if envmnt::exists(&cache_env_var) {
+        debug!("Using cached output for command: {}", &cache_key);
+        Some(envmnt::get_or(&cache_env_var, ""))
+    } else {
+        let output = command::run_command_get_output_string(command, args);
+
+        if let Some(ref output_value) = output {
+            envmnt::set(&cache_env_var, output_value);
+        }
+
+        output
+    }
+}
+
+fn set_env_for_command(key: &str, command_value: &EnvValueCommand) {
+    let command = expand_value(&command_value.command);
+    let args = command_value
+        .args
+        .as_ref()
+        .map(|args_vec| args_vec.iter().map(|arg| expand_value(arg)).collect());
+
+    match get_command_output(&command, &args) {
+        Some(output) => {
+            let value = if command_value.trim.unwrap_or(true) {
+                output.trim().to_string()
+            } else {
+                output
+            };
+
+            debug!("Setting Env: {} Value: {}", key, &value);
+            envmnt::set(key, &value);
+        }
+        None => {
+            if command_value.allow_failure.unwrap_or(false) {
+                let value = command_value.default.clone().unwrap_or_default();
+
+                evaluate_and_set_env(key, &value);
+            } else {
+                error!(
+                    "Error while evaluating command: {} for env: {}",
+                    &command, key
+                );
+            }
+        }
+    };
+}
+
 fn set_env_for_profile(
     profile_name: &str,
     sub_env: &IndexMap<String, EnvValue>,
@@ -186,6 +390,26 @@ fn set_env_for_profile(
     }
 }
 
+/// Returns a copy of all the current env vars
+pub(crate) fn get_env_snapshot() -> HashMap<String, String> {
+    env::vars().collect()
+}
+
+/// Restores the env to the provided snapshot, env vars which were not part of the snapshot are removed
+pub(crate) fn restore_env_snapshot(snapshot: &HashMap<String, String>) {
+    for (key, _) in env::vars() {
+        if !snapshot.contains_key(&key) {
+            envmnt::remove(&key);
+        }
+    }
+
+    for (key, value) in snapshot {
+        if env::var(key).ok().as_ref() != Some(value) {
+            envmnt::set(key, value);
+        }
+    }
+}
+
 /// Updates the env based on the provided data
 pub(crate) fn set_env(env: IndexMap<String, EnvValue>) {
     set_env_for_config(env, None, true)
@@ -213,10 +437,14 @@ pub(crate) fn set_env_for_config(
             EnvValue::List(ref value) => set_env_for_list(&key, value),
             EnvValue::Script(ref script_info) => set_env_for_script(&key, script_info),
             EnvValue::Decode(ref decode_info) => set_env_for_decode_info(&key, decode_info),
+            EnvValue::Secret(ref secret_value) => set_env_for_secret_value(key, secret_value),
             EnvValue::Conditional(ref conditioned_value) => {
                 set_env_for_conditional_value(&key, conditioned_value)
             }
             EnvValue::PathGlob(ref path_glob_info) => set_env_for_path_glob(&key, path_glob_info),
+            EnvValue::File(ref file_value) => set_env_for_file(key, file_value),
+            EnvValue::DataPath(ref data_path) => set_env_for_data_path(key, data_path),
+            EnvValue::Command(ref command_value) => set_env_for_command(key, command_value),
             EnvValue::Profile(ref sub_env) => {
                 if allow_sub_env {
                     set_env_for_profile(&key, sub_env, additional_profiles)
@@ -281,6 +509,8 @@ fn set_env_files_for_config(
                         Some(info.path),
                         info.base_path,
                         info.defaults_only.unwrap_or(false),
+                        info.secret.unwrap_or(false),
+                        &info.secret_keys.unwrap_or_default(),
                     )
                 } else {
                     false
@@ -294,6 +524,32 @@ fn set_env_files_for_config(
     all_loaded
 }
 
+/// Loads the optional .env.<profile> files of the current and additional profiles and returns
+/// the loaded file names.<br>
+/// The env vars of each file are attributed to that file in the env provenance.
+fn load_profile_env_files(additional_profiles: Option<&Vec<String>>) -> Vec<String> {
+    let mut profiles = vec![profile::get()];
+    if let Some(additional_profiles) = additional_profiles {
+        profiles.extend(additional_profiles.iter().cloned());
+    }
+
+    let working_directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", ".");
+    let mut loaded = vec![];
+    for profile_name in profiles {
+        let file_name = format!(".env.{}", &profile_name);
+
+        if Path::new(&working_directory).join(&file_name).exists()
+            && provenance::track(&format!("env file {}", &file_name), || {
+                load_env_file(Some(format!("./{}", &file_name)))
+            })
+        {
+            loaded.push(file_name);
+        }
+    }
+
+    loaded
+}
+
 fn set_env_scripts(
     env_scripts: Vec<String>,
     cli_arguments: &Vec<String>,
@@ -337,11 +593,24 @@ fn initialize_env(config: &Config, cli_args: &Vec<String>) -> Result<(), CargoMa
         None => None,
     };
 
-    set_env_files_for_config(config.env_files.clone(), additional_profiles);
+    provenance::track("env_files", || {
+        set_env_files_for_config(config.env_files.clone(), additional_profiles)
+    });
 
-    set_env_for_config(config.env.clone(), additional_profiles, true);
+    if config.config.load_profile_env_file.unwrap_or(false) {
+        let loaded = load_profile_env_files(additional_profiles);
+        debug!("Loaded profile env files: {:?}", &loaded);
+    }
+
+    let mut active_profiles = vec![profile::get()];
+    active_profiles.extend(additional_profiles.cloned().unwrap_or_default());
+    provenance::track_declared("[env]", &active_profiles, || {
+        set_env_for_config(config.env.clone(), additional_profiles, true)
+    });
 
-    set_env_scripts(config.env_scripts.clone(), cli_args)
+    provenance::track("env_scripts", || {
+        set_env_scripts(config.env_scripts.clone(), cli_args)
+    })
 }
 
 fn setup_env_for_duckscript() {
@@ -441,8 +710,13 @@ fn setup_env_for_crate(home: Option<PathBuf>) -> Result<CrateInfo, CargoMakeErro
 
 fn setup_env_for_git_repo() -> GitInfo {
     let info = git_info::get();
-    let git_info_clone = info.clone();
 
+    set_env_for_git_info(&info);
+
+    info
+}
+
+fn set_env_for_git_info(info: &GitInfo) {
     envmnt::set_optional("CARGO_MAKE_GIT_BRANCH", &info.current_branch);
     envmnt::set_optional("CARGO_MAKE_GIT_USER_NAME", &info.user_name);
     envmnt::set_optional("CARGO_MAKE_GIT_USER_EMAIL", &info.user_email);
@@ -454,13 +728,18 @@ fn setup_env_for_git_repo() -> GitInfo {
         "CARGO_MAKE_GIT_HEAD_LAST_COMMIT_HASH_PREFIX",
         &info.head.last_commit_hash_short,
     );
-
-    git_info_clone
 }
 
 fn setup_env_for_rust(home: Option<PathBuf>) -> RustInfo {
     let rustinfo = rust_info::get();
-    let rust_info_clone = rustinfo.clone();
+
+    set_env_for_rust_info(&rustinfo, home);
+
+    rustinfo
+}
+
+fn set_env_for_rust_info(rust_info_struct: &RustInfo, home: Option<PathBuf>) {
+    let rustinfo = rust_info_struct.clone();
 
     envmnt::set_optional("CARGO_MAKE_RUST_VERSION", &rustinfo.version);
 
@@ -501,19 +780,36 @@ fn setup_env_for_rust(home: Option<PathBuf>) -> RustInfo {
         "CARGO_MAKE_CRATE_TARGET_TRIPLE",
         &crateinfo::crate_target_triple(rustinfo.target_triple, home.clone()),
     );
-
-    rust_info_clone
 }
 
 fn setup_env_for_ci() -> CiInfo {
     let ci_info_struct = ci_info::get();
 
+    set_env_for_ci_info(&ci_info_struct);
+
+    ci_info_struct
+}
+
+fn set_env_for_ci_info(ci_info_struct: &CiInfo) {
     envmnt::set_bool("CARGO_MAKE_CI", ci_info_struct.ci);
     envmnt::set_bool("CARGO_MAKE_PR", ci_info_struct.pr.unwrap_or(false));
     envmnt::set_optional("CARGO_MAKE_CI_BRANCH_NAME", &ci_info_struct.branch_name);
     envmnt::set_optional("CARGO_MAKE_CI_VENDOR", &ci_info_struct.name);
+}
 
-    ci_info_struct
+/// Returns true if the git info loaded in the parent directory is still valid
+/// for the current working directory (no nested git repository).
+fn is_cached_git_info_valid(cached_git_info: &GitInfo) -> bool {
+    (cached_git_info.current_branch.is_some() || cached_git_info.head.last_commit_hash.is_some())
+        && !Path::new(".git").exists()
+}
+
+/// Returns true if the rust info loaded in the parent directory is still valid
+/// for the current working directory (no toolchain override).
+fn is_cached_rust_info_valid(cached_rust_info: &RustInfo) -> bool {
+    cached_rust_info.version.is_some()
+        && !Path::new("rust-toolchain").exists()
+        && !Path::new("rust-toolchain.toml").exists()
 }
 
 fn get_base_directory_name() -> Option<String> {
@@ -583,20 +879,37 @@ pub(crate) fn setup_env(
     home: Option<PathBuf>,
     time_summary_vec: &mut Vec<(String, u128)>,
 ) -> Result<EnvInfo, CargoMakeError> {
-    envmnt::set_bool("CARGO_MAKE", true);
-    envmnt::set("CARGO_MAKE_TASK", &task);
+    setup_env_with_cache(cli_args, config, task, home, time_summary_vec, None)
+}
 
-    envmnt::set("CARGO_MAKE_COMMAND", &cli_args.command);
+/// Sets up the env before the tasks execution.<br>
+/// If a cached env info is provided (for example, from the workspace level flow),
+/// the rust, git and CI info is reused instead of loaded again, as long as it is
+/// still valid for the current working directory.
+pub(crate) fn setup_env_with_cache(
+    cli_args: &CliArgs,
+    config: &Config,
+    task: &str,
+    home: Option<PathBuf>,
+    time_summary_vec: &mut Vec<(String, u128)>,
+    cached_env_info: Option<&EnvInfo>,
+) -> Result<EnvInfo, CargoMakeError> {
+    provenance::track("cargo-make invocation", || {
+        envmnt::set_bool("CARGO_MAKE", true);
+        envmnt::set("CARGO_MAKE_TASK", &task);
 
-    let task_arguments = match cli_args.arguments.clone() {
-        Some(args) => args,
-        None => vec![],
-    };
-    envmnt::set_list("CARGO_MAKE_TASK_ARGS", &task_arguments);
+        envmnt::set("CARGO_MAKE_COMMAND", &cli_args.command);
+
+        let task_arguments = match cli_args.arguments.clone() {
+            Some(args) => args,
+            None => vec![],
+        };
+        envmnt::set_list("CARGO_MAKE_TASK_ARGS", &task_arguments);
+    });
 
     // load duckscript_info
     let mut now = SystemTime::now();
-    setup_env_for_duckscript();
+    provenance::track("duckscript info", setup_env_for_duckscript);
     time_summary::add(time_summary_vec, "[Setup Env - Duckscript]", now);
 
     // load crate info
@@ -604,7 +917,7 @@ pub(crate) fn setup_env(
     let crate_info = if config.config.skip_crate_env_info.unwrap_or(false) {
         CrateInfo::new()
     } else {
-        setup_env_for_crate(home.clone())?
+        provenance::track("crate info", || setup_env_for_crate(home.clone()))?
     };
     time_summary::add(time_summary_vec, "[Setup Env - Crate Info]", now);
 
@@ -613,7 +926,14 @@ pub(crate) fn setup_env(
     let gitinfo = if config.config.skip_git_env_info.unwrap_or(false) {
         GitInfo::new()
     } else {
-        setup_env_for_git_repo()
+        provenance::track("git info", || match cached_env_info {
+            Some(env_info) if is_cached_git_info_valid(&env_info.git_info) => {
+                debug!("Reusing cached git info.");
+                set_env_for_git_info(&env_info.git_info);
+                env_info.git_info.clone()
+            }
+            _ => setup_env_for_git_repo(),
+        })
     };
     time_summary::add(time_summary_vec, "[Setup Env - Git]", now);
 
@@ -622,18 +942,33 @@ pub(crate) fn setup_env(
     let rustinfo = if config.config.skip_rust_env_info.unwrap_or(false) {
         RustInfo::new()
     } else {
-        setup_env_for_rust(home)
+        provenance::track("rust info", || match cached_env_info {
+            Some(env_info) if is_cached_rust_info_valid(&env_info.rust_info) => {
+                debug!("Reusing cached rust info.");
+                set_env_for_rust_info(&env_info.rust_info, home);
+                env_info.rust_info.clone()
+            }
+            _ => setup_env_for_rust(home),
+        })
     };
     time_summary::add(time_summary_vec, "[Setup Env - Rust]", now);
 
     // load CI info
     now = SystemTime::now();
-    let ci_info_struct = setup_env_for_ci();
+    let ci_info_struct = provenance::track("ci info", || match cached_env_info {
+        Some(env_info) => {
+            set_env_for_ci_info(&env_info.ci_info);
+            env_info.ci_info.clone()
+        }
+        None => setup_env_for_ci(),
+    });
     time_summary::add(time_summary_vec, "[Setup Env - CI]", now);
 
     // setup project info
     now = SystemTime::now();
-    setup_env_for_project(config, &crate_info)?;
+    provenance::track("project info", || {
+        setup_env_for_project(config, &crate_info)
+    })?;
     time_summary::add(time_summary_vec, "[Setup Env - Project]", now);
 
     // load env vars
@@ -712,14 +1047,36 @@ pub(crate) fn setup_cwd(cwd: Option<&str>) -> Option<PathBuf> {
     }
 }
 
+fn load_env_file_entries(
+    file_path: &str,
+    defaults_only: bool,
+) -> Result<Vec<(String, String)>, String> {
+    let content = fsio::file::read_text_file(file_path).map_err(|error| error.to_string())?;
+
+    // when only setting defaults, the existing env vars take precedence over the file values
+    dotenv::parse(
+        &content,
+        &|key| {
+            if envmnt::exists(key) {
+                Some(envmnt::get_or(key, ""))
+            } else {
+                None
+            }
+        },
+        defaults_only,
+    )
+}
+
 pub(crate) fn load_env_file(env_file: Option<String>) -> bool {
-    load_env_file_with_base_directory(env_file, None, false)
+    load_env_file_with_base_directory(env_file, None, false, false, &[])
 }
 
 fn load_env_file_with_base_directory(
     env_file: Option<String>,
     base_directory: Option<String>,
     defaults_only: bool,
+    secret: bool,
+    secret_keys: &[String],
 ) -> bool {
     match env_file {
         Some(file_name) => {
@@ -743,35 +1100,31 @@ fn load_env_file_with_base_directory(
             };
 
             match file_path.to_str() {
-                Some(file_path_str) => {
-                    let evaluate_env_var = |key: String, value: String| {
-                        let skip = if defaults_only {
-                            envmnt::exists(&key)
-                        } else {
-                            false
-                        };
+                Some(file_path_str) => match load_env_file_entries(file_path_str, defaults_only) {
+                    Ok(entries) => {
+                        for (key, value) in entries {
+                            if defaults_only && envmnt::exists(&key) {
+                                continue;
+                            }
 
-                        if skip {
-                            None
-                        } else {
-                            Some((key, expand_value(&value)))
-                        }
-                    };
+                            if secret || secret_keys.contains(&key) {
+                                secrets::add_key(&key);
+                            }
 
-                    match envmnt::evaluate_and_load_file(file_path_str, evaluate_env_var) {
-                        Err(error) => {
-                            error!(
-                                "Unable to load env file: {} Error: {:#?}",
-                                &file_path_str, error
-                            );
-                            false
-                        }
-                        _ => {
-                            debug!("Loaded env file: {}", &file_path_str);
-                            true
+                            envmnt::set(&key, &value);
                         }
+
+                        debug!("Loaded env file: {}", &file_path_str);
+                        true
                     }
-                }
+                    Err(error) => {
+                        error!(
+                            "Unable to load env file: {} Error: {}",
+                            &file_path_str, error
+                        );
+                        false
+                    }
+                },
                 None => false,
             }
         }
@@ -1,5 +1,6 @@
 use super::*;
 
+use crate::test;
 use crate::types::{ConfigSection, EnvFileInfo, EnvValueUnset, TaskCondition};
 use std::collections::HashMap;
 use std::env;
@@ -43,8 +44,13 @@ fn load_env_file_with_base_directory_not_defaults_and_exists() {
     envmnt::set("ENV2_TEST", "existing");
     envmnt::set("ENV3_TEST", "existing");
 
-    let output =
-        load_env_file_with_base_directory(Some("./examples/test.env".to_string()), None, false);
+    let output = load_env_file_with_base_directory(
+        Some("./examples/test.env".to_string()),
+        None,
+        false,
+        false,
+        &[],
+    );
 
     assert!(output);
 
@@ -65,6 +71,8 @@ fn load_env_file_with_base_directory_not_defaults_and_exists_path_has_env_var()
         Some("./${ENV_FILE_PATH_FOR_LOAD_ENV_FILE_TEST}.env".to_string()),
         None,
         false,
+        false,
+        &[],
     );
 
     assert!(output);
@@ -81,8 +89,13 @@ fn load_env_file_with_base_directory_defaults_only_and_exists() {
     envmnt::set("ENV2_TEST", "existing");
     envmnt::remove("ENV3_TEST");
 
-    let output =
-        load_env_file_with_base_directory(Some("./examples/test.env".to_string()), None, true);
+    let output = load_env_file_with_base_directory(
+        Some("./examples/test.env".to_string()),
+        None,
+        true,
+        false,
+        &[],
+    );
 
     assert!(output);
 
@@ -102,6 +115,32 @@ fn evaluate_and_set_env_simple() {
     );
 }
 
+#[test]
+#[ignore]
+fn evaluate_and_set_env_with_functions() {
+    envmnt::set("EVAL_SET_FUNCTIONS_LIST", "a|b");
+    envmnt::set("EVAL_SET_FUNCTIONS_NAME", "value");
+    evaluate_and_set_env(
+        "EVAL_SET_FUNCTIONS",
+        "${EVAL_SET_FUNCTIONS_NAME}: @@upper(${EVAL_SET_FUNCTIONS_NAME}) @@split(EVAL_SET_FUNCTIONS_LIST, |)",
+    );
+    assert_eq!(
+        envmnt::get_or_panic("EVAL_SET_FUNCTIONS"),
+        "value: VALUE a;b".to_string()
+    );
+}
+
+#[test]
+#[ignore]
+fn evaluate_and_set_env_invalid_function() {
+    envmnt::remove("EVAL_SET_INVALID_FUNCTION");
+    evaluate_and_set_env("EVAL_SET_INVALID_FUNCTION", "@@upper(a");
+    assert_eq!(
+        envmnt::get_or_panic("EVAL_SET_INVALID_FUNCTION"),
+        "@@upper(a".to_string()
+    );
+}
+
 #[test]
 fn evaluate_and_set_env_none_with_default() {
     envmnt::remove("EVAL_SET_NONE_WITH_DEFAULT");
@@ -329,6 +368,16 @@ fn set_env_script_with_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let mut env = IndexMap::new();
@@ -449,6 +498,16 @@ fn set_env_for_decode_info_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let decode_info = EnvValueDecode {
@@ -489,6 +548,16 @@ fn set_env_for_decode_info_condition_false() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let decode_info = EnvValueDecode {
@@ -503,6 +572,447 @@ fn set_env_for_decode_info_condition_false() {
     assert!(!envmnt::exists("ENV_DECODE_CONDITION_FALSE"));
 }
 
+#[test]
+#[ignore]
+fn load_env_file_dotenv_format() {
+    envmnt::remove("DOTENV_TEST_EXPORTED");
+    envmnt::remove("DOTENV_TEST_MULTI_LINE");
+    envmnt::remove("DOTENV_TEST_SINGLE_QUOTED");
+    envmnt::remove("DOTENV_TEST_DEFAULT");
+    envmnt::remove("DOTENV_TEST_NOT_DEFINED");
+
+    let output = load_env_file(Some("./src/lib/test/test_files/dotenv.env".to_string()));
+
+    assert!(output);
+    assert!(envmnt::is_equal("DOTENV_TEST_EXPORTED", "exported"));
+    assert!(envmnt::is_equal("DOTENV_TEST_MULTI_LINE", "line1\nline2"));
+    assert!(envmnt::is_equal(
+        "DOTENV_TEST_SINGLE_QUOTED",
+        "${DOTENV_TEST_EXPORTED}"
+    ));
+    assert!(envmnt::is_equal("DOTENV_TEST_DEFAULT", "default"));
+}
+
+#[test]
+#[ignore]
+fn load_env_file_dotenv_interpolation() {
+    let directory = test::get_temp_test_directory("load_env_file_dotenv_interpolation");
+    let file = directory.join("interpolation.env");
+    fsio::file::write_text_file(
+        &file,
+        r#"DOTENV_INTERPOLATION_BRACKETS=${DOTENV_INTERPOLATION_DEFINED}
+DOTENV_INTERPOLATION_NO_BRACKETS=$DOTENV_INTERPOLATION_DEFINED
+DOTENV_INTERPOLATION_COLON_DEFAULT=${DOTENV_INTERPOLATION_NOT_DEFINED:default}
+DOTENV_INTERPOLATION_NOT_DEFINED_VALUE=${DOTENV_INTERPOLATION_NOT_DEFINED}
+DOTENV_INTERPOLATION_SINGLE_QUOTED='${DOTENV_INTERPOLATION_DEFINED}'
+"#,
+    )
+    .unwrap();
+    envmnt::set("DOTENV_INTERPOLATION_DEFINED", "defined");
+    envmnt::remove("DOTENV_INTERPOLATION_NOT_DEFINED");
+
+    let output = load_env_file(Some(file.to_string_lossy().to_string()));
+
+    assert!(output);
+    // same as the env expansion used by previous versions
+    assert!(envmnt::is_equal("DOTENV_INTERPOLATION_BRACKETS", "defined"));
+    assert!(envmnt::is_equal(
+        "DOTENV_INTERPOLATION_COLON_DEFAULT",
+        "default"
+    ));
+    // dotenv semantics which differ from previous versions
+    assert!(envmnt::is_equal(
+        "DOTENV_INTERPOLATION_NO_BRACKETS",
+        "defined"
+    ));
+    assert!(envmnt::is_equal(
+        "DOTENV_INTERPOLATION_NOT_DEFINED_VALUE",
+        ""
+    ));
+    assert!(envmnt::is_equal(
+        "DOTENV_INTERPOLATION_SINGLE_QUOTED",
+        "${DOTENV_INTERPOLATION_DEFINED}"
+    ));
+}
+
+#[test]
+#[ignore]
+fn load_profile_env_files_valid() {
+    let directory = test::get_temp_test_directory("load_profile_env_files_valid");
+    fsio::file::write_text_file(
+        &directory.join(".env.profile_env_test"),
+        "PROFILE_ENV_FILE_TEST=main",
+    )
+    .unwrap();
+    fsio::file::write_text_file(
+        &directory.join(".env.profile_env_test_additional"),
+        "PROFILE_ENV_FILE_TEST_ADDITIONAL=additional",
+    )
+    .unwrap();
+
+    let working_directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", ".");
+    let profile_name = profile::get();
+    envmnt::remove("PROFILE_ENV_FILE_TEST");
+    envmnt::remove("PROFILE_ENV_FILE_TEST_ADDITIONAL");
+    envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", &directory);
+    profile::set("profile_env_test");
+
+    let loaded = load_profile_env_files(Some(&vec![
+        "profile_env_test_additional".to_string(),
+        "profile_env_test_missing".to_string(),
+    ]));
+
+    envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", &working_directory);
+    profile::set(&profile_name);
+
+    assert_eq!(
+        loaded,
+        vec![
+            ".env.profile_env_test".to_string(),
+            ".env.profile_env_test_additional".to_string()
+        ]
+    );
+    assert!(envmnt::is_equal("PROFILE_ENV_FILE_TEST", "main"));
+    assert_eq!(
+        provenance::get_sources("PROFILE_ENV_FILE_TEST")[0].source,
+        "env file .env.profile_env_test"
+    );
+    assert!(envmnt::is_equal(
+        "PROFILE_ENV_FILE_TEST_ADDITIONAL",
+        "additional"
+    ));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_secret_value_secret() {
+    envmnt::set("ENV_SECRET_VALUE_SOURCE", "secret-source-value");
+    envmnt::remove("ENV_SECRET_VALUE_SECRET");
+
+    let info = EnvValueSecret {
+        value: "${ENV_SECRET_VALUE_SOURCE}".to_string(),
+        secret: true,
+        condition: None,
+    };
+
+    set_env_for_secret_value("ENV_SECRET_VALUE_SECRET", &info);
+
+    assert!(envmnt::is_equal(
+        "ENV_SECRET_VALUE_SECRET",
+        "secret-source-value"
+    ));
+    assert_eq!(secrets::redact("token: secret-source-value"), "token: ***");
+}
+
+#[test]
+#[ignore]
+fn set_env_for_secret_value_not_secret() {
+    envmnt::remove("ENV_SECRET_VALUE_NOT_SECRET");
+
+    let info = EnvValueSecret {
+        value: "not-secret-value".to_string(),
+        secret: false,
+        condition: None,
+    };
+
+    set_env_for_secret_value("ENV_SECRET_VALUE_NOT_SECRET", &info);
+
+    assert!(envmnt::is_equal(
+        "ENV_SECRET_VALUE_NOT_SECRET",
+        "not-secret-value"
+    ));
+    assert_eq!(
+        secrets::redact("value: not-secret-value"),
+        "value: not-secret-value"
+    );
+}
+
+#[test]
+#[ignore]
+fn set_env_for_secret_value_condition_false() {
+    envmnt::remove("ENV_SECRET_VALUE_CONDITION_FALSE");
+
+    let mut condition = TaskCondition::default();
+    condition.platforms = Some(vec!["bad1".to_string()]);
+    let info = EnvValueSecret {
+        value: "secret-condition-value".to_string(),
+        secret: true,
+        condition: Some(condition),
+    };
+
+    set_env_for_secret_value("ENV_SECRET_VALUE_CONDITION_FALSE", &info);
+
+    assert!(!envmnt::exists("ENV_SECRET_VALUE_CONDITION_FALSE"));
+}
+
+#[test]
+#[ignore]
+fn load_env_file_with_base_directory_secret_keys() {
+    envmnt::remove("CARGO_MAKE_ENV_FILE_TEST1");
+    envmnt::remove("CARGO_MAKE_ENV_FILE_TEST2");
+
+    let output = load_env_file_with_base_directory(
+        Some("./test/test_files/env.env".to_string()),
+        Some("./src/lib".to_string()),
+        false,
+        false,
+        &["CARGO_MAKE_ENV_FILE_TEST2".to_string()],
+    );
+
+    assert!(output);
+    assert_eq!(secrets::redact("values: 1 2"), "values: 1 ***");
+
+    envmnt::remove("CARGO_MAKE_ENV_FILE_TEST1");
+    envmnt::remove("CARGO_MAKE_ENV_FILE_TEST2");
+    envmnt::remove("CARGO_MAKE_ENV_FILE_TEST3");
+}
+
+#[test]
+#[ignore]
+fn set_env_for_file_trimmed() {
+    envmnt::remove("ENV_FILE_VALUE_TRIMMED");
+
+    let info = EnvValueFile {
+        from_file: "./src/lib/test/test_files/text_file.txt".to_string(),
+        trim: None,
+        condition: None,
+    };
+
+    set_env_for_file("ENV_FILE_VALUE_TRIMMED", &info);
+
+    assert!(envmnt::is_equal("ENV_FILE_VALUE_TRIMMED", "text 1\ntext 2"));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_file_condition_false() {
+    envmnt::remove("ENV_FILE_VALUE_CONDITION_FALSE");
+
+    let mut condition = TaskCondition::default();
+    condition.platforms = Some(vec!["bad1".to_string()]);
+    let info = EnvValueFile {
+        from_file: "./src/lib/test/test_files/text_file.txt".to_string(),
+        trim: None,
+        condition: Some(condition),
+    };
+
+    set_env_for_file("ENV_FILE_VALUE_CONDITION_FALSE", &info);
+
+    assert!(!envmnt::exists("ENV_FILE_VALUE_CONDITION_FALSE"));
+}
+
+fn create_data_path(path: &str) -> EnvValueDataPath {
+    EnvValueDataPath {
+        toml: None,
+        json: None,
+        yaml: None,
+        path: path.to_string(),
+        default_value: None,
+        condition: None,
+    }
+}
+
+#[test]
+#[ignore]
+fn set_env_for_data_path_toml() {
+    envmnt::remove("ENV_DATA_PATH_TOML");
+
+    let mut info = create_data_path("package.name");
+    info.toml = Some("./Cargo.toml".to_string());
+
+    set_env_for_data_path("ENV_DATA_PATH_TOML", &info);
+
+    assert!(envmnt::is_equal("ENV_DATA_PATH_TOML", "cargo-make"));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_data_path_json() {
+    envmnt::remove("ENV_DATA_PATH_JSON");
+
+    let mut info = create_data_path("release.targets");
+    info.json = Some("./src/lib/test/test_files/data.json".to_string());
+
+    set_env_for_data_path("ENV_DATA_PATH_JSON", &info);
+
+    assert!(envmnt::is_equal("ENV_DATA_PATH_JSON", "linux;mac"));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_data_path_yaml() {
+    envmnt::remove("ENV_DATA_PATH_YAML");
+
+    let mut info = create_data_path("release.channel");
+    info.yaml = Some("./src/lib/test/test_files/data.yaml".to_string());
+
+    set_env_for_data_path("ENV_DATA_PATH_YAML", &info);
+
+    assert!(envmnt::is_equal("ENV_DATA_PATH_YAML", "beta"));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_data_path_default_value() {
+    envmnt::remove("ENV_DATA_PATH_DEFAULT_VALUE");
+
+    let mut info = create_data_path("release.missing");
+    info.yaml = Some("./src/lib/test/test_files/data.yaml".to_string());
+    info.default_value = Some("stable".to_string());
+
+    set_env_for_data_path("ENV_DATA_PATH_DEFAULT_VALUE", &info);
+
+    assert!(envmnt::is_equal("ENV_DATA_PATH_DEFAULT_VALUE", "stable"));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_data_path_missing_file_default_value() {
+    envmnt::remove("ENV_DATA_PATH_MISSING_FILE_DEFAULT_VALUE");
+
+    let mut info = create_data_path("release.channel");
+    info.yaml = Some("./src/lib/test/test_files/missing_data.yaml".to_string());
+    info.default_value = Some("stable".to_string());
+
+    set_env_for_data_path("ENV_DATA_PATH_MISSING_FILE_DEFAULT_VALUE", &info);
+
+    assert!(envmnt::is_equal(
+        "ENV_DATA_PATH_MISSING_FILE_DEFAULT_VALUE",
+        "stable"
+    ));
+}
+
+#[test]
+fn get_data_path_file_valid() {
+    let mut info = create_data_path("key");
+    info.json = Some("data.json".to_string());
+
+    let (file, format) = get_data_path_file(&info).unwrap();
+
+    assert_eq!(file, "data.json");
+    assert_eq!(format, data_path::DataFormat::Json);
+}
+
+#[test]
+fn get_data_path_file_none() {
+    let info = create_data_path("key");
+
+    assert!(get_data_path_file(&info).is_none());
+}
+
+#[test]
+fn get_data_path_file_multiple() {
+    let mut info = create_data_path("key");
+    info.toml = Some("data.toml".to_string());
+    info.yaml = Some("data.yaml".to_string());
+
+    assert!(get_data_path_file(&info).is_none());
+}
+
+fn create_command_value(command: &str, args: Option<Vec<String>>) -> EnvValueCommand {
+    EnvValueCommand {
+        command: command.to_string(),
+        args,
+        trim: None,
+        allow_failure: None,
+        default: None,
+    }
+}
+
+#[test]
+#[ignore]
+fn set_env_for_command_valid() {
+    envmnt::remove("ENV_COMMAND_VALID");
+
+    let info = create_command_value("rustc", Some(vec!["--version".to_string()]));
+
+    set_env_for_command("ENV_COMMAND_VALID", &info);
+
+    let value = envmnt::get_or_panic("ENV_COMMAND_VALID");
+    assert!(value.starts_with("rustc "));
+    assert_eq!(value, value.trim());
+}
+
+#[test]
+#[ignore]
+fn set_env_for_command_allow_failure_default() {
+    envmnt::remove("ENV_COMMAND_ALLOW_FAILURE_DEFAULT");
+
+    let mut info = create_command_value("cargo-make-test-bad-command", None);
+    info.allow_failure = Some(true);
+    info.default = Some("default value".to_string());
+
+    set_env_for_command("ENV_COMMAND_ALLOW_FAILURE_DEFAULT", &info);
+
+    assert!(envmnt::is_equal(
+        "ENV_COMMAND_ALLOW_FAILURE_DEFAULT",
+        "default value"
+    ));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_command_allow_failure_no_default() {
+    envmnt::remove("ENV_COMMAND_ALLOW_FAILURE_NO_DEFAULT");
+
+    let mut info = create_command_value("cargo-make-test-bad-command", None);
+    info.allow_failure = Some(true);
+
+    set_env_for_command("ENV_COMMAND_ALLOW_FAILURE_NO_DEFAULT", &info);
+
+    assert!(envmnt::is_equal("ENV_COMMAND_ALLOW_FAILURE_NO_DEFAULT", ""));
+}
+
+#[test]
+#[ignore]
+fn set_env_for_command_cached() {
+    envmnt::remove("ENV_COMMAND_CACHED");
+
+    let args = Some(vec!["arg1".to_string()]);
+    let info = create_command_value("cargo-make-test-cached-command", args.clone());
+
+    assert!(get_command_output("cargo-make-test-cached-command", &args).is_none());
+
+    // simulates the cache inherited from a parent cargo-make process
+    let cwd: String = FromPath::from_path(&env::current_dir().unwrap());
+    envmnt::set(
+        get_command_output_env_var_name(&format!(
+            "{}:cargo-make-test-cached-command [\"arg1\"]",
+            cwd
+        )),
+        " cached output\n",
+    );
+
+    set_env_for_command("ENV_COMMAND_CACHED", &info);
+
+    assert!(envmnt::is_equal("ENV_COMMAND_CACHED", "cached output"));
+}
+
+#[test]
+#[ignore]
+fn get_command_output_stored_in_env() {
+    let args = Some(vec!["--version".to_string()]);
+    let cwd: String = FromPath::from_path(&env::current_dir().unwrap());
+    let cache_env_var = get_command_output_env_var_name(&format!("{}:cargo [\"--version\"]", cwd));
+    envmnt::remove(&cache_env_var);
+
+    let output = get_command_output("cargo", &args).unwrap();
+
+    assert!(cache_env_var.starts_with("CARGO_MAKE_INTERNAL_COMMAND_OUTPUT_"));
+    assert!(envmnt::is_equal(&cache_env_var, &output));
+
+    // child processes inherit the cached output
+    let child_env = std::process::Command::new(if cfg!(windows) { "cmd" } else { "env" })
+        .args(if cfg!(windows) {
+            vec!["/C", "set"]
+        } else {
+            vec![]
+        })
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&child_env.stdout).contains(&cache_env_var));
+}
+
 #[test]
 #[ignore]
 fn set_env_for_conditional_value_no_condition() {
@@ -543,6 +1053,16 @@ fn set_env_for_conditional_value_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let info = EnvValueConditioned {
@@ -580,6 +1100,16 @@ fn set_env_for_conditional_value_condition_false() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     };
 
     let info = EnvValueConditioned {
@@ -726,6 +1256,16 @@ fn set_env_for_config_conditional() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
     };
 
@@ -845,6 +1385,8 @@ fn set_env_files_for_config_base_directory() {
                 base_path: Some("./src/lib".to_string()),
                 profile: None,
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
             EnvFile::Path("./src/lib/test/test_files/profile.env".to_string()),
         ],
@@ -881,12 +1423,16 @@ fn set_env_files_for_config_profile() {
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test1".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
             EnvFile::Info(EnvFileInfo {
                 path: "./test/test_files/env.env".to_string(),
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test2".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
         ],
         None,
@@ -922,12 +1468,16 @@ fn set_env_files_for_config_profile_inverse() {
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test2".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
             EnvFile::Info(EnvFileInfo {
                 path: "./test/test_files/profile.env".to_string(),
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test1".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
         ],
         None,
@@ -963,12 +1513,16 @@ fn set_env_files_for_config_additional_profiles() {
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test1".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
             EnvFile::Info(EnvFileInfo {
                 path: "./test/test_files/env.env".to_string(),
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test2".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
         ],
         Some(&vec!["env_test2".to_string()]),
@@ -1016,18 +1570,24 @@ fn initialize_env_all() {
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test1".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
             EnvFile::Info(EnvFileInfo {
                 path: "./test/test_files/env.env".to_string(),
                 base_path: Some("./src/lib".to_string()),
                 profile: Some("env_test2".to_string()),
                 defaults_only: None,
+                secret: None,
+                secret_keys: None,
             }),
         ],
         env,
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     initialize_env(&config, &vec![]).unwrap();
@@ -1061,8 +1621,10 @@ fn setup_env_empty() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1092,8 +1654,10 @@ fn setup_env_skip_git() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1113,8 +1677,10 @@ fn setup_env_skip_rust() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1134,8 +1700,10 @@ fn setup_env_skip_crate() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1183,8 +1751,10 @@ fn setup_env_cli_arguments() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     envmnt::set("CARGO_MAKE_TASK_ARGS", "EMPTY");
@@ -1205,8 +1775,10 @@ fn setup_env_values() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.env.insert(
         "MY_ENV_KEY".to_string(),
@@ -1236,8 +1808,10 @@ fn setup_env_script() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.env.insert(
         "MY_ENV_SCRIPT_KEY".to_string(),
@@ -1925,8 +2499,10 @@ fn setup_env_for_project_crate() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let crate_info = crateinfo::load().unwrap();
@@ -1954,8 +2530,10 @@ fn setup_env_for_project_workspace_with_main_crate() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1978,8 +2556,10 @@ fn setup_env_for_project_workspace_no_main_crate() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1993,3 +2573,29 @@ fn setup_env_for_project_workspace_no_main_crate() {
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "workspace1"));
     assert!(!envmnt::exists("CARGO_MAKE_PROJECT_VERSION"));
 }
+
+#[test]
+#[ignore]
+fn restore_env_snapshot_reverts_changes() {
+    envmnt::set("TEST_ENV_SNAPSHOT_CHANGED", "original");
+    envmnt::set("TEST_ENV_SNAPSHOT_REMOVED", "original");
+    envmnt::remove("TEST_ENV_SNAPSHOT_ADDED");
+
+    let snapshot = get_env_snapshot();
+
+    envmnt::set("TEST_ENV_SNAPSHOT_CHANGED", "changed");
+    envmnt::remove("TEST_ENV_SNAPSHOT_REMOVED");
+    envmnt::set("TEST_ENV_SNAPSHOT_ADDED", "added");
+
+    restore_env_snapshot(&snapshot);
+
+    assert_eq!(
+        envmnt::get_or_panic("TEST_ENV_SNAPSHOT_CHANGED"),
+        "original"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("TEST_ENV_SNAPSHOT_REMOVED"),
+        "original"
+    );
+    assert!(!envmnt::exists("TEST_ENV_SNAPSHOT_ADDED"));
+}
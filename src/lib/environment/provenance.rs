@@ -0,0 +1,236 @@
+//! # provenance
+//!
+//! Keeps track of which source (cli, env files, makefiles, cargo-make setup, ...) set each env var
+//! and which earlier sources it overrode.<br>
+//! Makefile env blocks are recorded as declarations while loading the makefiles and are attributed
+//! once the env block is applied.
+//!
+
+#[cfg(test)]
+#[path = "provenance_test.rs"]
+mod provenance_test;
+
+use crate::types::EnvValue;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Mutex;
+
+/// The source name of values which were already defined when cargo-make was invoked
+pub(crate) static PROCESS_ENV_SOURCE: &str = "process environment";
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single source which modified an env var
+pub(crate) struct EnvSource {
+    /// The source description
+    pub(crate) source: String,
+    /// The value set by the source (None if the value was removed or is unknown)
+    pub(crate) value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Declaration {
+    key: String,
+    source: String,
+    profile: Option<String>,
+}
+
+static SOURCES: Lazy<Mutex<IndexMap<String, Vec<EnvSource>>>> =
+    Lazy::new(|| Mutex::new(IndexMap::new()));
+
+static DECLARATIONS: Lazy<Mutex<Vec<Declaration>>> = Lazy::new(|| Mutex::new(vec![]));
+
+fn snapshot() -> HashMap<String, String> {
+    env::vars().collect()
+}
+
+fn add_source(
+    sources: &mut IndexMap<String, Vec<EnvSource>>,
+    key: &str,
+    previous_value: Option<&String>,
+    source: EnvSource,
+) {
+    let key_sources = sources.entry(key.to_string()).or_default();
+
+    if key_sources.is_empty() {
+        if let Some(value) = previous_value {
+            key_sources.push(EnvSource {
+                source: PROCESS_ENV_SOURCE.to_string(),
+                value: Some(value.to_string()),
+            });
+        }
+    }
+
+    key_sources.push(source);
+}
+
+fn record_changes(before: &HashMap<String, String>, get_sources: &dyn Fn(&str) -> Vec<String>) {
+    let after = snapshot();
+    let mut sources = SOURCES.lock().unwrap();
+
+    let mut changed_keys: Vec<String> = after
+        .iter()
+        .filter(|(key, value)| before.get(*key) != Some(*value))
+        .map(|(key, _)| key.to_string())
+        .chain(
+            before
+                .keys()
+                .filter(|key| !after.contains_key(*key))
+                .map(|key| key.to_string()),
+        )
+        .collect();
+    changed_keys.sort();
+
+    for key in &changed_keys {
+        let key_sources = get_sources(key);
+        let count = key_sources.len();
+
+        for (index, source) in key_sources.into_iter().enumerate() {
+            // only the last source actually defined the current value
+            let value = if index + 1 == count {
+                after.get(key).cloned()
+            } else {
+                None
+            };
+
+            add_source(
+                &mut sources,
+                key,
+                before.get(key),
+                EnvSource { source, value },
+            );
+        }
+    }
+}
+
+/// Invokes the provided action and attributes all env changes it made to the provided source
+pub(crate) fn track<T>(source: &str, action: impl FnOnce() -> T) -> T {
+    let before = snapshot();
+
+    let output = action();
+
+    record_changes(&before, &|_| vec![source.to_string()]);
+
+    output
+}
+
+/// Invokes the provided action which applies the makefiles env block and attributes the env
+/// changes to the makefiles which declared them (or the provided source if not declared).
+pub(crate) fn track_declared<T>(
+    source: &str,
+    active_profiles: &[String],
+    action: impl FnOnce() -> T,
+) -> T {
+    let before = snapshot();
+
+    let output = action();
+
+    let declarations = DECLARATIONS.lock().unwrap().clone();
+    record_changes(&before, &|key| {
+        let mut key_sources: Vec<String> = declarations
+            .iter()
+            .filter(|declaration| declaration.key == key)
+            .filter(|declaration| match declaration.profile {
+                Some(ref profile) => active_profiles.contains(profile),
+                None => true,
+            })
+            .map(|declaration| declaration.source.to_string())
+            .collect();
+        key_sources.dedup();
+
+        if key_sources.is_empty() {
+            vec![source.to_string()]
+        } else {
+            key_sources
+        }
+    });
+
+    output
+}
+
+/// Records all env vars defined in the provided env block as declared by the provided source
+pub(crate) fn declare(env: &IndexMap<String, EnvValue>, source: &str) {
+    let mut declarations = DECLARATIONS.lock().unwrap();
+
+    for (key, value) in env {
+        match value {
+            EnvValue::Profile(sub_env) => {
+                for sub_key in sub_env.keys() {
+                    declarations.push(Declaration {
+                        key: sub_key.to_string(),
+                        source: format!("{} [env.{}]", source, key),
+                        profile: Some(key.to_string()),
+                    });
+                }
+            }
+            _ => declarations.push(Declaration {
+                key: key.to_string(),
+                source: format!("{} [env]", source),
+                profile: None,
+            }),
+        }
+    }
+}
+
+/// Records the provided env var as declared by the provided source
+pub(crate) fn declare_key(key: &str, source: &str) {
+    DECLARATIONS.lock().unwrap().push(Declaration {
+        key: key.to_string(),
+        source: source.to_string(),
+        profile: None,
+    });
+}
+
+/// Returns the amount of declarations recorded so far
+pub(crate) fn get_declarations_count() -> usize {
+    DECLARATIONS.lock().unwrap().len()
+}
+
+/// Moves all declarations recorded from the provided start index so they precede the
+/// declarations recorded from the provided target index (meaning they are overridden by them).
+pub(crate) fn lower_declarations_priority(start: usize, target: usize) {
+    let mut declarations = DECLARATIONS.lock().unwrap();
+
+    let count = declarations.len();
+    if target < start && start <= count {
+        declarations[target..].rotate_right(count - start);
+    }
+}
+
+/// Returns all sources of the provided env var, the last source defined the current value
+pub(crate) fn get_sources(key: &str) -> Vec<EnvSource> {
+    let sources = SOURCES.lock().unwrap();
+
+    match sources.get(key) {
+        Some(key_sources) => key_sources.clone(),
+        None => vec![],
+    }
+}
+
+/// Clears all recorded sources and declarations
+pub(crate) fn clear() {
+    SOURCES.lock().unwrap().clear();
+    DECLARATIONS.lock().unwrap().clear();
+}
+
+#[derive(Debug, Default)]
+/// Holds all recorded sources and declarations of a flow
+pub(crate) struct ProvenanceState {
+    sources: IndexMap<String, Vec<EnvSource>>,
+    declarations: Vec<Declaration>,
+}
+
+/// Clears all recorded sources and declarations and returns them
+pub(crate) fn take() -> ProvenanceState {
+    ProvenanceState {
+        sources: std::mem::take(&mut *SOURCES.lock().unwrap()),
+        declarations: std::mem::take(&mut *DECLARATIONS.lock().unwrap()),
+    }
+}
+
+/// Replaces all recorded sources and declarations with the provided state
+pub(crate) fn restore(state: ProvenanceState) {
+    *SOURCES.lock().unwrap() = state.sources;
+    *DECLARATIONS.lock().unwrap() = state.declarations;
+}
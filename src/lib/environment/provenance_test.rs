@@ -0,0 +1,208 @@
+use super::*;
+
+#[test]
+#[ignore]
+fn track_new_value() {
+    clear();
+    envmnt::remove("TEST_PROVENANCE_TRACK_NEW");
+
+    let output = track("source1", || {
+        envmnt::set("TEST_PROVENANCE_TRACK_NEW", "value1");
+        true
+    });
+
+    assert!(output);
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_TRACK_NEW"),
+        vec![EnvSource {
+            source: "source1".to_string(),
+            value: Some("value1".to_string()),
+        }]
+    );
+}
+
+#[test]
+#[ignore]
+fn track_overridden_value() {
+    clear();
+    envmnt::set("TEST_PROVENANCE_TRACK_OVERRIDE", "initial");
+
+    track("source1", || {
+        envmnt::set("TEST_PROVENANCE_TRACK_OVERRIDE", "value1")
+    });
+    track("source2", || {
+        envmnt::set("TEST_PROVENANCE_TRACK_OVERRIDE", "value2")
+    });
+
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_TRACK_OVERRIDE"),
+        vec![
+            EnvSource {
+                source: PROCESS_ENV_SOURCE.to_string(),
+                value: Some("initial".to_string()),
+            },
+            EnvSource {
+                source: "source1".to_string(),
+                value: Some("value1".to_string()),
+            },
+            EnvSource {
+                source: "source2".to_string(),
+                value: Some("value2".to_string()),
+            }
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn track_unchanged_value() {
+    clear();
+    envmnt::set("TEST_PROVENANCE_TRACK_UNCHANGED", "value");
+
+    track("source1", || {
+        envmnt::set("TEST_PROVENANCE_TRACK_UNCHANGED", "value")
+    });
+
+    assert!(get_sources("TEST_PROVENANCE_TRACK_UNCHANGED").is_empty());
+}
+
+#[test]
+#[ignore]
+fn track_removed_value() {
+    clear();
+    envmnt::set("TEST_PROVENANCE_TRACK_REMOVED", "value");
+
+    track("source1", || {
+        envmnt::remove("TEST_PROVENANCE_TRACK_REMOVED")
+    });
+
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_TRACK_REMOVED"),
+        vec![
+            EnvSource {
+                source: PROCESS_ENV_SOURCE.to_string(),
+                value: Some("value".to_string()),
+            },
+            EnvSource {
+                source: "source1".to_string(),
+                value: None,
+            }
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn track_declared_values() {
+    clear();
+    envmnt::remove("TEST_PROVENANCE_DECLARED1");
+    envmnt::remove("TEST_PROVENANCE_DECLARED2");
+    envmnt::remove("TEST_PROVENANCE_DECLARED3");
+
+    let mut base_env = IndexMap::new();
+    base_env.insert(
+        "TEST_PROVENANCE_DECLARED1".to_string(),
+        EnvValue::Value("base".to_string()),
+    );
+    declare(&base_env, "base");
+
+    let mut profile_env = IndexMap::new();
+    profile_env.insert(
+        "TEST_PROVENANCE_DECLARED2".to_string(),
+        EnvValue::Value("profile".to_string()),
+    );
+    let mut main_env = IndexMap::new();
+    main_env.insert(
+        "TEST_PROVENANCE_DECLARED1".to_string(),
+        EnvValue::Value("main".to_string()),
+    );
+    main_env.insert("prod".to_string(), EnvValue::Profile(profile_env.clone()));
+    main_env.insert("other".to_string(), EnvValue::Profile(profile_env));
+    declare(&main_env, "main");
+
+    track_declared("default", &vec!["prod".to_string()], || {
+        envmnt::set("TEST_PROVENANCE_DECLARED1", "main");
+        envmnt::set("TEST_PROVENANCE_DECLARED2", "profile");
+        envmnt::set("TEST_PROVENANCE_DECLARED3", "other");
+    });
+
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_DECLARED1"),
+        vec![
+            EnvSource {
+                source: "base [env]".to_string(),
+                value: None,
+            },
+            EnvSource {
+                source: "main [env]".to_string(),
+                value: Some("main".to_string()),
+            }
+        ]
+    );
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_DECLARED2"),
+        vec![EnvSource {
+            source: "main [env.prod]".to_string(),
+            value: Some("profile".to_string()),
+        }]
+    );
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_DECLARED3"),
+        vec![EnvSource {
+            source: "default".to_string(),
+            value: Some("other".to_string()),
+        }]
+    );
+}
+
+#[test]
+#[ignore]
+fn lower_declarations_priority_moved() {
+    clear();
+    envmnt::remove("TEST_PROVENANCE_PRIORITY");
+
+    declare_key("TEST_PROVENANCE_PRIORITY", "core");
+    let member_start = get_declarations_count();
+    declare_key("TEST_PROVENANCE_PRIORITY", "member");
+    let workspace_start = get_declarations_count();
+    declare_key("TEST_PROVENANCE_PRIORITY", "workspace");
+
+    lower_declarations_priority(workspace_start, member_start);
+
+    track_declared("default", &vec![], || {
+        envmnt::set("TEST_PROVENANCE_PRIORITY", "member")
+    });
+
+    let sources: Vec<String> = get_sources("TEST_PROVENANCE_PRIORITY")
+        .iter()
+        .map(|source| source.source.to_string())
+        .collect();
+    assert_eq!(sources, vec!["core", "workspace", "member"]);
+}
+
+#[test]
+#[ignore]
+fn take_and_restore() {
+    clear();
+    envmnt::remove("TEST_PROVENANCE_TAKE_RESTORE");
+    track("source1", || {
+        envmnt::set("TEST_PROVENANCE_TAKE_RESTORE", "value1")
+    });
+
+    let state = take();
+
+    assert!(get_sources("TEST_PROVENANCE_TAKE_RESTORE").is_empty());
+
+    track("source2", || {
+        envmnt::set("TEST_PROVENANCE_TAKE_RESTORE", "value2")
+    });
+    restore(state);
+
+    assert_eq!(
+        get_sources("TEST_PROVENANCE_TAKE_RESTORE"),
+        vec![EnvSource {
+            source: "source1".to_string(),
+            value: Some("value1".to_string()),
+        }]
+    );
+}
@@ -0,0 +1,100 @@
+//! # required
+//!
+//! Validates that all required env vars are defined and hold valid values.
+//!
+
+#[cfg(test)]
+#[path = "required_test.rs"]
+mod required_test;
+
+use crate::error::CargoMakeError;
+use crate::types::{EnvRequired, EnvRequiredInfo};
+use indexmap::IndexMap;
+use regex::Regex;
+
+fn add_help(issue: String, info: &EnvRequiredInfo) -> String {
+    match info.help {
+        Some(ref help) => format!("{} - {}", issue, help),
+        None => issue,
+    }
+}
+
+fn get_issue(key: &str, info: &EnvRequiredInfo) -> Option<String> {
+    let value = envmnt::get_or(key, "");
+
+    let issue = if value.is_empty() {
+        Some(format!("{} is not defined", key))
+    } else {
+        let allowed_issue = match info.allowed_values {
+            Some(ref allowed_values) if !allowed_values.contains(&value) => Some(format!(
+                "{} value: {} is not one of: {}",
+                key,
+                &value,
+                allowed_values.join(", ")
+            )),
+            _ => None,
+        };
+
+        allowed_issue.or_else(|| match info.pattern {
+            Some(ref pattern) => match Regex::new(pattern) {
+                Ok(regex) if regex.is_match(&value) => None,
+                Ok(_) => Some(format!(
+                    "{} value: {} does not match pattern: {}",
+                    key, &value, pattern
+                )),
+                Err(error) => Some(format!(
+                    "{} has an invalid pattern: {}, {}",
+                    key, pattern, error
+                )),
+            },
+            None => None,
+        })
+    };
+
+    issue.map(|issue| add_help(issue, info))
+}
+
+/// Returns the issues found for all missing or invalid required env vars
+pub(crate) fn get_issues(env_required: &IndexMap<String, EnvRequired>) -> Vec<String> {
+    let mut issues = vec![];
+
+    for (key, required) in env_required {
+        let issue = match required {
+            EnvRequired::Boolean(false) => None,
+            EnvRequired::Boolean(true) => get_issue(key, &EnvRequiredInfo::default()),
+            EnvRequired::Info(info) => get_issue(key, info),
+        };
+
+        if let Some(value) = issue {
+            issues.push(value);
+        }
+    }
+
+    issues
+}
+
+/// Validates the required env vars and returns an error listing all the missing or invalid
+/// env vars (if any).
+pub(crate) fn validate(
+    env_required: &Option<IndexMap<String, EnvRequired>>,
+    scope: &str,
+) -> Result<(), CargoMakeError> {
+    let issues = match env_required {
+        Some(ref env_required_map) => get_issues(env_required_map),
+        None => vec![],
+    };
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        let lines: Vec<String> = issues
+            .iter()
+            .map(|issue| format!("  * {}", issue))
+            .collect();
+
+        Err(CargoMakeError::EnvRequired(
+            scope.to_string(),
+            lines.join("\n"),
+        ))
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+
+fn create_info(
+    pattern: Option<&str>,
+    allowed_values: Option<Vec<&str>>,
+    help: Option<&str>,
+) -> EnvRequired {
+    EnvRequired::Info(EnvRequiredInfo {
+        pattern: pattern.map(|value| value.to_string()),
+        allowed_values: allowed_values
+            .map(|values| values.iter().map(|value| value.to_string()).collect()),
+        help: help.map(|value| value.to_string()),
+    })
+}
+
+#[test]
+fn get_issues_empty() {
+    let issues = get_issues(&IndexMap::new());
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+#[ignore]
+fn get_issues_boolean() {
+    envmnt::set("TEST_ENV_REQUIRED_BOOLEAN_DEFINED", "value");
+    envmnt::remove("TEST_ENV_REQUIRED_BOOLEAN_MISSING");
+    envmnt::set("TEST_ENV_REQUIRED_BOOLEAN_EMPTY", "");
+    envmnt::remove("TEST_ENV_REQUIRED_BOOLEAN_DISABLED");
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_ENV_REQUIRED_BOOLEAN_DEFINED".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_BOOLEAN_MISSING".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_BOOLEAN_EMPTY".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_BOOLEAN_DISABLED".to_string(),
+        EnvRequired::Boolean(false),
+    );
+
+    let issues = get_issues(&env_required);
+
+    assert_eq!(
+        issues,
+        vec![
+            "TEST_ENV_REQUIRED_BOOLEAN_MISSING is not defined".to_string(),
+            "TEST_ENV_REQUIRED_BOOLEAN_EMPTY is not defined".to_string()
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn get_issues_missing_with_help() {
+    envmnt::remove("TEST_ENV_REQUIRED_MISSING_HELP");
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_ENV_REQUIRED_MISSING_HELP".to_string(),
+        create_info(None, None, Some("set it")),
+    );
+
+    let issues = get_issues(&env_required);
+
+    assert_eq!(
+        issues,
+        vec!["TEST_ENV_REQUIRED_MISSING_HELP is not defined - set it".to_string()]
+    );
+}
+
+#[test]
+#[ignore]
+fn get_issues_allowed_values() {
+    envmnt::set("TEST_ENV_REQUIRED_ALLOWED_VALID", "staging");
+    envmnt::set("TEST_ENV_REQUIRED_ALLOWED_INVALID", "dev");
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_ENV_REQUIRED_ALLOWED_VALID".to_string(),
+        create_info(None, Some(vec!["staging", "production"]), None),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_ALLOWED_INVALID".to_string(),
+        create_info(None, Some(vec!["staging", "production"]), Some("help")),
+    );
+
+    let issues = get_issues(&env_required);
+
+    assert_eq!(
+        issues,
+        vec![
+            "TEST_ENV_REQUIRED_ALLOWED_INVALID value: dev is not one of: staging, production - help"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn get_issues_pattern() {
+    envmnt::set("TEST_ENV_REQUIRED_PATTERN_VALID", "1.2.3");
+    envmnt::set("TEST_ENV_REQUIRED_PATTERN_INVALID", "1.x");
+    envmnt::set("TEST_ENV_REQUIRED_PATTERN_BAD_REGEX", "1");
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_ENV_REQUIRED_PATTERN_VALID".to_string(),
+        create_info(Some(r"^\d+\.\d+\.\d+$"), None, None),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_PATTERN_INVALID".to_string(),
+        create_info(Some(r"^\d+\.\d+\.\d+$"), None, None),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_PATTERN_BAD_REGEX".to_string(),
+        create_info(Some("("), None, None),
+    );
+
+    let issues = get_issues(&env_required);
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(
+        issues[0],
+        r"TEST_ENV_REQUIRED_PATTERN_INVALID value: 1.x does not match pattern: ^\d+\.\d+\.\d+$"
+    );
+    assert!(issues[1].starts_with("TEST_ENV_REQUIRED_PATTERN_BAD_REGEX has an invalid pattern: ("));
+}
+
+#[test]
+fn validate_none() {
+    let output = validate(&None, "flow");
+
+    assert!(output.is_ok());
+}
+
+#[test]
+#[ignore]
+fn validate_all_issues_reported() {
+    envmnt::remove("TEST_ENV_REQUIRED_VALIDATE1");
+    envmnt::set("TEST_ENV_REQUIRED_VALIDATE2", "bad");
+    envmnt::set("TEST_ENV_REQUIRED_VALIDATE3", "good");
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_ENV_REQUIRED_VALIDATE1".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_VALIDATE2".to_string(),
+        create_info(None, Some(vec!["good"]), None),
+    );
+    env_required.insert(
+        "TEST_ENV_REQUIRED_VALIDATE3".to_string(),
+        create_info(None, Some(vec!["good"]), None),
+    );
+
+    let output = validate(&Some(env_required), "task: test");
+
+    match output {
+        Err(CargoMakeError::EnvRequired(scope, issues)) => {
+            assert_eq!(scope, "task: test");
+            assert_eq!(
+                issues,
+                "  * TEST_ENV_REQUIRED_VALIDATE1 is not defined\n  * TEST_ENV_REQUIRED_VALIDATE2 value: bad is not one of: good"
+            );
+        }
+        _ => panic!("expected env required error"),
+    }
+}
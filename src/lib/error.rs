@@ -1,3 +1,4 @@
+use crate::secrets;
 use std::cmp::PartialEq;
 use std::string::ToString;
 
@@ -46,6 +47,36 @@ pub enum CargoMakeError {
     #[strum(to_string = "Task {0:#?} is {1}")]
     TaskIs(String, &'static str) = 110,
 
+    #[strum(to_string = "Invalid condition_expr for task: {0} in makefile: {1:#?}, {2}")]
+    InvalidConditionExpr(String, String, String) = 111,
+
+    #[strum(to_string = "Missing or invalid required env vars for {0}:\n{1}")]
+    EnvRequired(String, String) = 112,
+
+    #[strum(to_string = "Unable to parse function: {1} in {0}, position: {2}, {3}")]
+    FunctionParse(String, String, usize, String) = 113,
+
+    #[strum(to_string = "Function {0} failed, {1}")]
+    FunctionFailed(String, String) = 114,
+
+    #[strum(to_string = "Unable to extend from git repository: {0}, {1}")]
+    GitExtend(String, String) = 115,
+
+    #[strum(to_string = "Makefile lock file: {0} is not up to date:\n{1}")]
+    MakefileLock(String, String) = 116,
+
+    #[strum(to_string = "Lint found {0} error(s)")]
+    LintFailed(usize) = 117,
+
+    #[strum(to_string = "Makefile: {0} is not formatted, run with --fmt to format it")]
+    MakefileNotFormatted(String) = 118,
+
+    #[strum(to_string = "Unable to instantiate task: {0} from template, {1}")]
+    TemplateInstantiate(String, String) = 119,
+
+    #[strum(to_string = "Invalid user defined function: {0}, {1}")]
+    InvalidUserFunction(String, String) = 120,
+
     #[strum(to_string = "{0}")]
     NotFound(String) = 404,
 
@@ -175,7 +206,7 @@ impl<T: std::any::Any> std::process::Termination for SuccessOrCargoMakeError<T>
                 }
                 CargoMakeError::ExitCode(error) => error,
                 _ => {
-                    eprintln!("{}", err.to_string());
+                    eprintln!("{}", secrets::redact(&err.to_string()));
                     PROCESS_EXIT_CODE(err.discriminant() as i32)
                 }
             },
@@ -7,6 +7,7 @@
 #[path = "execution_plan_test.rs"]
 mod execution_plan_test;
 
+use crate::descriptor;
 use crate::environment;
 use crate::error::CargoMakeError;
 use crate::logger;
@@ -15,6 +16,7 @@ use crate::proxy_task::create_proxy_task;
 use crate::types::{
     Config, CrateInfo, EnvValue, ExecutionPlan, ScriptValue, Step, Task, TaskIdentifier, Workspace,
 };
+use crate::workspace;
 use fsio::path::{get_basename, get_parent_directory};
 use glob::Pattern;
 use indexmap::IndexMap;
@@ -229,6 +231,7 @@ fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
 
     if !filtered_members.is_empty() {
         let cargo_make_command = "cargo make";
+        let in_process = workspace::is_in_process_enabled();
 
         script_lines.push("workspace_directory = pwd".to_string());
         for member in &filtered_members {
@@ -244,17 +247,32 @@ fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
 
             debug!("Adding Member: {} Path: {}", &member_name, &member);
 
-            let mut make_line = "exec --fail-on-error ".to_string();
-            make_line.push_str(&cargo_make_command);
-            make_line
-                .push_str(" --disable-check-for-updates --allow-private --no-on-error --loglevel=");
-            make_line.push_str(&log_level);
-            make_line.push_str(" --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=");
-            make_line.push_str(&member_name);
-            make_line.push_str(" --profile ");
-            make_line.push_str(&profile_name);
-            make_line.push_str(" -- ");
-            make_line.push_str(&task);
+            let mut make_line = if in_process {
+                // run the member flow in the current process
+                let mut line = "cm_run_workspace_member ".to_string();
+                line.push_str(&member_name);
+                line.push(' ');
+                line.push_str(&profile_name);
+                line.push(' ');
+                line.push_str(task);
+
+                line
+            } else {
+                let mut line = "exec --fail-on-error ".to_string();
+                line.push_str(&cargo_make_command);
+                line.push_str(
+                    " --disable-check-for-updates --allow-private --no-on-error --loglevel=",
+                );
+                line.push_str(&log_level);
+                line.push_str(" --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=");
+                line.push_str(&member_name);
+                line.push_str(" --profile ");
+                line.push_str(&profile_name);
+                line.push_str(" -- ");
+                line.push_str(&task);
+
+                line
+            };
 
             if let Some(args) = envmnt::get_list("CARGO_MAKE_TASK_ARGS") {
                 for arg in args {
@@ -362,7 +380,10 @@ fn create_for_step(
             let working_directory = get_parent_directory(&path_obj);
             (working_directory, filename)
         } else {
-            (Some(path.to_string()), Some("Makefile.toml".to_string()))
+            (
+                Some(path.to_string()),
+                Some(descriptor::get_default_makefile(path_obj)),
+            )
         };
 
         let mut proxy_task = create_proxy_task(&task.name, true, false, makefile, None);
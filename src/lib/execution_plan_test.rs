@@ -9,8 +9,10 @@ fn get_actual_task_name_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let name = get_actual_task_name(&config, "test");
@@ -31,8 +33,10 @@ fn get_actual_task_name_no_alias() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("test".to_string(), Task::new());
@@ -49,8 +53,10 @@ fn get_actual_task_name_alias() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -72,8 +78,10 @@ fn get_actual_task_name_alias_self_referential() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -91,8 +99,10 @@ fn get_actual_task_name_alias_circular() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task_a = Task::new();
@@ -114,8 +124,10 @@ fn get_actual_task_name_platform_alias() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -201,8 +213,12 @@ fn create_workspace_task_with_members() {
 
     envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
 
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
     let task = create_workspace_task(&crate_info, "some_task");
 
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
     let mut expected_script = r#"workspace_directory = pwd
 cd ./member1
 exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile PROFILE_NAME -- some_task
@@ -248,8 +264,12 @@ fn create_workspace_task_with_members_no_workspace_profile() {
 
     envmnt::set_bool("CARGO_MAKE_USE_WORKSPACE_PROFILE", false);
 
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
     let task = create_workspace_task(&crate_info, "some_task");
 
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
     let mut expected_script = r#"workspace_directory = pwd
 cd ./member1
 exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile development -- some_task
@@ -297,8 +317,12 @@ fn create_workspace_task_with_members_and_arguments() {
         &vec!["arg1".to_string(), "arg2".to_string()],
     );
 
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
     let task = create_workspace_task(&crate_info, "some_task");
 
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
     envmnt::remove("CARGO_MAKE_TASK_ARGS");
 
     let mut expected_script = r#"workspace_directory = pwd
@@ -356,8 +380,12 @@ fn create_workspace_task_with_included_members() {
 
     profile::set(&profile::default_profile());
 
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
     let task = create_workspace_task(&crate_info, "some_task");
 
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
 
     let mut expected_script = r#"workspace_directory = pwd
@@ -411,8 +439,12 @@ fn create_workspace_task_with_included_and_skipped_members() {
 
     profile::set(&profile::default_profile());
 
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
     let task = create_workspace_task(&crate_info, "some_task");
 
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
     envmnt::remove("CARGO_MAKE_WORKSPACE_SKIP_MEMBERS");
 
@@ -434,6 +466,94 @@ cd ${workspace_directory}"#
     assert!(task.env.is_none());
 }
 
+#[test]
+#[ignore]
+fn create_workspace_task_with_members_in_process() {
+    let mut crate_info = CrateInfo::new();
+    let members = vec![
+        "member1".to_string(),
+        "member2".to_string(),
+        "dir1/member3".to_string(),
+    ];
+    crate_info.workspace = Some(Workspace {
+        members: Some(members),
+        exclude: None,
+        dependencies: None,
+        package: None,
+    });
+
+    envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
+    let task = create_workspace_task(&crate_info, "some_task");
+
+    let mut expected_script = r#"workspace_directory = pwd
+cd ./member1
+cm_run_workspace_member member1 PROFILE_NAME some_task
+cd ${workspace_directory}
+cd ./member2
+cm_run_workspace_member member2 PROFILE_NAME some_task
+cd ${workspace_directory}
+cd ./dir1/member3
+cm_run_workspace_member member3 PROFILE_NAME some_task
+cd ${workspace_directory}"#
+        .to_string();
+
+    let profile_name = profile::get();
+    expected_script = str::replace(&expected_script, "PROFILE_NAME", &profile_name);
+
+    assert!(task.script.is_some());
+    let script = match task.script.unwrap() {
+        ScriptValue::Text(value) => value.join("\n"),
+        _ => panic!("Invalid script value type."),
+    };
+    assert_eq!(script, expected_script);
+    assert!(task.env.is_none());
+}
+
+#[test]
+#[ignore]
+fn create_workspace_task_with_members_and_arguments_in_process() {
+    let mut crate_info = CrateInfo::new();
+    let members = vec!["member1".to_string(), "member2".to_string()];
+    crate_info.workspace = Some(Workspace {
+        members: Some(members),
+        exclude: None,
+        dependencies: None,
+        package: None,
+    });
+
+    envmnt::set_bool("CARGO_MAKE_USE_WORKSPACE_PROFILE", false);
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
+    envmnt::set_list(
+        "CARGO_MAKE_TASK_ARGS",
+        &vec!["arg1".to_string(), "arg2".to_string()],
+    );
+
+    let task = create_workspace_task(&crate_info, "some_task");
+
+    envmnt::remove("CARGO_MAKE_TASK_ARGS");
+    envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
+
+    let expected_script = r#"workspace_directory = pwd
+cd ./member1
+cm_run_workspace_member member1 development some_task arg1 arg2
+cd ${workspace_directory}
+cd ./member2
+cm_run_workspace_member member2 development some_task arg1 arg2
+cd ${workspace_directory}"#
+        .to_string();
+
+    assert!(task.script.is_some());
+    let script = match task.script.unwrap() {
+        ScriptValue::Text(value) => value.join("\n"),
+        _ => panic!("Invalid script value type."),
+    };
+    assert_eq!(script, expected_script);
+    assert!(task.env.is_none());
+}
+
 #[test]
 #[ignore]
 fn create_workspace_task_extend_workspace_makefile() {
@@ -482,8 +602,10 @@ fn is_workspace_flow_true_default() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -513,8 +635,10 @@ fn is_workspace_flow_false_in_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -544,8 +668,10 @@ fn is_workspace_flow_true_in_config() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -573,8 +699,10 @@ fn is_workspace_flow_true_in_task() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -601,8 +729,10 @@ fn is_workspace_flow_default_false_in_task_and_sub_flow() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -630,8 +760,10 @@ fn is_workspace_flow_true_in_task_and_sub_flow() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -659,8 +791,10 @@ fn is_workspace_flow_false_in_task_and_sub_flow() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -685,8 +819,10 @@ fn is_workspace_flow_task_not_defined() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let workspace_flow = is_workspace_flow(&config, "notfound", false, &crate_info, false);
@@ -706,8 +842,10 @@ fn is_workspace_flow_no_workspace() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -735,8 +873,10 @@ fn is_workspace_flow_disabled_via_cli() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -764,8 +904,10 @@ fn is_workspace_flow_disabled_via_task() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -784,8 +926,10 @@ fn create_single() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -817,8 +961,10 @@ fn create_single_disabled() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -851,8 +997,10 @@ fn create_single_private() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -878,8 +1026,10 @@ fn create_single_allow_private() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -912,8 +1062,10 @@ fn create_with_dependencies() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -952,8 +1104,10 @@ fn create_with_foreign_dependencies_directory() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1000,8 +1154,10 @@ fn create_with_foreign_dependencies_filename() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1048,8 +1204,10 @@ fn create_with_foreign_dependencies_file_and_directory() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1096,8 +1254,10 @@ fn create_with_dependencies_sub_flow() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1135,8 +1295,10 @@ fn create_disabled_task_with_dependencies() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1174,8 +1336,10 @@ fn create_with_dependencies_disabled() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1211,8 +1375,10 @@ fn create_platform_disabled() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -1225,6 +1391,7 @@ fn create_platform_disabled() {
         plugin: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         install_crate: None,
@@ -1234,6 +1401,8 @@ fn create_platform_disabled() {
         force: None,
         env_files: None,
         env: None,
+        env_scope: None,
+        env_required: None,
         cwd: None,
         install_script: None,
         args: None,
@@ -1254,6 +1423,7 @@ fn create_platform_disabled() {
         plugin: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         install_crate: None,
@@ -1263,6 +1433,8 @@ fn create_platform_disabled() {
         force: None,
         env_files: None,
         env: None,
+        env_scope: None,
+        env_required: None,
         cwd: None,
         install_script: None,
         args: None,
@@ -1283,6 +1455,7 @@ fn create_platform_disabled() {
         plugin: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         install_crate: None,
@@ -1292,6 +1465,8 @@ fn create_platform_disabled() {
         force: None,
         env_files: None,
         env: None,
+        env_scope: None,
+        env_required: None,
         cwd: None,
         install_script: None,
         args: None,
@@ -1325,8 +1500,10 @@ fn create_with_dependencies_and_skip_filter() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1367,8 +1544,10 @@ fn create_workspace() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let task = Task::new();
@@ -1397,8 +1576,10 @@ fn create_noworkspace() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let task = Task::new();
@@ -1527,6 +1708,7 @@ fn get_normalized_task_multi_extend() {
         plugin: None,
         watch: None,
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         install_crate: None,
@@ -1536,6 +1718,8 @@ fn get_normalized_task_multi_extend() {
         force: Some(true),
         env_files: None,
         env: None,
+        env_scope: None,
+        env_required: None,
         cwd: None,
         install_script: None,
         args: None,
@@ -1565,8 +1749,10 @@ fn get_normalized_task_multi_extend() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("1".to_string(), task1);
     config.tasks.insert("2".to_string(), task2);
@@ -1595,8 +1781,10 @@ fn get_normalized_task_simple() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     config.tasks.insert("1".to_string(), task1);
 
@@ -1618,8 +1806,10 @@ fn respect_skip_init_end_tasks() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
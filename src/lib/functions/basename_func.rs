@@ -0,0 +1,25 @@
+//! # basename_func
+//!
+//! Takes a path and returns its last component (file or directory name).
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "basename_func_test.rs"]
+mod basename_func_test;
+
+use crate::error::CargoMakeError;
+use std::path::Path;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "basename expects only 1 argument (path)",
+        ));
+    }
+
+    match Path::new(&function_args[0]).file_name() {
+        Some(name) => Ok(vec![name.to_string_lossy().to_string()]),
+        None => Ok(vec![]),
+    }
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn basename_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+fn basename_invoke_file() {
+    let output = invoke(&vec!["./src/lib/mod.rs".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["mod.rs"]);
+}
+
+#[test]
+fn basename_invoke_directory() {
+    let output = invoke(&vec!["./src/lib/".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["lib"]);
+}
+
+#[test]
+fn basename_invoke_name_only() {
+    let output = invoke(&vec!["Cargo.toml".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["Cargo.toml"]);
+}
+
+#[test]
+fn basename_invoke_empty_path() {
+    let output = invoke(&vec!["".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
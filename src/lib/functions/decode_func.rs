@@ -10,14 +10,20 @@
 mod decode_func_test;
 
 use crate::environment;
+use crate::error::CargoMakeError;
+use crate::functions::ValueSource;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+pub(crate) fn invoke(
+    function_args: &[String],
+    source: ValueSource,
+) -> Result<Vec<String>, CargoMakeError> {
     if function_args.len() == 0 {
-        error!("decode expects at least one argument.");
+        return Err(CargoMakeError::Arity(
+            "decode expects at least one argument.",
+        ));
     }
 
-    let env_key = function_args[0].clone();
-    let env_value = envmnt::get_or(&env_key, "");
+    let env_value = source.resolve(&function_args[0]);
 
     let mut mapped_value = None;
     let mut found = false;
@@ -50,8 +56,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
     output_value = environment::expand_value(&output_value);
 
     if output_value.len() > 0 {
-        vec![output_value]
+        Ok(vec![output_value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -3,14 +3,18 @@ use super::*;
 #[test]
 #[should_panic]
 fn decode_invoke_empty() {
-    invoke(&vec![]);
+    invoke(&vec![], ValueSource::EnvName).unwrap();
 }
 
 #[test]
 fn decode_invoke_only_source_not_found() {
     envmnt::remove("TEST_DECODE_ONLY_SOURCE_NOT_DEFINED");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_NOT_DEFINED".to_string()]);
+    let output = invoke(
+        &vec!["TEST_DECODE_ONLY_SOURCE_NOT_DEFINED".to_string()],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -19,7 +23,11 @@ fn decode_invoke_only_source_not_found() {
 fn decode_invoke_only_source_found_empty() {
     envmnt::set("TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY", "");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY".to_string()]);
+    let output = invoke(
+        &vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY".to_string()],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -28,7 +36,11 @@ fn decode_invoke_only_source_found_empty() {
 fn decode_invoke_only_source_found_value() {
     envmnt::set("TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE", "test");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE".to_string()]);
+    let output = invoke(
+        &vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE".to_string()],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["test"]);
 }
@@ -37,10 +49,11 @@ fn decode_invoke_only_source_found_value() {
 fn decode_invoke_only_default_empty() {
     envmnt::set("TEST_DECODE_ONLY_DEFAULT_EMPTY", "test");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_ONLY_DEFAULT_EMPTY".to_string(),
-        "".to_string(),
-    ]);
+    let output = invoke(
+        &vec!["TEST_DECODE_ONLY_DEFAULT_EMPTY".to_string(), "".to_string()],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -49,10 +62,14 @@ fn decode_invoke_only_default_empty() {
 fn decode_invoke_only_default_value() {
     envmnt::set("TEST_DECODE_ONLY_DEFAULT_VALUE", "test");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_ONLY_DEFAULT_VALUE".to_string(),
-        "default".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_ONLY_DEFAULT_VALUE".to_string(),
+            "default".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["default"]);
 }
@@ -62,10 +79,14 @@ fn decode_invoke_only_default_eval_value() {
     envmnt::set("TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE", "test");
     envmnt::set("TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE_RESULT", "result");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE".to_string(),
-        "${TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE_RESULT}-test".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE".to_string(),
+            "${TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE_RESULT}-test".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["result-test"]);
 }
@@ -74,13 +95,17 @@ fn decode_invoke_only_default_eval_value() {
 fn decode_invoke_mappings_not_found_use_source() {
     envmnt::set("TEST_DECODE_MAPPINGS_NOT_FOUND_USE_SOURCE", "source");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_MAPPINGS_NOT_FOUND_USE_SOURCE".to_string(),
-        "key1".to_string(),
-        "value1".to_string(),
-        "key2".to_string(),
-        "value2".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_MAPPINGS_NOT_FOUND_USE_SOURCE".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "value2".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["source"]);
 }
@@ -89,14 +114,18 @@ fn decode_invoke_mappings_not_found_use_source() {
 fn decode_invoke_mappings_not_found_use_default() {
     envmnt::set("TEST_DECODE_MAPPINGS_NOT_FOUND_USE_DEFAULT", "source");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_MAPPINGS_NOT_FOUND_USE_DEFAULT".to_string(),
-        "key1".to_string(),
-        "value1".to_string(),
-        "key2".to_string(),
-        "value2".to_string(),
-        "default".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_MAPPINGS_NOT_FOUND_USE_DEFAULT".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "value2".to_string(),
+            "default".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["default"]);
 }
@@ -105,13 +134,17 @@ fn decode_invoke_mappings_not_found_use_default() {
 fn decode_invoke_mappings_found_no_default() {
     envmnt::set("TEST_DECODE_MAPPINGS_FOUND_NO_DEFAULT", "key2");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_MAPPINGS_FOUND_NO_DEFAULT".to_string(),
-        "key1".to_string(),
-        "value1".to_string(),
-        "key2".to_string(),
-        "value2".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_MAPPINGS_FOUND_NO_DEFAULT".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "value2".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["value2"]);
 }
@@ -120,14 +153,18 @@ fn decode_invoke_mappings_found_no_default() {
 fn decode_invoke_mappings_found_with_default() {
     envmnt::set("TEST_DECODE_MAPPINGS_FOUND_WITH_DEFAULT", "key2");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_MAPPINGS_FOUND_WITH_DEFAULT".to_string(),
-        "key1".to_string(),
-        "value1".to_string(),
-        "key2".to_string(),
-        "value2".to_string(),
-        "default".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_MAPPINGS_FOUND_WITH_DEFAULT".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "value2".to_string(),
+            "default".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["value2"]);
 }
@@ -137,14 +174,35 @@ fn decode_invoke_mappings_found_eval_output() {
     envmnt::set("TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT", "key2");
     envmnt::set("TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT_VALUE", "value2");
 
-    let output = invoke(&vec![
-        "TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT".to_string(),
-        "key1".to_string(),
-        "value1".to_string(),
-        "key2".to_string(),
-        "${TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT_VALUE}-output".to_string(),
-        "default".to_string(),
-    ]);
+    let output = invoke(
+        &vec![
+            "TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "${TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT_VALUE}-output".to_string(),
+            "default".to_string(),
+        ],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["value2-output"]);
 }
+
+#[test]
+fn decode_invoke_value_source() {
+    let output = invoke(
+        &vec![
+            "key2".to_string(),
+            "key1".to_string(),
+            "value1".to_string(),
+            "key2".to_string(),
+            "value2".to_string(),
+        ],
+        ValueSource::Value,
+    )
+    .unwrap();
+
+    assert_eq!(output, vec!["value2"]);
+}
@@ -0,0 +1,34 @@
+//! # default_func
+//!
+//! Takes an environment variable name and a default value and returns the environment variable
+//! value if defined and not empty, otherwise the default value.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "default_func_test.rs"]
+mod default_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 2 {
+        return Err(CargoMakeError::Arity(
+            "default expects 2 arguments (environment variable name and default value)",
+        ));
+    }
+
+    let env_value = envmnt::get_or(&function_args[0], "");
+
+    let value = if env_value.is_empty() {
+        function_args[1].clone()
+    } else {
+        env_value
+    };
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
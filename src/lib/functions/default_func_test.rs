@@ -0,0 +1,65 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn default_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn default_invoke_missing_default() {
+    invoke(&vec!["TEST_DEFAULT_MISSING_DEFAULT".to_string()]).unwrap();
+}
+
+#[test]
+fn default_invoke_exists() {
+    envmnt::set("TEST_DEFAULT_EXISTS", "value");
+
+    let output = invoke(&vec![
+        "TEST_DEFAULT_EXISTS".to_string(),
+        "default".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["value"]);
+}
+
+#[test]
+fn default_invoke_empty_value() {
+    envmnt::set("TEST_DEFAULT_EMPTY_VALUE", "");
+
+    let output = invoke(&vec![
+        "TEST_DEFAULT_EMPTY_VALUE".to_string(),
+        "default".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["default"]);
+}
+
+#[test]
+fn default_invoke_not_exists() {
+    envmnt::remove("TEST_DEFAULT_NOT_EXISTS");
+
+    let output = invoke(&vec![
+        "TEST_DEFAULT_NOT_EXISTS".to_string(),
+        "default".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["default"]);
+}
+
+#[test]
+fn default_invoke_not_exists_empty_default() {
+    envmnt::remove("TEST_DEFAULT_NOT_EXISTS_EMPTY");
+
+    let output = invoke(&vec![
+        "TEST_DEFAULT_NOT_EXISTS_EMPTY".to_string(),
+        "".to_string(),
+    ])
+    .unwrap();
+
+    assert!(output.is_empty());
+}
@@ -0,0 +1,31 @@
+//! # dirname_func
+//!
+//! Takes a path and returns its parent directory.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "dirname_func_test.rs"]
+mod dirname_func_test;
+
+use crate::error::CargoMakeError;
+use std::path::Path;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "dirname expects only 1 argument (path)",
+        ));
+    }
+
+    let value = match Path::new(&function_args[0]).parent() {
+        Some(parent) => parent.to_string_lossy().to_string(),
+        None => "".to_string(),
+    };
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
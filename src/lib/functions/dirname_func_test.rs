@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn dirname_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+fn dirname_invoke_file() {
+    let output = invoke(&vec!["./src/lib/mod.rs".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["./src/lib"]);
+}
+
+#[test]
+fn dirname_invoke_directory() {
+    let output = invoke(&vec!["./src/lib/".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["./src"]);
+}
+
+#[test]
+fn dirname_invoke_name_only() {
+    let output = invoke(&vec!["Cargo.toml".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
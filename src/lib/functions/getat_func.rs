@@ -7,30 +7,40 @@
 #[path = "getat_func_test.rs"]
 mod getat_func_test;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+use crate::error::CargoMakeError;
+use crate::functions::ValueSource;
+
+pub(crate) fn invoke(
+    function_args: &[String],
+    source: ValueSource,
+) -> Result<Vec<String>, CargoMakeError> {
     if function_args.len() != 3 {
-        error!(
-            "split expects only 3 arguments (environment variable name, split by character, index)"
-        );
+        return Err(CargoMakeError::Arity(
+            "getat expects only 3 arguments (environment variable name, split by character, index)",
+        ));
     }
 
-    let env_key = function_args[0].clone();
     let split_by = function_args[1].clone();
     let index: usize = match function_args[2].parse() {
         Ok(value) => value,
         Err(error) => {
-            error!("Invalid index value: {}", &error);
-            return vec![]; // should not get here
+            return Err(CargoMakeError::FunctionFailed(
+                "getat".to_string(),
+                format!("invalid index value: {}", &error),
+            ))
         }
     };
 
     if split_by.len() != 1 {
-        error!("split expects a single character separator");
+        return Err(CargoMakeError::FunctionFailed(
+            "getat".to_string(),
+            "getat expects a single character separator".to_string(),
+        ));
     }
 
     let split_by_char = split_by.chars().next().unwrap();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = source.resolve(&function_args[0]);
 
     if value.len() > index {
         let splitted = value.split(split_by_char);
@@ -38,8 +48,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
         let splitted_vec: Vec<String> = splitted.map(|str_value| str_value.to_string()).collect();
         let value = splitted_vec[index].clone();
 
-        vec![value]
+        Ok(vec![value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
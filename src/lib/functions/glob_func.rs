@@ -0,0 +1,37 @@
+//! # glob_func
+//!
+//! Takes a glob pattern and returns all the matching paths (sorted).
+//! The value will be removed if no path matched.
+//!
+
+#[cfg(test)]
+#[path = "glob_func_test.rs"]
+mod glob_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "glob expects only 1 argument (glob pattern)",
+        ));
+    }
+
+    let entries = match glob::glob(&function_args[0]) {
+        Ok(entries) => entries,
+        Err(error) => {
+            return Err(CargoMakeError::FunctionFailed(
+                "glob".to_string(),
+                format!("invalid glob pattern: {}", error),
+            ))
+        }
+    };
+
+    let mut paths: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    paths.sort();
+
+    Ok(paths)
+}
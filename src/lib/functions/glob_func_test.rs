@@ -0,0 +1,44 @@
+use super::*;
+use crate::test;
+use std::fs;
+
+#[test]
+#[should_panic]
+fn glob_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn glob_invoke_invalid_pattern() {
+    invoke(&vec!["a[".to_string()]).unwrap();
+}
+
+#[test]
+fn glob_invoke_matches() {
+    let directory = test::get_temp_test_directory("glob_invoke_matches");
+    fs::write(directory.join("b.txt"), "").unwrap();
+    fs::write(directory.join("a.txt"), "").unwrap();
+    fs::write(directory.join("c.md"), "").unwrap();
+
+    let pattern = directory.join("*.txt").to_string_lossy().to_string();
+    let output = invoke(&vec![pattern]).unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            directory.join("a.txt").to_string_lossy().to_string(),
+            directory.join("b.txt").to_string_lossy().to_string()
+        ]
+    );
+}
+
+#[test]
+fn glob_invoke_no_matches() {
+    let directory = test::get_temp_test_directory("glob_invoke_no_matches");
+
+    let pattern = directory.join("*.txt").to_string_lossy().to_string();
+    let output = invoke(&vec![pattern]).unwrap();
+
+    assert!(output.is_empty());
+}
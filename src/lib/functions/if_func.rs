@@ -0,0 +1,43 @@
+//! # if_func
+//!
+//! Takes a condition value, a value to return if the condition is true and an optional value to
+//! return if the condition is false.<br>
+//! The condition is false if empty or one of: false, no, 0 (case insensitive).
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "if_func_test.rs"]
+mod if_func_test;
+
+use crate::error::CargoMakeError;
+
+fn is_true(value: &str) -> bool {
+    let lowercase_value = value.trim().to_lowercase();
+
+    !(lowercase_value.is_empty()
+        || lowercase_value == "false"
+        || lowercase_value == "no"
+        || lowercase_value == "0")
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    let args_count = function_args.len();
+    if !(2..=3).contains(&args_count) {
+        return Err(CargoMakeError::Arity(
+            "if expects 2 or 3 arguments (condition, value if true and optional value if false)",
+        ));
+    }
+
+    let value = if is_true(&function_args[0]) {
+        function_args[1].clone()
+    } else {
+        function_args.get(2).cloned().unwrap_or_default()
+    };
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
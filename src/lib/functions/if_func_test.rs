@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn if_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn if_invoke_too_many_args() {
+    invoke(&vec![
+        "true".to_string(),
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn if_invoke_true() {
+    let output = invoke(&vec![
+        "true".to_string(),
+        "yes".to_string(),
+        "no".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["yes"]);
+}
+
+#[test]
+fn if_invoke_any_value_is_true() {
+    let output = invoke(&vec!["value".to_string(), "yes".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["yes"]);
+}
+
+#[test]
+fn if_invoke_false() {
+    for condition in ["", "false", "FALSE", "no", "0"] {
+        let output = invoke(&vec![
+            condition.to_string(),
+            "yes".to_string(),
+            "no".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(output, vec!["no"]);
+    }
+}
+
+#[test]
+fn if_invoke_false_no_else() {
+    let output = invoke(&vec!["false".to_string(), "yes".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
@@ -0,0 +1,28 @@
+//! # join_func
+//!
+//! Takes a separator and a list of values and returns a single value of all values joined by
+//! the separator.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "join_func_test.rs"]
+mod join_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.is_empty() {
+        return Err(CargoMakeError::Arity(
+            "join expects at least 1 argument (separator and optionally the values to join)",
+        ));
+    }
+
+    let value = function_args[1..].join(&function_args[0]);
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn join_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+fn join_invoke_no_values() {
+    let output = invoke(&vec![",".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn join_invoke_single_value() {
+    let output = invoke(&vec![",".to_string(), "a".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["a"]);
+}
+
+#[test]
+fn join_invoke_multiple_values() {
+    let output = invoke(&vec![
+        ", ".to_string(),
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["a, b, c"]);
+}
@@ -0,0 +1,55 @@
+//! # json_get_func
+//!
+//! Takes a JSON file path, a dot separated key path and an optional default value and returns
+//! the value found under the key path.<br>
+//! Lists of simple values are joined using `;` while objects are returned as JSON.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "json_get_func_test.rs"]
+mod json_get_func_test;
+
+use crate::environment::data_path;
+use crate::environment::data_path::DataFormat;
+use crate::error::CargoMakeError;
+use std::fs;
+
+fn fail(message: String) -> Result<Vec<String>, CargoMakeError> {
+    Err(CargoMakeError::FunctionFailed(
+        "json_get".to_string(),
+        message,
+    ))
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    let args_count = function_args.len();
+    if !(2..=3).contains(&args_count) {
+        return Err(CargoMakeError::Arity(
+            "json_get expects 2 or 3 arguments (file path, key path and optional default value)",
+        ));
+    }
+
+    let file = &function_args[0];
+    let path = &function_args[1];
+
+    let content = match fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(error) => return fail(format!("unable to read file: {}, {}", file, error)),
+    };
+
+    let value = match data_path::get_value_from_content(&content, DataFormat::Json, path) {
+        Ok(Some(value)) => value,
+        Ok(None) => match function_args.get(2) {
+            Some(default_value) => default_value.to_string(),
+            None => return fail(format!("key path: {} not found in file: {}", path, file)),
+        },
+        Err(error) => return fail(format!("unable to parse file: {}, {}", file, error)),
+    };
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::test;
+use std::path::PathBuf;
+
+fn create_json_file(name: &str) -> String {
+    let file: PathBuf = test::get_temp_test_directory(name).join("data.json");
+    fs::write(
+        &file,
+        r#"{"package": {"name": "test", "version": "1.2.3", "keywords": ["a", "b"]}}"#,
+    )
+    .unwrap();
+
+    file.to_string_lossy().to_string()
+}
+
+#[test]
+#[should_panic]
+fn json_get_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn json_get_invoke_file_not_exists() {
+    invoke(&vec![
+        "./json_get_invoke_file_not_exists.json".to_string(),
+        "package".to_string(),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn json_get_invoke_value() {
+    let file = create_json_file("json_get_invoke_value");
+
+    let output = invoke(&vec![file, "package.version".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1.2.3"]);
+}
+
+#[test]
+fn json_get_invoke_list() {
+    let file = create_json_file("json_get_invoke_list");
+
+    let output = invoke(&vec![file, "package.keywords".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["a;b"]);
+}
+
+#[test]
+#[should_panic]
+fn json_get_invoke_not_found() {
+    let file = create_json_file("json_get_invoke_not_found");
+
+    invoke(&vec![file, "package.bad".to_string()]).unwrap();
+}
+
+#[test]
+fn json_get_invoke_not_found_with_default() {
+    let file = create_json_file("json_get_invoke_not_found_with_default");
+
+    let output = invoke(&vec![
+        file,
+        "package.bad".to_string(),
+        "default".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["default"]);
+}
@@ -0,0 +1,27 @@
+//! # lower_func
+//!
+//! Takes a value and returns it in lower case.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "lower_func_test.rs"]
+mod lower_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "lower expects only 1 argument (value)",
+        ));
+    }
+
+    let value = function_args[0].to_lowercase();
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -7,18 +7,83 @@
 #[path = "mod_test.rs"]
 mod mod_test;
 
+mod basename_func;
 mod decode_func;
+mod default_func;
+mod dirname_func;
 mod getat_func;
+mod glob_func;
+mod if_func;
+mod join_func;
+mod json_get_func;
+mod lower_func;
+mod parser;
+mod path_join_func;
+mod regex_replace_func;
 mod remove_empty_func;
+mod replace_func;
+mod semver_bump_func;
+mod sha256_func;
 mod split_func;
 mod trim_func;
+mod upper_func;
+pub(crate) mod user_functions;
 
+use crate::environment;
 use crate::error::CargoMakeError;
-use crate::types::{Step, Task};
+use crate::functions::parser::{Argument, FunctionCall, ParseError, Segment};
+use crate::types::{ScriptSections, ScriptValue, Step, Task};
+use indexmap::IndexMap;
+
+/// The built-in functions which take an environment variable name as their first argument
+static ENV_NAME_FUNCTIONS: &[&str] = &["split", "trim", "getat", "decode"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Defines how the first argument of the env var name based functions is resolved
+pub(crate) enum ValueSource {
+    /// The first argument is the environment variable name holding the value
+    EnvName,
+    /// The first argument is the value itself (output of a nested function call)
+    Value,
+}
+
+impl ValueSource {
+    /// Returns the value the first argument refers to
+    pub(crate) fn resolve(&self, argument: &str) -> String {
+        match self {
+            ValueSource::EnvName => envmnt::get_or(argument, ""),
+            ValueSource::Value => argument.to_string(),
+        }
+    }
+}
+
+/// The names of all built-in functions, user defined functions can not use these names
+pub(crate) static BUILTIN_FUNCTIONS: &[&str] = &[
+    "split",
+    "remove-empty",
+    "trim",
+    "getat",
+    "decode",
+    "replace",
+    "regex_replace",
+    "join",
+    "upper",
+    "lower",
+    "default",
+    "basename",
+    "dirname",
+    "path_join",
+    "glob",
+    "sha256",
+    "semver_bump",
+    "json_get",
+    "if",
+];
 
 fn run_function(
     function_name: &str,
-    function_args: &Vec<String>,
+    function_args: &[String],
+    source: ValueSource,
 ) -> Result<Vec<String>, CargoMakeError> {
     debug!(
         "Running function: {} arguments: {:#?}",
@@ -26,107 +91,215 @@ fn run_function(
     );
 
     match function_name {
-        "split" => Ok(split_func::invoke(function_args)),
-        "remove-empty" => Ok(remove_empty_func::invoke(function_args)),
-        "trim" => trim_func::invoke(function_args),
-        "getat" => Ok(getat_func::invoke(function_args)),
-        "decode" => Ok(decode_func::invoke(function_args)),
-        _ => {
-            error!("Unknown function: {}", &function_name);
-            Err(CargoMakeError::NotFound(format!(
-                "Unknown function: {}",
-                &function_name
-            )))
-        }
+        "split" => split_func::invoke(function_args, source),
+        "remove-empty" => remove_empty_func::invoke(function_args),
+        "trim" => trim_func::invoke(function_args, source),
+        "getat" => getat_func::invoke(function_args, source),
+        "decode" => decode_func::invoke(function_args, source),
+        "replace" => replace_func::invoke(function_args),
+        "regex_replace" => regex_replace_func::invoke(function_args),
+        "join" => join_func::invoke(function_args),
+        "upper" => upper_func::invoke(function_args),
+        "lower" => lower_func::invoke(function_args),
+        "default" => default_func::invoke(function_args),
+        "basename" => basename_func::invoke(function_args),
+        "dirname" => dirname_func::invoke(function_args),
+        "path_join" => path_join_func::invoke(function_args),
+        "glob" => glob_func::invoke(function_args),
+        "sha256" => sha256_func::invoke(function_args),
+        "semver_bump" => semver_bump_func::invoke(function_args),
+        "json_get" => json_get_func::invoke(function_args),
+        "if" => if_func::invoke(function_args),
+        _ => match user_functions::invoke(function_name, function_args) {
+            Some(output) => output,
+            None => {
+                error!("Unknown function: {}", &function_name);
+                Err(CargoMakeError::NotFound(format!(
+                    "Unknown function: {}",
+                    &function_name
+                )))
+            }
+        },
     }
 }
 
-fn get_function_name(function_string: &str) -> Option<String> {
-    match function_string.find('(') {
-        Some(index) => Some(function_string[0..index].to_string()),
-        None => None,
+fn evaluate(call: &FunctionCall) -> Result<Vec<String>, CargoMakeError> {
+    let mut function_args = vec![];
+    let mut source = ValueSource::EnvName;
+
+    for (index, argument) in call.args.iter().enumerate() {
+        match argument {
+            Argument::Value(value) => function_args.push(environment::expand_value(value)),
+            Argument::Function(nested_call) => {
+                let output = evaluate(nested_call)?;
+
+                if index == 0 && ENV_NAME_FUNCTIONS.contains(&call.name.as_str()) {
+                    // the nested output is the value itself and not an env var name
+                    source = ValueSource::Value;
+                    function_args.push(output.join(";"));
+                } else {
+                    function_args.extend(output);
+                }
+            }
+        }
     }
+
+    run_function(&call.name, &function_args, source)
 }
 
-fn get_function_argument(value: &str) -> String {
-    let str_value = if value.len() == 1 {
-        value
-    } else {
-        value.trim()
-    };
+fn to_parse_error(scope: &str, value: &str, error: ParseError) -> CargoMakeError {
+    CargoMakeError::FunctionParse(
+        scope.to_string(),
+        value.to_string(),
+        error.position,
+        error.message,
+    )
+}
 
-    str_value.to_string()
+fn evaluate_and_run(scope: &str, value: &str) -> Result<Vec<String>, CargoMakeError> {
+    match parser::parse(value) {
+        Ok(Some(call)) => evaluate(&call),
+        Ok(None) => Ok(vec![value.to_string()]),
+        Err(error) => Err(to_parse_error(scope, value, error)),
+    }
 }
 
-fn get_function_arguments(function_string: &str) -> Option<Vec<String>> {
-    if function_string.starts_with("(") && function_string.ends_with(")") {
-        let args_string = function_string[1..(function_string.len() - 1)].to_string();
+fn expand_functions(scope: &str, value: &str, separator: &str) -> Result<String, CargoMakeError> {
+    if !value.contains(parser::FUNCTION_PREFIX) {
+        return Ok(value.to_string());
+    }
 
-        let arguments = if args_string.len() > 0 {
-            args_string
-                .split(",")
-                .map(|str_value| get_function_argument(&str_value))
-                .collect()
-        } else {
-            vec![]
-        };
+    let segments =
+        parser::parse_embedded(value).map_err(|error| to_parse_error(scope, value, error))?;
 
-        Some(arguments)
-    } else {
-        None
+    let mut output = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => output.push_str(&text),
+            Segment::Function(call) => output.push_str(&evaluate(&call)?.join(separator)),
+        }
     }
+
+    Ok(output)
 }
 
-fn evaluate_and_run(value: &str) -> Result<Vec<String>, CargoMakeError> {
-    let value_string = value.to_string();
+/// Evaluates all function calls embedded in the provided text and replaces them with their
+/// output values (joined by a space).
+pub(crate) fn expand_text(scope: &str, value: &str) -> Result<String, CargoMakeError> {
+    expand_functions(scope, value, " ")
+}
 
-    if value_string.starts_with("@@") {
-        let mut function_string = value[2..].to_string();
+/// Evaluates all function calls embedded in the provided env value and replaces them with their
+/// output values (joined by `;` same as list env values).
+pub(crate) fn expand_env_value(key: &str, value: &str) -> Result<String, CargoMakeError> {
+    expand_functions(&format!("env: {}", key), value, ";")
+}
 
-        let func_name_option = get_function_name(&function_string);
-        match func_name_option {
-            Some(function_name) => {
-                function_string = function_string[function_name.len()..].to_string();
-                let func_args_option = get_function_arguments(&function_string);
+fn evaluate_list(
+    scope: &str,
+    values: &Option<Vec<String>>,
+) -> Result<Option<Vec<String>>, CargoMakeError> {
+    match values {
+        Some(ref values) => {
+            let mut new_values = vec![];
 
-                match func_args_option {
-                    Some(function_args) => run_function(&function_name, &function_args),
-                    None => Ok(vec![value_string]),
-                }
+            for value in values {
+                new_values.extend(evaluate_and_run(scope, value)?);
             }
-            None => Ok(vec![value_string]),
+
+            Ok(Some(new_values))
         }
-    } else {
-        Ok(vec![value_string])
+        None => Ok(None),
     }
 }
 
-fn modify_arguments(task: &mut Task) -> Result<(), CargoMakeError> {
-    task.args = match task.args {
-        Some(ref args) => {
-            let mut new_args = vec![];
+fn modify_arguments(scope: &str, task: &mut Task) -> Result<(), CargoMakeError> {
+    task.args = evaluate_list(scope, &task.args)?;
+    task.install_crate_args = evaluate_list(scope, &task.install_crate_args)?;
+    Ok(())
+}
 
-            for index in 0..args.len() {
-                let result_args = evaluate_and_run(&args[index])?;
+fn modify_cwd(scope: &str, task: &mut Task) -> Result<(), CargoMakeError> {
+    task.cwd = match task.cwd {
+        Some(ref cwd) => Some(expand_text(scope, cwd)?),
+        None => None,
+    };
+    Ok(())
+}
 
-                for result_index in 0..result_args.len() {
-                    new_args.push(result_args[result_index].clone());
-                }
+fn expand_optional_text(
+    scope: &str,
+    value: &Option<String>,
+) -> Result<Option<String>, CargoMakeError> {
+    match value {
+        Some(ref text) => Ok(Some(expand_text(scope, text)?)),
+        None => Ok(None),
+    }
+}
+
+fn modify_script(scope: &str, task: &mut Task) -> Result<(), CargoMakeError> {
+    task.script = match task.script {
+        Some(ScriptValue::SingleLine(ref text)) => {
+            Some(ScriptValue::SingleLine(expand_text(scope, text)?))
+        }
+        Some(ScriptValue::Text(ref lines)) => {
+            let mut new_lines = vec![];
+            for line in lines {
+                new_lines.push(expand_text(scope, line)?);
             }
 
-            Some(new_args)
+            Some(ScriptValue::Text(new_lines))
         }
-        None => None,
+        Some(ScriptValue::Sections(ref sections)) => Some(ScriptValue::Sections(ScriptSections {
+            pre: expand_optional_text(scope, &sections.pre)?,
+            main: expand_optional_text(scope, &sections.main)?,
+            post: expand_optional_text(scope, &sections.post)?,
+        })),
+        ref other => other.clone(),
     };
     Ok(())
 }
 
+fn modify_condition_env(scope: &str, task: &mut Task) -> Result<(), CargoMakeError> {
+    if let Some(ref mut condition) = task.condition {
+        if let Some(ref env) = condition.env {
+            let mut new_env = IndexMap::new();
+            for (key, value) in env {
+                new_env.insert(key.to_string(), expand_text(scope, value)?);
+            }
+
+            condition.env = Some(new_env);
+        }
+    }
+    Ok(())
+}
+
+/// Returns a copy of the provided step with all functions in the args, install_crate_args,
+/// cwd and script evaluated.
 pub(crate) fn run(step: &Step) -> Result<Step, CargoMakeError> {
     //clone data before modify
     let mut config = step.config.clone();
+    let scope = format!("task: {}", &step.name);
 
     //update args by running any needed function
-    modify_arguments(&mut config)?;
+    modify_arguments(&scope, &mut config)?;
+    modify_cwd(&scope, &mut config)?;
+    modify_script(&scope, &mut config)?;
+
+    Ok(Step {
+        name: step.name.clone(),
+        config,
+    })
+}
+
+/// Returns a copy of the provided step with all functions in the condition env values and
+/// cwd (in which the condition is validated) evaluated.
+pub(crate) fn run_condition(step: &Step) -> Result<Step, CargoMakeError> {
+    let mut config = step.config.clone();
+    let scope = format!("task: {}", &step.name);
+
+    modify_condition_env(&scope, &mut config)?;
+    modify_cwd(&scope, &mut config)?;
 
     Ok(Step {
         name: step.name.clone(),
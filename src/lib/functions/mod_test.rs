@@ -1,15 +1,36 @@
 use super::*;
+use crate::types::{TaskCondition, UserFunction};
 
 #[test]
 #[should_panic]
 fn run_function_empty() {
-    run_function("", &vec![]).unwrap();
+    run_function("", &vec![], ValueSource::EnvName).unwrap();
 }
 
 #[test]
 #[should_panic]
 fn run_function_not_exists() {
-    run_function("bad", &vec![]).unwrap();
+    run_function("bad", &vec![], ValueSource::EnvName).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_function_user_function() {
+    let mut functions = IndexMap::new();
+    functions.insert(
+        "artifact_name".to_string(),
+        UserFunction {
+            description: None,
+            script: ScriptValue::Text(vec!["output = set \"${1}.tar.gz\"".to_string()]),
+        },
+    );
+    user_functions::register(&Some(functions)).unwrap();
+
+    let output = evaluate_and_run("test", "@@artifact_name(@@lower(APP))").unwrap();
+
+    assert_eq!(output, vec!["app.tar.gz"]);
+
+    user_functions::register(&None).unwrap();
 }
 
 #[test]
@@ -19,6 +40,7 @@ fn run_function_split() {
     let output = run_function(
         "split",
         &vec!["TEST_MOD_SPLIT_FUNC_MOD".to_string(), ",".to_string()],
+        ValueSource::EnvName,
     )
     .unwrap();
 
@@ -36,6 +58,7 @@ fn run_function_getat() {
             ",".to_string(),
             "2".to_string(),
         ],
+        ValueSource::EnvName,
     )
     .unwrap();
 
@@ -49,6 +72,7 @@ fn run_function_remove_empty() {
     let output = run_function(
         "remove-empty",
         &vec!["TEST_MOD_REMOVE_EMPTY_FUNC_MOD".to_string()],
+        ValueSource::EnvName,
     )
     .unwrap();
 
@@ -59,7 +83,12 @@ fn run_function_remove_empty() {
 fn run_function_trim() {
     envmnt::set("TEST_MOD_TRIM_FUNC_MOD", "    ");
 
-    let output = run_function("trim", &vec!["TEST_MOD_TRIM_FUNC_MOD".to_string()]).unwrap();
+    let output = run_function(
+        "trim",
+        &vec!["TEST_MOD_TRIM_FUNC_MOD".to_string()],
+        ValueSource::EnvName,
+    )
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -77,6 +106,7 @@ fn run_function_decode() {
             "ci".to_string(),
             "test".to_string(),
         ],
+        ValueSource::EnvName,
     )
     .unwrap();
 
@@ -84,110 +114,179 @@ fn run_function_decode() {
 }
 
 #[test]
-fn get_function_name_valid() {
-    let output = get_function_name("test(123)");
-
-    assert_eq!(output.unwrap(), "test");
+fn run_function_new_functions() {
+    let args = vec!["a".to_string(), "b".to_string()];
+    let cases = vec![
+        ("replace", vec!["abc", "b", "x"], vec!["axc"]),
+        ("regex_replace", vec!["a1b2", "[0-9]", ""], vec!["ab"]),
+        ("join", vec!["-", "a", "b"], vec!["a-b"]),
+        ("upper", vec!["abc"], vec!["ABC"]),
+        ("lower", vec!["ABC"], vec!["abc"]),
+        (
+            "default",
+            vec!["TEST_MOD_DEFAULT_FUNC_MISSING", "x"],
+            vec!["x"],
+        ),
+        ("basename", vec!["a/b.txt"], vec!["b.txt"]),
+        ("dirname", vec!["a/b.txt"], vec!["a"]),
+        ("semver_bump", vec!["1.0.0", "minor"], vec!["1.1.0"]),
+        ("if", vec!["true", "a", "b"], vec!["a"]),
+    ];
+    envmnt::remove("TEST_MOD_DEFAULT_FUNC_MISSING");
+
+    for (name, function_args, expected) in cases {
+        let function_args: Vec<String> = function_args.iter().map(|arg| arg.to_string()).collect();
+        let output = run_function(name, &function_args, ValueSource::EnvName).unwrap();
+
+        assert_eq!(output, expected);
+    }
+
+    let output = run_function("path_join", &args, ValueSource::EnvName).unwrap();
+    assert_eq!(output.len(), 1);
 }
 
 #[test]
-fn get_function_name_invalid() {
-    let output = get_function_name("test[123]");
+fn evaluate_and_run_expands_arguments() {
+    envmnt::set("TEST_MOD_EXPAND_FUNC_VALUE", "value");
+
+    let output = evaluate_and_run("test", "@@upper(${TEST_MOD_EXPAND_FUNC_VALUE})").unwrap();
 
-    assert!(output.is_none());
+    assert_eq!(output, vec!["VALUE"]);
 }
 
 #[test]
-fn get_function_argument_empty() {
-    let output = get_function_argument("");
+fn evaluate_and_run_expands_env_name_arguments() {
+    envmnt::set("TEST_MOD_EXPAND_FUNC_NAME", "TEST_MOD_EXPAND_FUNC_LIST");
+    envmnt::set("TEST_MOD_EXPAND_FUNC_LIST", "a|b");
 
-    assert_eq!(output, "");
+    // functions which take an env var name receive the expanded argument
+    let output = evaluate_and_run("test", "@@split(${TEST_MOD_EXPAND_FUNC_NAME},|)").unwrap();
+
+    assert_eq!(output, vec!["a", "b"]);
 }
 
 #[test]
-fn get_function_argument_single_char() {
-    let output = get_function_argument(" ");
+fn evaluate_and_run_env_name_arguments_not_expanded() {
+    envmnt::set("TEST_MOD_EXPAND_FUNC_PLAIN", "a|b");
+
+    let output = evaluate_and_run("test", "@@split(TEST_MOD_EXPAND_FUNC_PLAIN,|)").unwrap();
 
-    assert_eq!(output, " ");
+    assert_eq!(output, vec!["a", "b"]);
 }
 
 #[test]
-fn get_function_argument_spaces() {
-    let output = get_function_argument("     ");
+fn evaluate_and_run_valid() {
+    envmnt::set("TEST_MOD_RUN_FUNC_VALUE", "1 2 3 4");
 
-    assert_eq!(output, "");
+    let output = evaluate_and_run("test", "@@split(TEST_MOD_RUN_FUNC_VALUE, )").unwrap();
+
+    assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
 
 #[test]
-fn get_function_argument_mixed() {
-    let output = get_function_argument(" |");
-
-    assert_eq!(output, "|");
+#[should_panic]
+fn evaluate_and_run_unknown_function() {
+    evaluate_and_run("test", "@@bad()").unwrap();
 }
 
 #[test]
-fn get_function_arguments_missing_start() {
-    let output = get_function_arguments("1,2,3,4)");
+fn evaluate_and_run_no_function() {
+    let output = evaluate_and_run("test", "value").unwrap();
 
-    assert!(output.is_none());
+    assert_eq!(output, vec!["value"]);
 }
 
 #[test]
-fn get_function_arguments_missing_end() {
-    let output = get_function_arguments("(1,2,3,4");
+fn evaluate_and_run_nested() {
+    envmnt::set("TEST_MOD_NESTED_FUNC_VALUE", "a,  inner  ,c");
+
+    let output = evaluate_and_run(
+        "test",
+        "@@trim(@@getat(TEST_MOD_NESTED_FUNC_VALUE, \",\", 1))",
+    )
+    .unwrap();
 
-    assert!(output.is_none());
+    assert_eq!(output, vec!["inner"]);
 }
 
 #[test]
-fn get_function_arguments_empty() {
-    let output = get_function_arguments("()");
+fn evaluate_and_run_nested_value_source() {
+    envmnt::set("TEST_MOD_NESTED_SOURCE_FUNC_VALUE", "A|B");
+
+    let output = evaluate_and_run(
+        "test",
+        "@@split(@@lower(${TEST_MOD_NESTED_SOURCE_FUNC_VALUE}), |)",
+    )
+    .unwrap();
 
-    let expected: Vec<String> = vec![];
-    assert_eq!(output.unwrap(), expected);
+    assert_eq!(output, vec!["a", "b"]);
 }
 
 #[test]
-fn get_function_arguments_single() {
-    let output = get_function_arguments("(1)");
+fn evaluate_and_run_nested_not_first_argument() {
+    envmnt::set("TEST_MOD_NESTED_NOT_FIRST_FUNC_VALUE", "a-b");
+
+    let output = evaluate_and_run(
+        "test",
+        "@@split(TEST_MOD_NESTED_NOT_FIRST_FUNC_VALUE, @@lower(-))",
+    )
+    .unwrap();
 
-    assert_eq!(output.unwrap(), vec!["1"]);
+    assert_eq!(output, vec!["a", "b"]);
 }
 
 #[test]
-fn get_function_arguments_multiple() {
-    let output = get_function_arguments("(1,2,3)");
+fn evaluate_and_run_nested_multiple_values() {
+    envmnt::set("TEST_MOD_NESTED_MULTIPLE_FUNC_ENV", "ci");
+    envmnt::set("TEST_MOD_NESTED_MULTIPLE_FUNC_MAPPING", "dev|ci|test");
 
-    assert_eq!(output.unwrap(), vec!["1", "2", "3"]);
+    let output = evaluate_and_run(
+        "test",
+        "@@decode(TEST_MOD_NESTED_MULTIPLE_FUNC_ENV, @@split(TEST_MOD_NESTED_MULTIPLE_FUNC_MAPPING, |))",
+    )
+    .unwrap();
+
+    assert_eq!(output, vec!["test"]);
 }
 
 #[test]
-fn get_function_arguments_multiple_with_spaces() {
-    let output = get_function_arguments("(1  ,  2,   3   )");
+fn evaluate_and_run_quoted_arguments() {
+    envmnt::set("TEST_MOD_QUOTED_FUNC_VALUE", "1,2");
 
-    assert_eq!(output.unwrap(), vec!["1", "2", "3"]);
+    let output = evaluate_and_run("test", "@@split(TEST_MOD_QUOTED_FUNC_VALUE, ',')").unwrap();
+
+    assert_eq!(output, vec!["1", "2"]);
 }
 
 #[test]
-fn evaluate_and_run_valid() {
-    envmnt::set("TEST_MOD_RUN_FUNC_VALUE", "1 2 3 4");
+fn evaluate_and_run_escaped_arguments() {
+    envmnt::set("TEST_MOD_ESCAPED_FUNC_VALUE", "1,2");
 
-    let output = evaluate_and_run("@@split(TEST_MOD_RUN_FUNC_VALUE, )").unwrap();
+    let output = evaluate_and_run("test", "@@split(TEST_MOD_ESCAPED_FUNC_VALUE, \\,)").unwrap();
 
-    assert_eq!(output, vec!["1", "2", "3", "4"]);
+    assert_eq!(output, vec!["1", "2"]);
 }
 
 #[test]
-#[should_panic]
-fn evaluate_and_run_unknown_function() {
-    evaluate_and_run("@@bad()").unwrap();
+fn evaluate_and_run_parse_error() {
+    let output = evaluate_and_run("task: mytask", "@@split(VALUE, \"|)");
+
+    match output {
+        Err(CargoMakeError::FunctionParse(task_name, value, position, message)) => {
+            assert_eq!(task_name, "task: mytask");
+            assert_eq!(value, "@@split(VALUE, \"|)");
+            assert_eq!(position, 16);
+            assert_eq!(message, "unterminated quoted string");
+        }
+        _ => panic!("expected parse error"),
+    }
 }
 
 #[test]
-fn evaluate_and_run_no_function() {
-    let output = evaluate_and_run("value").unwrap();
+fn evaluate_and_run_not_function_call() {
+    let output = evaluate_and_run("test", "@@value").unwrap();
 
-    assert_eq!(output, vec!["value"]);
+    assert_eq!(output, vec!["@@value"]);
 }
 
 #[test]
@@ -201,7 +300,7 @@ fn modify_arguments_with_functions() {
         "end".to_string(),
     ]);
 
-    modify_arguments(&mut task).unwrap();
+    modify_arguments("test", &mut task).unwrap();
 
     assert_eq!(task.args.unwrap(), vec!["start", "1", "2", "3", "4", "end"]);
 }
@@ -228,3 +327,210 @@ fn run_with_functions() {
         vec!["start", "1", "2", "3", "4", "end"]
     );
 }
+
+#[test]
+fn expand_text_no_functions() {
+    let output = expand_text("test", "echo value").unwrap();
+
+    assert_eq!(output, "echo value");
+}
+
+#[test]
+fn expand_text_with_functions() {
+    envmnt::set("TEST_MOD_EXPAND_TEXT_FUNC_VALUE", "1|2");
+
+    let output = expand_text(
+        "test",
+        "echo @@upper(a) @@split(TEST_MOD_EXPAND_TEXT_FUNC_VALUE, |) end",
+    )
+    .unwrap();
+
+    assert_eq!(output, "echo A 1 2 end");
+}
+
+#[test]
+fn expand_text_parse_error() {
+    let output = expand_text("task: test", "echo @@upper(a");
+
+    match output {
+        Err(CargoMakeError::FunctionParse(scope, _, position, _)) => {
+            assert_eq!(scope, "task: test");
+            assert_eq!(position, 13);
+        }
+        _ => panic!("expected parse error"),
+    }
+}
+
+#[test]
+fn expand_env_value_with_functions() {
+    envmnt::set("TEST_MOD_EXPAND_ENV_FUNC_VALUE", "1|2");
+
+    let output = expand_env_value("TEST", "@@split(TEST_MOD_EXPAND_ENV_FUNC_VALUE, |)").unwrap();
+
+    assert_eq!(output, "1;2");
+}
+
+#[test]
+fn expand_env_value_parse_error() {
+    let output = expand_env_value("TEST", "@@upper(a");
+
+    match output {
+        Err(CargoMakeError::FunctionParse(scope, _, _, _)) => assert_eq!(scope, "env: TEST"),
+        _ => panic!("expected parse error"),
+    }
+}
+
+#[test]
+fn modify_arguments_install_crate_args() {
+    let mut task = Task::new();
+    task.install_crate_args = Some(vec![
+        "--version".to_string(),
+        "@@semver_bump(1.0.0, patch)".to_string(),
+    ]);
+
+    modify_arguments("test", &mut task).unwrap();
+
+    assert_eq!(task.install_crate_args.unwrap(), vec!["--version", "1.0.1"]);
+}
+
+#[test]
+fn modify_cwd_with_functions() {
+    let mut task = Task::new();
+    task.cwd = Some("./@@lower(SRC)".to_string());
+
+    modify_cwd("test", &mut task).unwrap();
+
+    assert_eq!(task.cwd.unwrap(), "./src");
+}
+
+#[test]
+fn modify_script_text() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo @@upper(a)".to_string(),
+        "echo b".to_string(),
+    ]));
+
+    modify_script("test", &mut task).unwrap();
+
+    match task.script.unwrap() {
+        ScriptValue::Text(lines) => assert_eq!(lines, vec!["echo A", "echo b"]),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn modify_script_single_line() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::SingleLine("echo @@upper(a)".to_string()));
+
+    modify_script("test", &mut task).unwrap();
+
+    match task.script.unwrap() {
+        ScriptValue::SingleLine(line) => assert_eq!(line, "echo A"),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn modify_script_sections() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Sections(ScriptSections {
+        pre: Some("echo @@upper(pre)".to_string()),
+        main: None,
+        post: Some("echo @@upper(post)".to_string()),
+    }));
+
+    modify_script("test", &mut task).unwrap();
+
+    match task.script.unwrap() {
+        ScriptValue::Sections(sections) => {
+            assert_eq!(sections.pre.unwrap(), "echo PRE");
+            assert!(sections.main.is_none());
+            assert_eq!(sections.post.unwrap(), "echo POST");
+        }
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn modify_script_literal_function_prefix() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo user@@example.com".to_string(),
+        "echo @@ @@(a) @@upper (a)".to_string(),
+    ]));
+
+    modify_script("test", &mut task).unwrap();
+
+    match task.script.unwrap() {
+        ScriptValue::Text(lines) => assert_eq!(
+            lines,
+            vec!["echo user@@example.com", "echo @@ @@(a) @@upper (a)"]
+        ),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn modify_script_invalid_function() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::SingleLine("echo @@upper(a".to_string()));
+
+    assert!(modify_script("test", &mut task).is_err());
+}
+
+#[test]
+fn run_with_functions_in_all_fields() {
+    let mut task = Task::new();
+    task.args = Some(vec!["@@upper(a)".to_string()]);
+    task.cwd = Some("@@lower(DIR)".to_string());
+    task.script = Some(ScriptValue::SingleLine("echo @@upper(b)".to_string()));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let output = run(&step).unwrap();
+
+    assert_eq!(output.config.args.unwrap(), vec!["A"]);
+    assert_eq!(output.config.cwd.unwrap(), "dir");
+    match output.config.script.unwrap() {
+        ScriptValue::SingleLine(line) => assert_eq!(line, "echo B"),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+fn run_condition_with_functions() {
+    let mut env = IndexMap::new();
+    env.insert("KEY".to_string(), "@@upper(value)".to_string());
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        env: Some(env),
+        ..TaskCondition::default()
+    });
+    task.cwd = Some("@@lower(DIR)".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let output = run_condition(&step).unwrap();
+
+    assert_eq!(output.config.cwd.unwrap(), "dir");
+
+    let condition_env = output.config.condition.unwrap().env.unwrap();
+    assert_eq!(condition_env.get("KEY").unwrap(), "VALUE");
+}
+
+#[test]
+fn run_function_all_builtin_functions() {
+    for name in BUILTIN_FUNCTIONS {
+        let output = run_function(name, &vec![], ValueSource::EnvName);
+
+        if let Err(CargoMakeError::NotFound(message)) = output {
+            panic!("built-in function: {} not invoked, {}", name, message);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! # parser
+//!
+//! Parses function call expressions such as `@@trim(@@getat(ENV, ",", 1))`.<br>
+//! Arguments can be nested function calls, quoted strings (single or double quotes) or raw text.
+//! In raw text a backslash only escapes `,`, `)` and `\` and is kept as is anywhere else (a
+//! backslash before the closing `)` of the call is kept as well, for example `@@split(PATH,\)`).
+//! In quoted strings a backslash only escapes the quote character and `\`.
+//!
+
+#[cfg(test)]
+#[path = "parser_test.rs"]
+mod parser_test;
+
+/// The function call prefix
+pub(crate) static FUNCTION_PREFIX: &str = "@@";
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single function argument
+pub(crate) enum Argument {
+    /// Literal value
+    Value(String),
+    /// Nested function call, its output values are used as arguments
+    Function(FunctionCall),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A parsed function call
+pub(crate) struct FunctionCall {
+    /// The function name
+    pub(crate) name: String,
+    /// The function arguments
+    pub(crate) args: Vec<Argument>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A part of a text which may contain embedded function calls
+pub(crate) enum Segment {
+    /// Plain text
+    Text(String),
+    /// Function call
+    Function(FunctionCall),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Parse error details
+pub(crate) struct ParseError {
+    /// The position (1 based character index) in which the error was found
+    pub(crate) position: usize,
+    /// The error description
+    pub(crate) message: String,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    index: usize,
+    /// The number of currently open function calls
+    depth: usize,
+}
+
+fn is_name_char(value: char) -> bool {
+    value.is_alphanumeric() || value == '-' || value == '_' || value == '.' || value == ':'
+}
+
+fn is_escapable(value: char) -> bool {
+    value == '\\' || value == ',' || value == ')'
+}
+
+fn get_raw_argument(value: &str) -> String {
+    // single character arguments (for example a space separator) are not trimmed
+    let str_value = if value.chars().count() == 1 {
+        value
+    } else {
+        value.trim()
+    };
+
+    str_value.to_string()
+}
+
+impl Parser {
+    fn new(value: &str) -> Parser {
+        Parser {
+            chars: value.chars().collect(),
+            index: 0,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn is_at_function(&self) -> bool {
+        self.peek() == Some('@') && self.chars.get(self.index + 1) == Some(&'@')
+    }
+
+    fn is_at_call_start(&self) -> bool {
+        if !self.is_at_function() {
+            return false;
+        }
+
+        let mut index = self.index + FUNCTION_PREFIX.len();
+        while index < self.chars.len() && is_name_char(self.chars[index]) {
+            index += 1;
+        }
+
+        index > self.index + FUNCTION_PREFIX.len() && self.chars.get(index) == Some(&'(')
+    }
+
+    fn error<T>(&self, index: usize, message: &str) -> Result<T, ParseError> {
+        Err(ParseError {
+            position: index + 1,
+            message: message.to_string(),
+        })
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(value) = self.peek() {
+            if value.is_whitespace() {
+                self.index += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns true if the text from the provided index holds enough unescaped ')' characters
+    /// to close all the open function calls
+    fn has_closing_parentheses(&self, index: usize) -> bool {
+        let mut count = 0;
+        let mut escaped = false;
+
+        for value in self.chars.iter().skip(index) {
+            if escaped {
+                escaped = false;
+            } else if *value == '\\' {
+                escaped = true;
+            } else if *value == ')' {
+                count += 1;
+            }
+        }
+
+        count >= self.depth
+    }
+
+    fn read_escaped(&mut self, output: &mut String, quote: Option<char>) {
+        // current char is the backslash
+        let escaped = match (self.chars.get(self.index + 1), quote) {
+            (Some('\\'), _) => true,
+            (Some(value), Some(quote)) => *value == quote,
+            // the backslash is a literal value in case the ')' is needed to close the call
+            (Some(')'), None) => self.has_closing_parentheses(self.index + 2),
+            (Some(value), None) => is_escapable(*value),
+            (None, _) => false,
+        };
+
+        if escaped {
+            output.push(self.chars[self.index + 1]);
+            self.index += 2;
+        } else {
+            output.push('\\');
+            self.index += 1;
+        }
+    }
+
+    fn parse_call(&mut self) -> Result<FunctionCall, ParseError> {
+        self.index += FUNCTION_PREFIX.len();
+
+        let name_start = self.index;
+        while let Some(value) = self.peek() {
+            if is_name_char(value) {
+                self.index += 1;
+            } else {
+                break;
+            }
+        }
+        let name: String = self.chars[name_start..self.index].iter().collect();
+
+        if name.is_empty() {
+            return self.error(name_start, "missing function name");
+        }
+        if self.peek() != Some('(') {
+            return self.error(self.index, "expected '(' after the function name");
+        }
+        let open_index = self.index;
+        self.index += 1;
+
+        let mut args = vec![];
+        if self.peek() == Some(')') {
+            self.index += 1;
+            return Ok(FunctionCall { name, args });
+        }
+
+        self.depth += 1;
+        loop {
+            args.push(self.parse_argument()?);
+
+            match self.peek() {
+                Some(',') => self.index += 1,
+                Some(')') => {
+                    self.index += 1;
+                    self.depth -= 1;
+                    break;
+                }
+                _ => {
+                    return self.error(open_index, &format!("missing ')' for function: {}", &name))
+                }
+            }
+        }
+
+        Ok(FunctionCall { name, args })
+    }
+
+    fn parse_argument(&mut self) -> Result<Argument, ParseError> {
+        let start = self.index;
+        self.skip_whitespace();
+
+        let argument = match self.peek() {
+            Some('@') if self.is_at_function() => Argument::Function(self.parse_call()?),
+            Some(quote) if quote == '"' || quote == '\'' => {
+                Argument::Value(self.parse_quoted(quote)?)
+            }
+            _ => {
+                self.index = start;
+                return self.parse_raw();
+            }
+        };
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') | Some(')') | None => Ok(argument),
+            Some(_) => self.error(self.index, "unexpected text after argument"),
+        }
+    }
+
+    fn parse_quoted(&mut self, quote: char) -> Result<String, ParseError> {
+        let start = self.index;
+        self.index += 1;
+
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some('\\') => self.read_escaped(&mut value, Some(quote)),
+                Some(current) if current == quote => {
+                    self.index += 1;
+                    return Ok(value);
+                }
+                Some(current) => {
+                    value.push(current);
+                    self.index += 1;
+                }
+                None => return self.error(start, "unterminated quoted string"),
+            }
+        }
+    }
+
+    fn parse_raw(&mut self) -> Result<Argument, ParseError> {
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some(',') | Some(')') | None => break,
+                Some('\\') => self.read_escaped(&mut value, None),
+                Some('(') => return self.error(self.index, "unexpected '(' in argument, quote it"),
+                Some(current) => {
+                    value.push(current);
+                    self.index += 1;
+                }
+            }
+        }
+
+        Ok(Argument::Value(get_raw_argument(&value)))
+    }
+}
+
+/// Returns true if the provided value should be parsed as a function call
+pub(crate) fn is_function_call(value: &str) -> bool {
+    value.starts_with(FUNCTION_PREFIX) && value.contains('(')
+}
+
+/// Parses the provided value and returns the function call (or None if the value is not a function call)
+pub(crate) fn parse(value: &str) -> Result<Option<FunctionCall>, ParseError> {
+    if !is_function_call(value) {
+        return Ok(None);
+    }
+
+    let mut parser = Parser::new(value);
+    let call = parser.parse_call()?;
+
+    if parser.index < parser.chars.len() {
+        parser.error(parser.index, "unexpected text after the function call")
+    } else {
+        Ok(Some(call))
+    }
+}
+
+/// Parses the provided text and returns its plain text and embedded function calls segments.<br>
+/// Only `@@` followed by a function name and `(` is treated as a function call.
+pub(crate) fn parse_embedded(value: &str) -> Result<Vec<Segment>, ParseError> {
+    let mut parser = Parser::new(value);
+    let mut segments = vec![];
+    let mut text = String::new();
+
+    while let Some(current) = parser.peek() {
+        if parser.is_at_call_start() {
+            if !text.is_empty() {
+                segments.push(Segment::Text(text));
+                text = String::new();
+            }
+
+            segments.push(Segment::Function(parser.parse_call()?));
+        } else {
+            text.push(current);
+            parser.index += 1;
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    Ok(segments)
+}
@@ -0,0 +1,388 @@
+use super::*;
+
+fn value(value: &str) -> Argument {
+    Argument::Value(value.to_string())
+}
+
+fn call(name: &str, args: Vec<Argument>) -> FunctionCall {
+    FunctionCall {
+        name: name.to_string(),
+        args,
+    }
+}
+
+fn text(value: &str) -> Segment {
+    Segment::Text(value.to_string())
+}
+
+fn parse_error(value: &str) -> ParseError {
+    parse(value).unwrap_err()
+}
+
+#[test]
+fn is_function_call_valid() {
+    assert!(is_function_call("@@split(A,b)"));
+}
+
+#[test]
+fn is_function_call_no_prefix() {
+    assert!(!is_function_call("split(A,b)"));
+}
+
+#[test]
+fn is_function_call_no_arguments() {
+    assert!(!is_function_call("@@split"));
+}
+
+#[test]
+fn get_raw_argument_empty() {
+    let output = get_raw_argument("");
+
+    assert_eq!(output, "");
+}
+
+#[test]
+fn get_raw_argument_single_char() {
+    let output = get_raw_argument(" ");
+
+    assert_eq!(output, " ");
+}
+
+#[test]
+fn get_raw_argument_spaces() {
+    let output = get_raw_argument("     ");
+
+    assert_eq!(output, "");
+}
+
+#[test]
+fn get_raw_argument_mixed() {
+    let output = get_raw_argument(" |");
+
+    assert_eq!(output, "|");
+}
+
+#[test]
+fn parse_function_name_valid() {
+    let output = parse("@@test(123)").unwrap().unwrap();
+
+    assert_eq!(output.name, "test");
+}
+
+#[test]
+fn parse_function_name_invalid() {
+    let output = parse("@@test[123]").unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn parse_arguments_missing_start() {
+    let output = parse("@@test1,2,3,4)").unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn parse_arguments_missing_end() {
+    let output = parse_error("@@test(1,2,3,4");
+
+    assert_eq!(output.message, "missing ')' for function: test");
+}
+
+#[test]
+fn parse_not_function() {
+    let output = parse("value").unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn parse_no_arguments() {
+    let output = parse("@@test()").unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![]));
+}
+
+#[test]
+fn parse_single() {
+    let output = parse("@@test(1)").unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![value("1")]));
+}
+
+#[test]
+fn parse_multiple() {
+    let output = parse("@@test(1,2,3)").unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value("1"), value("2"), value("3")])
+    );
+}
+
+#[test]
+fn parse_multiple_with_spaces() {
+    let output = parse("@@test(1  ,  2,   3   )").unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value("1"), value("2"), value("3")])
+    );
+}
+
+#[test]
+fn parse_single_space_argument() {
+    let output = parse("@@split(ENV, )").unwrap().unwrap();
+
+    assert_eq!(output, call("split", vec![value("ENV"), value(" ")]));
+}
+
+#[test]
+fn parse_empty_arguments() {
+    let output = parse("@@test(1,,)").unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![value("1"), value(""), value("")]));
+}
+
+#[test]
+fn parse_name_with_dash() {
+    let output = parse("@@remove-empty(ENV)").unwrap().unwrap();
+
+    assert_eq!(output, call("remove-empty", vec![value("ENV")]));
+}
+
+#[test]
+fn parse_quoted() {
+    let output = parse(r#"@@test("a, (b)", 'c, "d"' )"#).unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value("a, (b)"), value(r#"c, "d""#)])
+    );
+}
+
+#[test]
+fn parse_quoted_keeps_spaces() {
+    let output = parse(r#"@@test("  a  ")"#).unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![value("  a  ")]));
+}
+
+#[test]
+fn parse_quoted_escaped() {
+    let output = parse(r#"@@test("a\"b\\c")"#).unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![value(r#"a"b\c"#)]));
+}
+
+#[test]
+fn parse_raw_escaped() {
+    let output = parse(r"@@test(a\,b, c\), d\\e)").unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value("a,b"), value("c)"), value(r"d\e")])
+    );
+}
+
+#[test]
+fn parse_raw_backslash_before_other_characters() {
+    let output = parse(r#"@@test(\a, b\"c\', \n)"#).unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value(r"\a"), value(r#"b\"c\'"#), value(r"\n")])
+    );
+}
+
+#[test]
+fn parse_raw_backslash_before_closing_parenthesis() {
+    let output = parse(r"@@split(PATH,\)").unwrap().unwrap();
+
+    assert_eq!(output, call("split", vec![value("PATH"), value(r"\")]));
+}
+
+#[test]
+fn parse_nested_backslash_before_closing_parenthesis() {
+    let output = parse(r"@@trim(@@split(PATH,\))").unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call(
+            "trim",
+            vec![Argument::Function(call(
+                "split",
+                vec![value("PATH"), value(r"\")]
+            ))]
+        )
+    );
+}
+
+#[test]
+fn parse_quoted_backslash_before_other_characters() {
+    let output = parse(r#"@@test("a\,b\'c", 'd\"e\'')"#).unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call("test", vec![value(r"a\,b\'c"), value(r#"d\"e'"#)])
+    );
+}
+
+#[test]
+fn parse_raw_backslash_not_escaping() {
+    let output = parse(r"@@test(C:\dir\file)").unwrap().unwrap();
+
+    assert_eq!(output, call("test", vec![value(r"C:\dir\file")]));
+}
+
+#[test]
+fn parse_nested() {
+    let output = parse(r#"@@trim(@@getat(ENV, ",", 1))"#).unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call(
+            "trim",
+            vec![Argument::Function(call(
+                "getat",
+                vec![value("ENV"), value(","), value("1")]
+            ))]
+        )
+    );
+}
+
+#[test]
+fn parse_nested_multiple_levels() {
+    let output = parse("@@a(1, @@b(@@c(), 2), 3)").unwrap().unwrap();
+
+    assert_eq!(
+        output,
+        call(
+            "a",
+            vec![
+                value("1"),
+                Argument::Function(call(
+                    "b",
+                    vec![Argument::Function(call("c", vec![])), value("2")]
+                )),
+                value("3")
+            ]
+        )
+    );
+}
+
+#[test]
+fn parse_error_missing_name() {
+    let output = parse_error("@@(1)");
+
+    assert_eq!(output.position, 3);
+    assert_eq!(output.message, "missing function name");
+}
+
+#[test]
+fn parse_error_invalid_name() {
+    let output = parse_error("@@test name(1)");
+
+    assert_eq!(output.position, 7);
+    assert_eq!(output.message, "expected '(' after the function name");
+}
+
+#[test]
+fn parse_error_missing_end() {
+    let output = parse_error("@@test(1,2");
+
+    assert_eq!(output.position, 7);
+    assert_eq!(output.message, "missing ')' for function: test");
+}
+
+#[test]
+fn parse_error_nested_missing_end() {
+    let output = parse_error("@@a(@@b(1)");
+
+    assert_eq!(output.position, 4);
+    assert_eq!(output.message, "missing ')' for function: a");
+}
+
+#[test]
+fn parse_error_text_after_call() {
+    let output = parse_error("@@test(1) more");
+
+    assert_eq!(output.position, 10);
+    assert_eq!(output.message, "unexpected text after the function call");
+}
+
+#[test]
+fn parse_error_unterminated_quote() {
+    let output = parse_error(r#"@@test(1, "abc)"#);
+
+    assert_eq!(output.position, 11);
+    assert_eq!(output.message, "unterminated quoted string");
+}
+
+#[test]
+fn parse_error_text_after_quote() {
+    let output = parse_error(r#"@@test("a"b)"#);
+
+    assert_eq!(output.position, 11);
+    assert_eq!(output.message, "unexpected text after argument");
+}
+
+#[test]
+fn parse_error_unescaped_parenthesis() {
+    let output = parse_error("@@test(a(b))");
+
+    assert_eq!(output.position, 9);
+    assert_eq!(output.message, "unexpected '(' in argument, quote it");
+}
+
+#[test]
+fn parse_embedded_empty() {
+    let output = parse_embedded("").unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn parse_embedded_text_only() {
+    let output = parse_embedded("echo @@ a@@b (c)").unwrap();
+
+    assert_eq!(output, vec![text("echo @@ a@@b (c)")]);
+}
+
+#[test]
+fn parse_embedded_function_only() {
+    let output = parse_embedded("@@upper(a)").unwrap();
+
+    assert_eq!(
+        output,
+        vec![Segment::Function(call("upper", vec![value("a")]))]
+    );
+}
+
+#[test]
+fn parse_embedded_mixed() {
+    let output = parse_embedded("echo @@upper(a) and @@lower(@@upper(b)) done").unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            text("echo "),
+            Segment::Function(call("upper", vec![value("a")])),
+            text(" and "),
+            Segment::Function(call(
+                "lower",
+                vec![Argument::Function(call("upper", vec![value("b")]))]
+            )),
+            text(" done"),
+        ]
+    );
+}
+
+#[test]
+fn parse_embedded_error_position() {
+    let output = parse_embedded("echo @@upper(a").unwrap_err();
+
+    assert_eq!(output.position, 13);
+    assert_eq!(output.message, "missing ')' for function: upper");
+}
@@ -0,0 +1,35 @@
+//! # path_join_func
+//!
+//! Takes a list of path components and returns them joined as a single path.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "path_join_func_test.rs"]
+mod path_join_func_test;
+
+use crate::error::CargoMakeError;
+use std::path::PathBuf;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.is_empty() {
+        return Err(CargoMakeError::Arity(
+            "path_join expects at least 1 argument (path components)",
+        ));
+    }
+
+    let mut path = PathBuf::new();
+    for component in function_args {
+        if !component.is_empty() {
+            path.push(component);
+        }
+    }
+
+    let value = path.to_string_lossy().to_string();
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+use std::path::Path;
+
+#[test]
+#[should_panic]
+fn path_join_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+fn path_join_invoke_single() {
+    let output = invoke(&vec!["src".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["src"]);
+}
+
+#[test]
+fn path_join_invoke_multiple() {
+    let output = invoke(&vec![
+        "src".to_string(),
+        "".to_string(),
+        "lib".to_string(),
+        "mod.rs".to_string(),
+    ])
+    .unwrap();
+
+    let expected = Path::new("src").join("lib").join("mod.rs");
+    assert_eq!(output, vec![expected.to_string_lossy().to_string()]);
+}
+
+#[test]
+fn path_join_invoke_all_empty() {
+    let output = invoke(&vec!["".to_string(), "".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
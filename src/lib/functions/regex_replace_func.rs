@@ -0,0 +1,41 @@
+//! # regex_replace_func
+//!
+//! Takes a value, a regular expression and a replacement and returns the value with all the
+//! matches replaced (the replacement may reference capture groups, for example `$1`).
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "regex_replace_func_test.rs"]
+mod regex_replace_func_test;
+
+use crate::error::CargoMakeError;
+use regex::Regex;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 3 {
+        return Err(CargoMakeError::Arity(
+            "regex_replace expects 3 arguments (value, regular expression and replacement)",
+        ));
+    }
+
+    let regex = match Regex::new(&function_args[1]) {
+        Ok(regex) => regex,
+        Err(error) => {
+            return Err(CargoMakeError::FunctionFailed(
+                "regex_replace".to_string(),
+                format!("invalid regular expression: {}", error),
+            ))
+        }
+    };
+
+    let value = regex
+        .replace_all(&function_args[0], function_args[2].as_str())
+        .to_string();
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn regex_replace_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn regex_replace_invoke_invalid_regex() {
+    invoke(&vec!["abc".to_string(), "(".to_string(), "".to_string()]).unwrap();
+}
+
+#[test]
+fn regex_replace_invoke_all_matches() {
+    let output = invoke(&vec![
+        "a1b22c333".to_string(),
+        "[0-9]+".to_string(),
+        "-".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["a-b-c-"]);
+}
+
+#[test]
+fn regex_replace_invoke_capture_groups() {
+    let output = invoke(&vec![
+        "v1.2.3".to_string(),
+        r"^v(\d+)\.(\d+)\.\d+$".to_string(),
+        "$1.$2".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["1.2"]);
+}
+
+#[test]
+fn regex_replace_invoke_empty_output() {
+    let output = invoke(&vec!["abc".to_string(), ".*".to_string(), "".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
@@ -7,9 +7,13 @@
 #[path = "remove_empty_func_test.rs"]
 mod remove_empty_func_test;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
     if function_args.len() != 1 {
-        error!("remove_empty expects only 1 argument (environment variable name)");
+        return Err(CargoMakeError::Arity(
+            "remove_empty expects only 1 argument (environment variable name)",
+        ));
     }
 
     let env_key = function_args[0].clone();
@@ -17,8 +21,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
     let value = envmnt::get_or(&env_key, "");
 
     if value.len() > 0 {
-        vec![value]
+        Ok(vec![value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -3,20 +3,20 @@ use super::*;
 #[test]
 #[should_panic]
 fn remove_empty_invoke_empty() {
-    invoke(&vec![]);
+    invoke(&vec![]).unwrap();
 }
 
 #[test]
 #[should_panic]
 fn remove_empty_invoke_invalid_too_many_args() {
-    invoke(&vec!["TEST".to_string(), "1".to_string()]);
+    invoke(&vec!["TEST".to_string(), "1".to_string()]).unwrap();
 }
 
 #[test]
 fn remove_empty_invoke_exists_with_value() {
     envmnt::set("TEST_REMOVE_EMPTY_VALID", "abc");
 
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_VALID".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_VALID".to_string()]).unwrap();
 
     assert_eq!(output, vec!["abc"]);
 }
@@ -25,14 +25,14 @@ fn remove_empty_invoke_exists_with_value() {
 fn remove_empty_invoke_exists_empty() {
     envmnt::set("TEST_REMOVE_EMPTY_EMPTY", "");
 
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_EMPTY".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_EMPTY".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
 
 #[test]
 fn remove_empty_invoke_not_exists() {
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_NOT_EXISTS".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_NOT_EXISTS".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
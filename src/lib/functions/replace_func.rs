@@ -0,0 +1,27 @@
+//! # replace_func
+//!
+//! Takes a value, a string to replace and the replacement and returns the updated value.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "replace_func_test.rs"]
+mod replace_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 3 {
+        return Err(CargoMakeError::Arity(
+            "replace expects 3 arguments (value, string to replace and replacement)",
+        ));
+    }
+
+    let value = function_args[0].replace(&function_args[1], &function_args[2]);
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn replace_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn replace_invoke_too_many_args() {
+    invoke(&vec![
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+        "d".to_string(),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn replace_invoke_found() {
+    let output = invoke(&vec!["1.2.3".to_string(), ".".to_string(), "-".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1-2-3"]);
+}
+
+#[test]
+fn replace_invoke_not_found() {
+    let output = invoke(&vec!["abc".to_string(), "x".to_string(), "y".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["abc"]);
+}
+
+#[test]
+fn replace_invoke_empty_output() {
+    let output = invoke(&vec!["abc".to_string(), "abc".to_string(), "".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
@@ -0,0 +1,57 @@
+//! # semver_bump_func
+//!
+//! Takes a semantic version and the part to bump (major, minor or patch) and returns the bumped
+//! version.<br>
+//! All lower parts are reset and any pre-release or build metadata is removed.
+//!
+
+#[cfg(test)]
+#[path = "semver_bump_func_test.rs"]
+mod semver_bump_func_test;
+
+use crate::error::CargoMakeError;
+use semver::{BuildMetadata, Prerelease, Version};
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 2 {
+        return Err(CargoMakeError::Arity(
+            "semver_bump expects 2 arguments (version and part: major, minor or patch)",
+        ));
+    }
+
+    let mut version = match Version::parse(&function_args[0]) {
+        Ok(version) => version,
+        Err(error) => {
+            return Err(CargoMakeError::FunctionFailed(
+                "semver_bump".to_string(),
+                format!("invalid version: {}, {}", &function_args[0], error),
+            ))
+        }
+    };
+
+    match function_args[1].as_str() {
+        "major" => {
+            version.major += 1;
+            version.minor = 0;
+            version.patch = 0;
+        }
+        "minor" => {
+            version.minor += 1;
+            version.patch = 0;
+        }
+        "patch" => version.patch += 1,
+        part => {
+            return Err(CargoMakeError::FunctionFailed(
+                "semver_bump".to_string(),
+                format!(
+                    "invalid version part: {}, only major, minor or patch are supported",
+                    part
+                ),
+            ))
+        }
+    }
+    version.pre = Prerelease::EMPTY;
+    version.build = BuildMetadata::EMPTY;
+
+    Ok(vec![version.to_string()])
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn semver_bump_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn semver_bump_invoke_invalid_version() {
+    invoke(&vec!["1.2".to_string(), "patch".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn semver_bump_invoke_invalid_part() {
+    invoke(&vec!["1.2.3".to_string(), "bad".to_string()]).unwrap();
+}
+
+#[test]
+fn semver_bump_invoke_major() {
+    let output = invoke(&vec!["1.2.3".to_string(), "major".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["2.0.0"]);
+}
+
+#[test]
+fn semver_bump_invoke_minor() {
+    let output = invoke(&vec!["1.2.3".to_string(), "minor".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1.3.0"]);
+}
+
+#[test]
+fn semver_bump_invoke_patch() {
+    let output = invoke(&vec!["1.2.3".to_string(), "patch".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1.2.4"]);
+}
+
+#[test]
+fn semver_bump_invoke_removes_pre_release() {
+    let output = invoke(&vec!["1.2.3-beta.1+build".to_string(), "patch".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1.2.4"]);
+}
@@ -0,0 +1,29 @@
+//! # sha256_func
+//!
+//! Takes a file path and returns the SHA-256 hash (lowercase hex) of its content.
+//!
+
+#[cfg(test)]
+#[path = "sha256_func_test.rs"]
+mod sha256_func_test;
+
+use crate::error::CargoMakeError;
+use sha2::{Digest, Sha256};
+use std::fs;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "sha256 expects only 1 argument (file path)",
+        ));
+    }
+
+    let file = &function_args[0];
+    match fs::read(file) {
+        Ok(content) => Ok(vec![format!("{:x}", Sha256::digest(content))]),
+        Err(error) => Err(CargoMakeError::FunctionFailed(
+            "sha256".to_string(),
+            format!("unable to read file: {}, {}", file, error),
+        )),
+    }
+}
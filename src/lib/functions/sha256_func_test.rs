@@ -0,0 +1,27 @@
+use super::*;
+use crate::test;
+
+#[test]
+#[should_panic]
+fn sha256_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn sha256_invoke_file_not_exists() {
+    invoke(&vec!["./sha256_invoke_file_not_exists.txt".to_string()]).unwrap();
+}
+
+#[test]
+fn sha256_invoke_valid() {
+    let file = test::get_temp_test_directory("sha256_invoke_valid").join("file.txt");
+    fs::write(&file, "hello world").unwrap();
+
+    let output = invoke(&vec![file.to_string_lossy().to_string()]).unwrap();
+
+    assert_eq!(
+        output,
+        vec!["b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"]
+    );
+}
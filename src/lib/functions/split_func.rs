@@ -1,19 +1,25 @@
 //! # split_func
 //!
-//! Split function which takes an environment variable name and a split by string.
+//! Split function which takes an environment variable name (or a nested function output value)
+//! and a split by string.
 //!
 
 #[cfg(test)]
 #[path = "split_func_test.rs"]
 mod split_func_test;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+use crate::error::CargoMakeError;
+use crate::functions::ValueSource;
+
+pub(crate) fn invoke(
+    function_args: &[String],
+    source: ValueSource,
+) -> Result<Vec<String>, CargoMakeError> {
     let args_count = function_args.len();
     if args_count < 2 || args_count > 3 {
-        error!("split expects two or three arguments (environment variable name, split by character, optional mode: default, remove-empty)");
+        return Err(CargoMakeError::Arity("split expects two or three arguments (environment variable name, split by character, optional mode: default, remove-empty)"));
     }
 
-    let env_key = function_args[0].clone();
     let split_by = function_args[1].clone();
     let mode_name = if args_count == 3 {
         &function_args[2]
@@ -23,17 +29,20 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
     let remove_empty = mode_name == "remove-empty";
 
     if split_by.len() != 1 {
-        error!("split expects a single character separator");
+        return Err(CargoMakeError::FunctionFailed(
+            "split".to_string(),
+            "split expects a single character separator".to_string(),
+        ));
     }
 
     let split_by_char = split_by.chars().next().unwrap();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = source.resolve(&function_args[0]);
 
     if value.len() > 0 {
         let splitted = value.split(split_by_char);
 
-        splitted
+        Ok(splitted
             .map(|str_value| str_value.to_string())
             .filter(|string_value| {
                 if remove_empty && string_value.is_empty() {
@@ -42,8 +51,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
                     true
                 }
             })
-            .collect()
+            .collect())
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -1,23 +1,26 @@
 //! # trim_func
 //!
-//! Takes an environment variable name and returns its value trimmed.
+//! Takes an environment variable name (or a nested function output value) and returns its value
+//! trimmed.
 //! The value will be removed if empty.
 //!
 
 use crate::error::CargoMakeError;
+use crate::functions::ValueSource;
 
 #[cfg(test)]
 #[path = "trim_func_test.rs"]
 mod trim_func_test;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, CargoMakeError> {
-    if function_args.len() > 2 {
+pub(crate) fn invoke(
+    function_args: &[String],
+    source: ValueSource,
+) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.is_empty() || function_args.len() > 2 {
         return Err(CargoMakeError::Arity("trim expects up to 2 arguments (environment variable name and optionally start/end trim flag)"));
     }
 
-    let env_key = function_args[0].clone();
-
-    let value = envmnt::get_or(&env_key, "");
+    let value = source.resolve(&function_args[0]);
 
     let trimmed_value = if function_args.len() == 1 {
         value.trim().to_string()
@@ -28,7 +31,6 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, CargoMa
             "start" => value.trim_start().to_string(),
             "end" => value.trim_end().to_string(),
             _ => {
-                error!("Invalid trim type provided, only start or end are supported.");
                 return Err(CargoMakeError::MethodCallRestriction(
                     "Invalid trim type provided, only start or end are supported.",
                 ));
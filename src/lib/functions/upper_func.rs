@@ -0,0 +1,27 @@
+//! # upper_func
+//!
+//! Takes a value and returns it in upper case.
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "upper_func_test.rs"]
+mod upper_func_test;
+
+use crate::error::CargoMakeError;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "upper expects only 1 argument (value)",
+        ));
+    }
+
+    let value = function_args[0].to_uppercase();
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn upper_invoke_empty() {
+    invoke(&vec![]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn upper_invoke_too_many_args() {
+    invoke(&vec!["a".to_string(), "b".to_string()]).unwrap();
+}
+
+#[test]
+fn upper_invoke_valid() {
+    let output = invoke(&vec!["aBc".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["ABC"]);
+}
+
+#[test]
+fn upper_invoke_empty_value() {
+    let output = invoke(&vec!["".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
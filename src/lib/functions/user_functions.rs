@@ -0,0 +1,149 @@
+//! # user_functions
+//!
+//! Handles the makefile user defined functions which are implemented as duckscript scripts.<br>
+//! The function output values are cached per function name and arguments for the current run.
+//!
+
+#[cfg(test)]
+#[path = "user_functions_test.rs"]
+mod user_functions_test;
+
+use crate::error::CargoMakeError;
+use crate::functions::BUILTIN_FUNCTIONS;
+use crate::scriptengine;
+use crate::scriptengine::duck_script;
+use crate::types::UserFunction;
+use duckscript::runner;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// The variable holding the function output
+static OUTPUT_VARIABLE: &str = "output";
+
+#[derive(Debug, Default)]
+/// Holds the registered user defined functions and their output cache
+pub(crate) struct Registry {
+    functions: IndexMap<String, UserFunction>,
+    cache: HashMap<(String, Vec<String>), Vec<String>>,
+}
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| Mutex::new(Registry::default()));
+
+/// Merges the user defined functions, extended functions override base functions with the same name
+pub(crate) fn merge(
+    base: Option<IndexMap<String, UserFunction>>,
+    extended: Option<IndexMap<String, UserFunction>>,
+) -> Option<IndexMap<String, UserFunction>> {
+    match (base, extended) {
+        (Some(mut base_functions), Some(extended_functions)) => {
+            base_functions.extend(extended_functions);
+            Some(base_functions)
+        }
+        (base_functions, None) => base_functions,
+        (None, extended_functions) => extended_functions,
+    }
+}
+
+/// Registers the user defined functions for the current run and clears the output cache.<br>
+/// Fails if a user defined function has the same name as a built-in function, as it would
+/// never be invoked.
+pub(crate) fn register(
+    functions: &Option<IndexMap<String, UserFunction>>,
+) -> Result<(), CargoMakeError> {
+    let functions = functions.clone().unwrap_or_default();
+
+    if let Some(name) = functions
+        .keys()
+        .find(|name| BUILTIN_FUNCTIONS.contains(&name.as_str()))
+    {
+        return Err(CargoMakeError::InvalidUserFunction(
+            name.to_string(),
+            "the name is reserved for a built-in function".to_string(),
+        ));
+    }
+
+    let mut registry = REGISTRY.lock().unwrap();
+
+    registry.functions = functions;
+    registry.cache.clear();
+
+    Ok(())
+}
+
+/// Clears the registered functions and output cache and returns them
+pub(crate) fn take() -> Registry {
+    std::mem::take(&mut *REGISTRY.lock().unwrap())
+}
+
+/// Replaces the registered functions and output cache with the provided registry
+pub(crate) fn restore(registry: Registry) {
+    *REGISTRY.lock().unwrap() = registry;
+}
+
+fn run_script(
+    name: &str,
+    function: &UserFunction,
+    function_args: &[String],
+) -> Result<Vec<String>, CargoMakeError> {
+    let fail = |message: String| CargoMakeError::FunctionFailed(name.to_string(), message);
+
+    let script = scriptengine::get_script_text(&function.script)?;
+
+    let mut array_command = "@ = array".to_string();
+    for index in 1..=function_args.len() {
+        array_command.push_str(&format!(" ${{{}}}", index));
+    }
+    let script_text = format!(
+        "exit_on_error true\n{}\n{}",
+        &array_command,
+        script.join("\n")
+    );
+
+    let mut context = duck_script::create_common_context(&function_args.to_vec());
+    context.variables.remove(OUTPUT_VARIABLE);
+    if let Err(error) = duck_script::load_sdk(&mut context.commands, None, None) {
+        return Err(fail(format!("unable to load duckscript SDK: {}", error)));
+    }
+
+    match runner::run_script(&script_text, context) {
+        Ok(context) => match context.variables.get(OUTPUT_VARIABLE) {
+            Some(value) if !value.is_empty() => Ok(vec![value.to_string()]),
+            _ => Ok(vec![]),
+        },
+        Err(error) => Err(fail(error.to_string())),
+    }
+}
+
+/// Invokes the user defined function (if defined) and returns its output values.<br>
+/// Returns None if no such function is defined.
+pub(crate) fn invoke(
+    name: &str,
+    function_args: &[String],
+) -> Option<Result<Vec<String>, CargoMakeError>> {
+    let cache_key = (name.to_string(), function_args.to_vec());
+
+    let function = {
+        let registry = REGISTRY.lock().unwrap();
+
+        if let Some(output) = registry.cache.get(&cache_key) {
+            debug!("Using cached output for function: {}", name);
+            return Some(Ok(output.clone()));
+        }
+
+        registry.functions.get(name)?.clone()
+    };
+
+    let output = run_script(name, &function, function_args);
+
+    if let Ok(ref values) = output {
+        REGISTRY
+            .lock()
+            .unwrap()
+            .cache
+            .insert(cache_key, values.clone());
+    }
+
+    Some(output)
+}
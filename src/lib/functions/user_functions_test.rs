@@ -0,0 +1,174 @@
+use super::*;
+use crate::types::ScriptValue;
+
+fn create_function(script: Vec<&str>) -> UserFunction {
+    UserFunction {
+        description: None,
+        script: ScriptValue::Text(script.iter().map(|line| line.to_string()).collect()),
+    }
+}
+
+fn create_functions(name: &str, script: Vec<&str>) -> Option<IndexMap<String, UserFunction>> {
+    let mut functions = IndexMap::new();
+    functions.insert(name.to_string(), create_function(script));
+
+    Some(functions)
+}
+
+#[test]
+fn merge_both_none() {
+    let output = merge(None, None);
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn merge_base_only() {
+    let output = merge(create_functions("base", vec!["output = set base"]), None).unwrap();
+
+    assert_eq!(output.len(), 1);
+    assert!(output.contains_key("base"));
+}
+
+#[test]
+fn merge_extended_only() {
+    let output = merge(None, create_functions("extended", vec!["output = set 1"])).unwrap();
+
+    assert_eq!(output.len(), 1);
+    assert!(output.contains_key("extended"));
+}
+
+#[test]
+fn merge_both() {
+    let mut base = create_functions("base", vec!["output = set base"]).unwrap();
+    base.insert(
+        "both".to_string(),
+        create_function(vec!["output = set base"]),
+    );
+    let extended = create_functions("both", vec!["output = set extended"]);
+
+    let output = merge(Some(base), extended).unwrap();
+
+    assert_eq!(output.len(), 2);
+    match output.get("both").unwrap().script {
+        ScriptValue::Text(ref lines) => assert_eq!(lines, &vec!["output = set extended"]),
+        _ => panic!("invalid script type"),
+    }
+}
+
+#[test]
+#[ignore]
+fn invoke_not_defined() {
+    register(&None).unwrap();
+
+    let output = invoke("invoke_not_defined", &vec![]);
+
+    assert!(output.is_none());
+}
+
+#[test]
+#[ignore]
+fn invoke_with_arguments() {
+    register(&create_functions(
+        "artifact_name",
+        vec!["output = set \"${1}-${2}.tar.gz\""],
+    ))
+    .unwrap();
+
+    let output = invoke(
+        "artifact_name",
+        &vec!["app".to_string(), "linux".to_string()],
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(output, vec!["app-linux.tar.gz"]);
+}
+
+#[test]
+#[ignore]
+fn invoke_no_output() {
+    register(&create_functions("no_output", vec!["value = set 1"])).unwrap();
+
+    let output = invoke("no_output", &vec![]).unwrap().unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+#[ignore]
+fn invoke_script_error() {
+    register(&create_functions("script_error", vec!["assert_fail error"])).unwrap();
+
+    let output = invoke("script_error", &vec![]).unwrap();
+
+    match output {
+        Err(CargoMakeError::FunctionFailed(name, _)) => assert_eq!(name, "script_error"),
+        _ => panic!("expected function failure"),
+    }
+}
+
+#[test]
+#[ignore]
+fn invoke_cached() {
+    envmnt::set("TEST_USER_FUNCTIONS_CACHED_COUNT", "0");
+    register(&create_functions(
+        "counter",
+        vec![
+            "count = get_env TEST_USER_FUNCTIONS_CACHED_COUNT",
+            "count = calc ${count} + 1",
+            "set_env TEST_USER_FUNCTIONS_CACHED_COUNT ${count}",
+            "output = set \"${1}${count}\"",
+        ],
+    ))
+    .unwrap();
+
+    let first = invoke("counter", &vec!["a".to_string()]).unwrap().unwrap();
+    let second = invoke("counter", &vec!["a".to_string()]).unwrap().unwrap();
+    let other_args = invoke("counter", &vec!["b".to_string()]).unwrap().unwrap();
+
+    assert_eq!(first, vec!["a1"]);
+    assert_eq!(second, vec!["a1"]);
+    assert_eq!(other_args, vec!["b2"]);
+    assert_eq!(
+        envmnt::get_or_panic("TEST_USER_FUNCTIONS_CACHED_COUNT"),
+        "2"
+    );
+
+    // registering the functions again starts a new cache
+    register(&create_functions("counter", vec!["output = set \"${1}\""])).unwrap();
+    let output = invoke("counter", &vec!["a".to_string()]).unwrap().unwrap();
+    assert_eq!(output, vec!["a"]);
+}
+
+#[test]
+fn register_builtin_function_name() {
+    let output = register(&create_functions("split", vec!["output = set 1"]));
+
+    match output {
+        Err(CargoMakeError::InvalidUserFunction(name, _)) => assert_eq!(name, "split"),
+        _ => panic!("expected invalid user function error"),
+    }
+}
+
+#[test]
+#[ignore]
+fn take_and_restore() {
+    register(&create_functions("take_restore", vec!["output = set root"])).unwrap();
+
+    let registry = take();
+
+    assert!(invoke("take_restore", &vec![]).is_none());
+
+    register(&create_functions(
+        "take_restore",
+        vec!["output = set member"],
+    ))
+    .unwrap();
+    restore(registry);
+
+    let output = invoke("take_restore", &vec![]).unwrap().unwrap();
+    assert_eq!(output, vec!["root"]);
+
+    register(&None).unwrap();
+}
@@ -14,9 +14,10 @@ use fsio::path::from_path::FromPath;
 use glob::glob;
 use ignore::WalkBuilder;
 use std::collections::HashSet;
+use std::env;
 use std::fs::File;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn create_text_file(text: &str, extension: &str) -> Result<String, CargoMakeError> {
     let file_path = fsio_path::get_temporary_file_path(extension);
@@ -155,3 +156,45 @@ pub(crate) fn get_path_list(
 pub(crate) fn canonicalize_to_string(path_string: &str) -> String {
     fsio_path::canonicalize_or(path_string, path_string)
 }
+
+fn get_executable_extensions() -> Vec<String> {
+    if cfg!(windows) {
+        let extensions = envmnt::get_or("PATHEXT", ".COM;.EXE;.BAT;.CMD");
+        let mut extensions: Vec<String> = extensions
+            .split(';')
+            .filter(|extension| !extension.is_empty())
+            .map(|extension| extension.to_string())
+            .collect();
+        extensions.insert(0, "".to_string());
+
+        extensions
+    } else {
+        vec!["".to_string()]
+    }
+}
+
+/// Searches for the provided command in the PATH and returns its full path if found.<br>
+/// Commands which contain a path separator are checked as is.
+pub(crate) fn find_executable(command: &str) -> Option<PathBuf> {
+    if command.is_empty() {
+        return None;
+    }
+
+    let extensions = get_executable_extensions();
+    let find_with_extension = |base: &Path| {
+        extensions
+            .iter()
+            .map(|extension| PathBuf::from(format!("{}{}", base.display(), extension)))
+            .find(|candidate| candidate.is_file())
+    };
+
+    let command_path = Path::new(command);
+    if command_path.components().count() > 1 {
+        return find_with_extension(command_path);
+    }
+
+    let path_value = env::var_os("PATH")?;
+    env::split_paths(&path_value)
+        .map(|directory| directory.join(command))
+        .find_map(|candidate| find_with_extension(&candidate))
+}
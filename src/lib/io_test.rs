@@ -113,3 +113,33 @@ fn get_path_list_dirs_with_gitignore() {
 fn get_path_list_dirs_with_wrong_include_file_type() {
     get_path_list("./target", true, true, Some("bad".to_string()));
 }
+
+#[test]
+fn find_executable_found() {
+    let output = find_executable("cargo");
+
+    assert!(output.unwrap().is_file());
+}
+
+#[test]
+fn find_executable_full_path() {
+    let executable = find_executable("cargo").unwrap();
+
+    let output = find_executable(&executable.to_string_lossy());
+
+    assert!(output.is_some());
+}
+
+#[test]
+fn find_executable_not_found() {
+    let output = find_executable("cargo_make_bad_command_for_test");
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn find_executable_empty() {
+    let output = find_executable("");
+
+    assert!(output.is_none());
+}
@@ -7,6 +7,8 @@
 #[path = "legacy_test.rs"]
 mod legacy_test;
 
+use crate::types::{DeprecationInfo, Task};
+use indexmap::IndexMap;
 use std::env;
 use std::fs::copy;
 use std::path::PathBuf;
@@ -84,9 +86,60 @@ pub(crate) fn migrate(target_directory: PathBuf, file: &str) -> bool {
     }
 }
 
-pub(crate) fn show_deprecated_attriute_warning(old_attribute: &str, new_attribute: &str) {
-    warn!(
-        "[DEPRECATED] The attribute '{}' has been replaced with '{}'. Please update your makefile.",
-        old_attribute, new_attribute
-    );
+#[derive(Debug, Clone, Copy)]
+/// Holds a deprecated task attribute and its replacement
+pub(crate) struct Deprecation {
+    /// The deprecated attribute name
+    pub(crate) name: &'static str,
+    /// The replacement attribute name
+    pub(crate) replacement: &'static str,
+}
+
+/// All renamed task attributes, used for the runtime warnings and for the makefile migration.<br>
+/// Deprecated core tasks are defined by the deprecated attribute of the internal makefiles tasks.
+pub(crate) static DEPRECATIONS: &[Deprecation] = &[Deprecation {
+    name: "force",
+    replacement: "ignore_errors",
+}];
+
+/// The deprecation message prefix of deprecated tasks which have a direct replacement task
+static TASK_REPLACEMENT_PREFIX: &str = "Please use task:";
+
+/// Returns the replacement of the deprecated attribute (if deprecated)
+pub(crate) fn get_replacement(name: &str) -> Option<&'static str> {
+    DEPRECATIONS
+        .iter()
+        .find(|deprecation| deprecation.name == name)
+        .map(|deprecation| deprecation.replacement)
+}
+
+/// Returns the replacement task of a deprecated task, in case its deprecation message is in the
+/// form of: Please use task: <name>
+pub(crate) fn get_task_replacement(task: &Task) -> Option<String> {
+    match task.deprecated {
+        Some(DeprecationInfo::Message(ref message)) => message
+            .strip_prefix(TASK_REPLACEMENT_PREFIX)
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty()),
+        _ => None,
+    }
+}
+
+/// Returns all deprecated tasks which have a direct replacement task, mapped to their replacement
+pub(crate) fn get_task_replacements(tasks: &IndexMap<String, Task>) -> IndexMap<String, String> {
+    tasks
+        .iter()
+        .filter_map(|(name, task)| {
+            get_task_replacement(task).map(|replacement| (name.to_string(), replacement))
+        })
+        .collect()
+}
+
+pub(crate) fn show_deprecated_attriute_warning(old_attribute: &str) {
+    if let Some(new_attribute) = get_replacement(old_attribute) {
+        warn!(
+            "[DEPRECATED] The attribute '{}' has been replaced with '{}'. Please update your makefile or run with --migrate.",
+            old_attribute, new_attribute
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use super::*;
+use crate::descriptor;
 use crate::test;
+use crate::types::RunTaskInfo;
 
 #[test]
 #[cfg(target_os = "linux")]
@@ -89,5 +91,71 @@ fn migrate_from_directory_delete_legacy_directory() {
 
 #[test]
 fn show_deprecated_attriute_warning_valid() {
-    show_deprecated_attriute_warning("old", "new");
+    show_deprecated_attriute_warning("force");
+}
+
+#[test]
+fn show_deprecated_attriute_warning_unknown() {
+    show_deprecated_attriute_warning("unknown");
+}
+
+#[test]
+fn get_replacement_found() {
+    assert_eq!(get_replacement("force").unwrap(), "ignore_errors");
+}
+
+#[test]
+fn get_replacement_not_deprecated() {
+    assert!(get_replacement("ignore_errors").is_none());
+}
+
+#[test]
+fn get_task_replacement_message() {
+    let mut task = Task::new();
+    task.deprecated = Some(DeprecationInfo::Message(
+        "Please use task: clippy-ci-flow".to_string(),
+    ));
+
+    assert_eq!(get_task_replacement(&task).unwrap(), "clippy-ci-flow");
+}
+
+#[test]
+fn get_task_replacement_other_message() {
+    let mut task = Task::new();
+    task.deprecated = Some(DeprecationInfo::Message(
+        "Use build task instead.".to_string(),
+    ));
+
+    assert!(get_task_replacement(&task).is_none());
+}
+
+#[test]
+fn get_task_replacement_boolean() {
+    let mut task = Task::new();
+    task.deprecated = Some(DeprecationInfo::Boolean(true));
+
+    assert!(get_task_replacement(&task).is_none());
+}
+
+#[test]
+fn get_task_replacements_internal_makefile() {
+    let config = descriptor::load_internal_descriptors(true, false, None).unwrap();
+
+    let output = get_task_replacements(&config.tasks);
+
+    assert_eq!(
+        output.get("conditioned-check-format").unwrap(),
+        "check-format-ci-flow"
+    );
+    assert_eq!(output.get("conditioned-clippy").unwrap(), "clippy-ci-flow");
+    assert!(!output.contains_key("build-verbose"));
+    assert!(!output.contains_key("bintray-upload"));
+    for (name, replacement) in &output {
+        let task = config.tasks.get(name).unwrap();
+        match task.run_task {
+            Some(RunTaskInfo::Name(ref run_task_name)) => assert_eq!(run_task_name, replacement),
+            _ => panic!("deprecated task: {} does not run its replacement", name),
+        }
+        assert!(config.tasks.contains_key(replacement));
+    }
 }
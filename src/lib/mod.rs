@@ -57,6 +57,7 @@ pub mod cli_commands;
 pub mod cli_parser;
 mod command;
 mod condition;
+mod condition_expr;
 pub mod config;
 mod descriptor;
 mod environment;
@@ -73,10 +74,12 @@ mod proxy_task;
 mod recursion_level;
 pub mod runner;
 mod scriptengine;
+mod secrets;
 mod storage;
 mod time_summary;
 mod toolchain;
 mod version;
+mod workspace;
 
 /// Handles the command line arguments and executes the runner.
 pub fn run_cli(command_name: String, sub_command: bool) -> Result<CliArgs, CargoMakeError> {
@@ -144,7 +144,10 @@ fn setup_script_globals_for_task(context: &mut Context, step: &Step, script: &mu
     // meta info
     context.variables.insert(
         "task.has_condition".to_string(),
-        (task.condition.is_some() || task.condition_script.is_some()).to_string(),
+        (task.condition.is_some()
+            || task.condition_expr.is_some()
+            || task.condition_script.is_some())
+        .to_string(),
     );
     context.variables.insert(
         "task.has_env".to_string(),
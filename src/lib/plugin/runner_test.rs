@@ -58,8 +58,10 @@ fn get_plugin_no_plugins_config() {
             env_files: vec![],
             env: IndexMap::new(),
             env_scripts: vec![],
+            env_required: None,
             tasks: IndexMap::new(),
             plugins: None,
+            functions: None,
         },
         "test",
     );
@@ -75,11 +77,13 @@ fn get_plugin_not_found() {
             env_files: vec![],
             env: IndexMap::new(),
             env_scripts: vec![],
+            env_required: None,
             tasks: IndexMap::new(),
             plugins: Some(Plugins {
                 aliases: None,
                 plugins: IndexMap::new(),
             }),
+            functions: None,
         },
         "test",
     );
@@ -103,11 +107,13 @@ fn get_plugin_found() {
             env_files: vec![],
             env: IndexMap::new(),
             env_scripts: vec![],
+            env_required: None,
             tasks: IndexMap::new(),
             plugins: Some(Plugins {
                 aliases: None,
                 plugins,
             }),
+            functions: None,
         },
         "test",
     );
@@ -136,11 +142,13 @@ fn get_plugin_found_with_alias() {
             env_files: vec![],
             env: IndexMap::new(),
             env_scripts: vec![],
+            env_required: None,
             tasks: IndexMap::new(),
             plugins: Some(Plugins {
                 aliases: Some(aliases),
                 plugins,
             }),
+            functions: None,
         },
         "test",
     );
@@ -454,6 +462,7 @@ fn run_task_invoked_valid() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("extended".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -472,13 +481,26 @@ fn run_task_invoked_valid() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: None,
         ignore_errors: Some(true),
         force: Some(true),
         env_files: Some(vec![EnvFile::Path("extended".to_string())]),
         env: Some(env.clone()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
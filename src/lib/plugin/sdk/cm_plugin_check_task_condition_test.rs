@@ -50,6 +50,16 @@ fn run_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        git_branch: None,
+        git_dirty: None,
+        git_changed_files: None,
+        git_tag_on_head: None,
+        commands_exist: None,
+        target_triples: None,
+        crate_features: None,
+        all: None,
+        any: None,
+        not: None,
     });
 
     let mut flow_info = create_empty_flow_info();
@@ -34,9 +34,9 @@ use crate::proxy_task::create_proxy_task;
 use crate::scriptengine;
 use crate::time_summary;
 use crate::types::{
-    CliArgs, Config, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo, FlowState,
-    MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step, Task,
-    TaskWatchOptions,
+    CliArgs, Config, DeprecationInfo, EnvInfo, EnvScope, EnvValue, ExecutionPlan, FlowInfo,
+    FlowState, MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step,
+    Task, TaskWatchOptions,
 };
 
 fn do_in_task_working_directory<F>(step: &Step, mut action: F) -> Result<(), CargoMakeError>
@@ -325,126 +325,125 @@ fn should_watch(task: &Task) -> bool {
     }
 }
 
-pub(crate) fn run_task(
-    flow_info: &FlowInfo,
-    flow_state: Rc<RefCell<FlowState>>,
-    step: &Step,
-) -> Result<(), CargoMakeError> {
-    let options = RunTaskOptions {
-        plugins_enabled: true,
+fn is_task_env_isolated(config: &Config, task: &Task) -> bool {
+    match task.env_scope {
+        Some(env_scope) => env_scope == EnvScope::Task,
+        None => config.config.isolate_task_env.unwrap_or(false),
+    }
+}
+
+fn set_task_env(step: &Step) {
+    //get profile
+    let profile_name = profile::get();
+
+    match step.config.env_files {
+        Some(ref env_files) => environment::set_env_files(env_files.clone()),
+        None => (),
+    };
+    match step.config.env {
+        Some(ref env) => environment::set_env(env.clone()),
+        None => (),
     };
 
-    run_task_with_options(flow_info, flow_state, step, &options)
+    envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+
+    //make sure profile env is not overwritten
+    profile::set(&profile_name);
 }
 
-pub(crate) fn run_task_with_options(
+fn run_actionable_task(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
     step: &Step,
-    options: &RunTaskOptions,
+    start_time: SystemTime,
+    isolate_env: bool,
 ) -> Result<(), CargoMakeError> {
-    let start_time = SystemTime::now();
-
-    // if a plugin is handling the task execution flow
-    if run_task_plugin(flow_info, flow_state.clone(), step, options) {
-        time_summary::add(
-            &mut flow_state.borrow_mut().time_summary,
-            &step.name,
-            start_time,
-        );
-        return Ok(());
-    }
-
-    if step.config.is_actionable() {
+    if !isolate_env {
         match step.config.env {
             Some(ref env) => environment::set_current_task_meta_info_env(env.clone()),
             None => (),
         };
+    }
 
-        if validate_condition(
-            &flow_info,
-            &environment::expand_condition_script_runner_arguments(&step),
-        )? {
-            if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
-                debug!("Running Task: {}", &step.name);
-            } else {
-                info!("Running Task: {}", &step.name);
-            }
-
-            if !step.config.is_valid() {
-                error!(
-                    "Invalid task: {}, contains multiple actions.\n{:#?}",
-                    &step.name, &step.config
-                );
-            }
-
-            let deprecated_info = step.config.deprecated.clone();
-            match deprecated_info {
-                Some(deprecated) => match deprecated {
-                    DeprecationInfo::Boolean(value) => {
-                        if value {
-                            warn!("Task: {} is deprecated.", &step.name);
-                        }
+    if validate_condition(
+        &flow_info,
+        &environment::expand_condition_script_runner_arguments(&functions::run_condition(step)?),
+    )? {
+        if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
+            debug!("Running Task: {}", &step.name);
+        } else {
+            info!("Running Task: {}", &step.name);
+        }
 
-                        ()
-                    }
-                    DeprecationInfo::Message(ref message) => {
-                        warn!("Task: {} is deprecated - {}", &step.name, message);
+        if !step.config.is_valid() {
+            error!(
+                "Invalid task: {}, contains multiple actions.\n{:#?}",
+                &step.name, &step.config
+            );
+        }
 
-                        ()
+        let deprecated_info = step.config.deprecated.clone();
+        match deprecated_info {
+            Some(deprecated) => match deprecated {
+                DeprecationInfo::Boolean(value) => {
+                    if value {
+                        warn!("Task: {} is deprecated.", &step.name);
                     }
-                },
-                None => (),
-            };
 
-            //get profile
-            let profile_name = profile::get();
+                    ()
+                }
+                DeprecationInfo::Message(ref message) => {
+                    warn!("Task: {} is deprecated - {}", &step.name, message);
 
-            match step.config.env_files {
-                Some(ref env_files) => environment::set_env_files(env_files.clone()),
-                None => (),
-            };
-            match step.config.env {
-                Some(ref env) => environment::set_env(env.clone()),
-                None => (),
-            };
+                    ()
+                }
+            },
+            None => (),
+        };
 
-            envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+        // isolated task env is set before the condition evaluation
+        if !isolate_env {
+            set_task_env(step);
+        }
 
-            //make sure profile env is not overwritten
-            profile::set(&profile_name);
+        environment::required::validate(
+            &step.config.env_required,
+            &format!("task: {}", &step.name),
+        )?;
 
-            // modify step using env and functions
-            let mut updated_step = functions::run(&step)?;
-            updated_step = environment::expand_env(&updated_step);
+        // modify step using env and functions
+        let mut updated_step = functions::run(&step)?;
+        updated_step = environment::expand_env(&updated_step);
 
-            let watch = should_watch(&step.config);
+        let watch = should_watch(&step.config);
 
-            if watch {
-                watch_task(
-                    &flow_info,
-                    flow_state,
-                    &step.name,
-                    step.config.watch.clone(),
-                )?;
-            } else {
-                do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
-                    installer::install(&updated_step.config, flow_info, flow_state.clone())?;
-                    Ok(true)
-                })?;
-
-                match step.config.run_task {
-                    Some(ref sub_task) => {
-                        time_summary::add(
-                            &mut flow_state.borrow_mut().time_summary,
-                            &step.name,
-                            start_time,
-                        );
-
-                        run_sub_task(&flow_info, flow_state, sub_task)?;
-                    }
-                    None => {
-                        do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
+        if watch {
+            watch_task(
+                &flow_info,
+                flow_state,
+                &step.name,
+                step.config.watch.clone(),
+            )?;
+        } else {
+            do_in_task_working_directory(&updated_step, || -> Result<bool, CargoMakeError> {
+                installer::install(&updated_step.config, flow_info, flow_state.clone())?;
+                Ok(true)
+            })?;
+
+            match step.config.run_task {
+                Some(ref sub_task) => {
+                    time_summary::add(
+                        &mut flow_state.borrow_mut().time_summary,
+                        &step.name,
+                        start_time,
+                    );
+
+                    run_sub_task(&flow_info, flow_state, sub_task)?;
+                }
+                None => {
+                    do_in_task_working_directory(
+                        &updated_step,
+                        || -> Result<bool, CargoMakeError> {
                             // run script
                             let script_runner_done = scriptengine::invoke(
                                 &updated_step.config,
@@ -457,36 +456,85 @@ pub(crate) fn run_task_with_options(
                                 command::run(&updated_step)?;
                             };
                             Ok(true)
-                        })?;
+                        },
+                    )?;
+
+                    time_summary::add(
+                        &mut flow_state.borrow_mut().time_summary,
+                        &step.name,
+                        start_time,
+                    );
+                }
+            };
+        }
+    } else {
+        let fail_message = match step.config.condition {
+            Some(ref condition) => match condition.fail_message {
+                Some(ref value) => value.to_string(),
+                None => "".to_string(),
+            },
+            None => "".to_string(),
+        };
 
-                        time_summary::add(
-                            &mut flow_state.borrow_mut().time_summary,
-                            &step.name,
-                            start_time,
-                        );
-                    }
-                };
-            }
+        if logger::should_reduce_output(&flow_info) && !step.config.is_actionable() {
+            debug!("Skipping Task: {} {}", &step.name, &fail_message);
         } else {
-            let fail_message = match step.config.condition {
-                Some(ref condition) => match condition.fail_message {
-                    Some(ref value) => value.to_string(),
-                    None => "".to_string(),
-                },
-                None => "".to_string(),
-            };
+            info!("Skipping Task: {} {}", &step.name, &fail_message);
+        }
+    }
 
-            if logger::should_reduce_output(&flow_info) && !step.config.is_actionable() {
-                debug!("Skipping Task: {} {}", &step.name, &fail_message);
-            } else {
-                info!("Skipping Task: {} {}", &step.name, &fail_message);
-            }
+    Ok(())
+}
+
+pub(crate) fn run_task(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+) -> Result<(), CargoMakeError> {
+    let options = RunTaskOptions {
+        plugins_enabled: true,
+    };
+
+    run_task_with_options(flow_info, flow_state, step, &options)
+}
+
+pub(crate) fn run_task_with_options(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    options: &RunTaskOptions,
+) -> Result<(), CargoMakeError> {
+    let start_time = SystemTime::now();
+
+    // if a plugin is handling the task execution flow
+    if run_task_plugin(flow_info, flow_state.clone(), step, options) {
+        time_summary::add(
+            &mut flow_state.borrow_mut().time_summary,
+            &step.name,
+            start_time,
+        );
+        return Ok(());
+    }
+
+    if step.config.is_actionable() {
+        if is_task_env_isolated(&flow_info.config, &step.config) {
+            let env_snapshot = environment::get_env_snapshot();
+            set_task_env(step);
+
+            let result = run_actionable_task(flow_info, flow_state, step, start_time, true);
+
+            // restore the env so the task env does not leak to other tasks
+            environment::restore_env_snapshot(&env_snapshot);
+
+            result
+        } else {
+            run_actionable_task(flow_info, flow_state, step, start_time, false)
         }
     } else {
         debug!("Ignoring Empty Task: {}", &step.name);
-    }
 
-    Ok(())
+        Ok(())
+    }
 }
 
 fn run_task_flow(
@@ -691,6 +739,8 @@ pub fn run(
 
     let flow_state_rc = Rc::new(RefCell::new(flow_state));
 
+    environment::required::validate(&flow_info.config.env_required, "flow")?;
+
     if flow_info.disable_on_error || flow_info.config.config.on_error_task.is_none() {
         run_flow(&flow_info, flow_state_rc.clone(), false)?;
     } else {
@@ -3,10 +3,11 @@ use std::env::VarError;
 use super::*;
 use crate::test;
 use crate::types::{
-    ConditionScriptValue, ConfigSection, CrateInfo, EnvFile, RunTaskDetails, ScriptValue,
-    TaskCondition,
+    ConditionScriptValue, ConfigSection, CrateInfo, EnvFile, EnvRequired, EnvRequiredInfo,
+    RunTaskDetails, ScriptValue, TaskCondition,
 };
 use cfg_if::cfg_if;
+use fsio::path::from_path::FromPath;
 use git_info::types::GitInfo;
 use rust_info::types::RustInfo;
 
@@ -22,8 +23,10 @@ fn run_flow_private() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -59,8 +62,10 @@ fn run_flow_private_skipped() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -96,8 +101,10 @@ fn run_flow_private_sub_task() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -133,8 +140,10 @@ fn run_flow_allow_private() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
 
     let mut task = Task::new();
@@ -171,8 +180,10 @@ fn run_task_bad_script() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -211,8 +222,10 @@ fn run_task_script_with_args_error() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -250,8 +263,10 @@ fn run_task_script_with_args_valid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -288,8 +303,10 @@ fn run_task_command() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -350,8 +367,10 @@ fn run_task_bad_command_valid_script() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -389,8 +408,10 @@ fn run_task_no_command_valid_script() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -434,8 +455,10 @@ fn run_task_bad_run_task_valid_command() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -480,8 +503,10 @@ fn run_task_valid_run_task() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -519,8 +544,10 @@ fn run_task_invalid_task() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -558,8 +585,10 @@ fn run_task_set_env_file() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -613,8 +642,10 @@ fn run_task_set_env() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -655,6 +686,526 @@ fn run_task_set_env() {
     assert_eq!(envmnt::get_or_panic("TEST_RUN_TASK_SET_ENV"), "VALID");
 }
 
+#[test]
+#[ignore]
+fn run_task_isolate_task_env_config() {
+    let mut config_section = ConfigSection::new();
+    config_section.isolate_task_env = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let file = test::get_temp_test_directory("run_task_isolate_task_env_config").join("value.txt");
+    let file_path: String = FromPath::from_path(&file);
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_CONFIG".to_string(),
+        EnvValue::Value("VALID".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "writefile {} ${{TEST_RUN_TASK_ISOLATE_ENV_CONFIG}}",
+        &file_path
+    )]));
+    task.env = Some(env);
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_ISOLATE_ENV_CONFIG", "EMPTY");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(fsio::file::read_text_file(&file).unwrap(), "VALID");
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_ISOLATE_ENV_CONFIG"),
+        "EMPTY"
+    );
+}
+
+#[test]
+#[ignore]
+fn run_task_isolate_task_env_task_scope() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let file =
+        test::get_temp_test_directory("run_task_isolate_task_env_task_scope").join("value.txt");
+    let file_path: String = FromPath::from_path(&file);
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE".to_string(),
+        EnvValue::Value("VALID".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "writefile {} ${{TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE}}",
+        &file_path
+    )]));
+    task.env = Some(env);
+    task.env_scope = Some(EnvScope::Task);
+    task.env.as_mut().unwrap().insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE_NEW".to_string(),
+        EnvValue::Value("NEW".to_string()),
+    );
+    envmnt::remove("TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE_NEW");
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE", "EMPTY");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(fsio::file::read_text_file(&file).unwrap(), "VALID");
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE"),
+        "EMPTY"
+    );
+    assert!(!envmnt::exists("TEST_RUN_TASK_ISOLATE_ENV_TASK_SCOPE_NEW"));
+}
+
+#[test]
+#[ignore]
+fn run_task_isolate_task_env_global_scope() {
+    let mut config_section = ConfigSection::new();
+    config_section.isolate_task_env = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let file =
+        test::get_temp_test_directory("run_task_isolate_task_env_global_scope").join("value.txt");
+    let file_path: String = FromPath::from_path(&file);
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_GLOBAL_SCOPE".to_string(),
+        EnvValue::Value("VALID".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "writefile {} ${{TEST_RUN_TASK_ISOLATE_ENV_GLOBAL_SCOPE}}",
+        &file_path
+    )]));
+    task.env = Some(env);
+    task.env_scope = Some(EnvScope::Global);
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_ISOLATE_ENV_GLOBAL_SCOPE", "EMPTY");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(fsio::file::read_text_file(&file).unwrap(), "VALID");
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_ISOLATE_ENV_GLOBAL_SCOPE"),
+        "VALID"
+    );
+}
+
+#[test]
+#[ignore]
+fn run_task_isolate_task_env_condition() {
+    let mut config_section = ConfigSection::new();
+    config_section.isolate_task_env = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let file =
+        test::get_temp_test_directory("run_task_isolate_task_env_condition").join("value.txt");
+    let file_path: String = FromPath::from_path(&file);
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_CONDITION".to_string(),
+        EnvValue::Value("VALID".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "writefile {} ${{TEST_RUN_TASK_ISOLATE_ENV_CONDITION}}",
+        &file_path
+    )]));
+    task.env = Some(env);
+    let mut condition_env = IndexMap::new();
+    condition_env.insert(
+        "TEST_RUN_TASK_ISOLATE_ENV_CONDITION".to_string(),
+        "VALID".to_string(),
+    );
+    task.condition = Some(TaskCondition {
+        env: Some(condition_env),
+        ..TaskCondition::default()
+    });
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_ISOLATE_ENV_CONDITION", "EMPTY");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    // the condition is only met using the task env
+    assert_eq!(fsio::file::read_text_file(&file).unwrap(), "VALID");
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_ISOLATE_ENV_CONDITION"),
+        "EMPTY"
+    );
+}
+
+fn create_empty_config_flow_info() -> FlowInfo {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+
+    FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    }
+}
+
+#[test]
+#[ignore]
+fn run_task_env_required_missing() {
+    let flow_info = create_empty_config_flow_info();
+
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_RUN_TASK_ENV_REQUIRED_MISSING".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.env_required = Some(env_required);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::remove("TEST_RUN_TASK_ENV_REQUIRED_MISSING");
+
+    let output = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+
+    match output {
+        Err(CargoMakeError::EnvRequired(scope, issues)) => {
+            assert_eq!(scope, "task: test");
+            assert_eq!(
+                issues,
+                "  * TEST_RUN_TASK_ENV_REQUIRED_MISSING is not defined"
+            );
+        }
+        _ => panic!("expected env required error"),
+    }
+}
+
+#[test]
+#[ignore]
+fn run_task_env_required_defined_by_task_env() {
+    let flow_info = create_empty_config_flow_info();
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_ENV_REQUIRED_TASK_ENV".to_string(),
+        EnvValue::Value("production".to_string()),
+    );
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_RUN_TASK_ENV_REQUIRED_TASK_ENV".to_string(),
+        EnvRequired::Info(EnvRequiredInfo {
+            allowed_values: Some(vec!["production".to_string()]),
+            ..EnvRequiredInfo::default()
+        }),
+    );
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.env = Some(env);
+    task.env_required = Some(env_required);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::remove("TEST_RUN_TASK_ENV_REQUIRED_TASK_ENV");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_flow_env_required_defined_by_previous_step() {
+    let mut flow_info = create_empty_config_flow_info();
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_FLOW_ENV_REQUIRED_PREVIOUS_STEP".to_string(),
+        EnvValue::Value("1".to_string()),
+    );
+    let mut first_task = Task::new();
+    first_task.command = Some("echo".to_string());
+    first_task.env = Some(env);
+    let mut env_required = IndexMap::new();
+    env_required.insert(
+        "TEST_RUN_FLOW_ENV_REQUIRED_PREVIOUS_STEP".to_string(),
+        EnvRequired::Boolean(true),
+    );
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.dependencies = Some(vec!["first".into()]);
+    task.env_required = Some(env_required);
+    flow_info
+        .config
+        .tasks
+        .insert("first".to_string(), first_task);
+    flow_info.config.tasks.insert("test".to_string(), task);
+
+    envmnt::remove("TEST_RUN_FLOW_ENV_REQUIRED_PREVIOUS_STEP");
+
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_task_functions_in_condition_env_and_cwd() {
+    let flow_info = create_empty_config_flow_info();
+
+    let directory = test::get_temp_test_directory("run_task_functions_in_condition_env_and_cwd");
+    let directory_path: String = FromPath::from_path(&directory);
+
+    let mut condition_env = IndexMap::new();
+    condition_env.insert(
+        "TEST_RUN_TASK_FUNCTIONS_CONDITION".to_string(),
+        "@@upper(valid)".to_string(),
+    );
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        env: Some(condition_env),
+        ..TaskCondition::default()
+    });
+    task.cwd = Some(format!("@@path_join({})", &directory_path));
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![
+        "writefile ./value.txt @@lower(VALID)".to_string(),
+    ]));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_FUNCTIONS_CONDITION", "VALID");
+    let current_directory: String = FromPath::from_path(&std::env::current_dir().unwrap());
+    envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", &current_directory);
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        fsio::file::read_text_file(&directory.join("value.txt")).unwrap(),
+        "valid"
+    );
+}
+
+#[test]
+fn is_task_env_isolated_default() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+
+    assert!(!is_task_env_isolated(&config, &Task::new()));
+}
+
+#[test]
+fn is_task_env_isolated_config() {
+    let mut config_section = ConfigSection::new();
+    config_section.isolate_task_env = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+
+    assert!(is_task_env_isolated(&config, &Task::new()));
+}
+
+#[test]
+fn is_task_env_isolated_task_scope() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let mut task = Task::new();
+    task.env_scope = Some(EnvScope::Task);
+
+    assert!(is_task_env_isolated(&config, &task));
+}
+
+#[test]
+fn is_task_env_isolated_global_scope_overrides_config() {
+    let mut config_section = ConfigSection::new();
+    config_section.isolate_task_env = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        env_required: None,
+        tasks: IndexMap::new(),
+        plugins: None,
+        functions: None,
+    };
+    let mut task = Task::new();
+    task.env_scope = Some(EnvScope::Global);
+
+    assert!(!is_task_env_isolated(&config, &task));
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -664,8 +1215,10 @@ fn run_task_cwd_no_such_dir() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -703,8 +1256,10 @@ fn run_task_cwd_dir_exists() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -742,8 +1297,10 @@ fn run_task_cwd_env_expansion() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -781,8 +1338,10 @@ fn run_task_deprecated_message() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -843,8 +1402,10 @@ fn run_task_deprecated_flag() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -926,6 +1487,7 @@ fn run_task_failed_condition_script_doesnt_change_env() {
         name: "test".to_string(),
         config: Task {
             // condition for this task always evaluates to false
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::SingleLine("exit 1".to_string())),
             script_runner: Some("@duckscript".to_string()),
             // this scripts should never run since the condition fails
@@ -1442,8 +2004,10 @@ fn run_sub_task_and_report_for_name() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1489,8 +2053,10 @@ fn run_sub_task_and_report_for_name_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1533,8 +2099,10 @@ fn run_sub_task_and_report_for_details_single() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1585,8 +2153,10 @@ fn run_sub_task_and_report_for_details_multiple() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1636,8 +2206,10 @@ fn run_sub_task_and_report_routing_empty() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1682,8 +2254,10 @@ fn run_sub_task_and_report_routing_no_condition() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1736,8 +2310,10 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1778,6 +2354,16 @@ fn run_sub_task_and_report_routing_condition_not_met() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
         condition_script: None,
         condition_script_runner_args: None,
@@ -1808,8 +2394,10 @@ fn run_sub_task_and_report_routing_not_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1861,8 +2449,10 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1903,8 +2493,10 @@ fn get_sub_task_info_for_routing_info_empty() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1939,8 +2531,10 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1983,6 +2577,16 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2003,8 +2607,10 @@ fn get_sub_task_info_for_routing_info_condition_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2047,6 +2653,16 @@ fn get_sub_task_info_for_routing_info_condition_found() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2067,8 +2683,10 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2111,6 +2729,16 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2131,8 +2759,10 @@ fn get_sub_task_info_for_routing_info_script_not_met() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2178,8 +2808,10 @@ fn get_sub_task_info_for_routing_info_script_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2225,8 +2857,10 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2270,6 +2904,16 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    git_branch: None,
+                    git_dirty: None,
+                    git_changed_files: None,
+                    git_tag_on_head: None,
+                    commands_exist: None,
+                    target_triples: None,
+                    crate_features: None,
+                    all: None,
+                    any: None,
+                    not: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2300,8 +2944,10 @@ fn get_sub_task_info_for_routing_info_default() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2345,6 +2991,16 @@ fn get_sub_task_info_for_routing_info_default() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    git_branch: None,
+                    git_dirty: None,
+                    git_changed_files: None,
+                    git_tag_on_head: None,
+                    commands_exist: None,
+                    target_triples: None,
+                    crate_features: None,
+                    all: None,
+                    any: None,
+                    not: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2384,8 +3040,10 @@ fn get_sub_task_info_for_routing_info_multiple() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2429,6 +3087,16 @@ fn get_sub_task_info_for_routing_info_multiple() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    git_branch: None,
+                    git_dirty: None,
+                    git_changed_files: None,
+                    git_tag_on_head: None,
+                    commands_exist: None,
+                    target_triples: None,
+                    crate_features: None,
+                    all: None,
+                    any: None,
+                    not: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2477,8 +3145,10 @@ fn get_sub_task_info_for_routing_info_fork_false() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2521,6 +3191,16 @@ fn get_sub_task_info_for_routing_info_fork_false() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2541,8 +3221,10 @@ fn get_sub_task_info_for_routing_info_fork_true() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2585,6 +3267,16 @@ fn get_sub_task_info_for_routing_info_fork_true() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2605,8 +3297,10 @@ fn create_fork_step_valid() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks: IndexMap::new(),
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2687,8 +3381,10 @@ fn run_flow_skip_init_end_tasks() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     let flow_info = FlowInfo {
         config,
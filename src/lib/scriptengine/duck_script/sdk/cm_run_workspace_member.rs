@@ -0,0 +1,52 @@
+//! # cm_run_workspace_member
+//!
+//! Enables to run a cargo-make task on a workspace member from within duckscript.
+//!
+
+use crate::error::CargoMakeError;
+use crate::types::FlowInfo;
+use crate::workspace;
+use duckscript::types::command::{Command, CommandResult};
+
+#[derive(Clone)]
+pub(crate) struct CommandImpl {
+    flow_info: FlowInfo,
+}
+
+impl Command for CommandImpl {
+    fn name(&self) -> String {
+        "cm_run_workspace_member".to_string()
+    }
+
+    fn clone_and_box(&self) -> Box<dyn Command> {
+        Box::new((*self).clone())
+    }
+
+    fn run(&self, arguments: Vec<String>) -> CommandResult {
+        if arguments.len() < 3 {
+            CommandResult::Error(
+                CargoMakeError::NotFound(String::from(
+                    "Member name, profile or task name not provided.",
+                ))
+                .to_string(),
+            )
+        } else {
+            match workspace::run_member(
+                &self.flow_info.env_info,
+                &arguments[0],
+                &arguments[1],
+                &arguments[2],
+                &arguments[3..],
+            ) {
+                Ok(_) => CommandResult::Continue(Some("true".to_string())),
+                Err(error) => CommandResult::Error(error.to_string()),
+            }
+        }
+    }
+}
+
+pub(crate) fn create(flow_info: &FlowInfo) -> Box<dyn Command> {
+    Box::new(CommandImpl {
+        flow_info: flow_info.clone(),
+    })
+}
@@ -4,6 +4,7 @@
 //!
 
 mod cm_run_task;
+mod cm_run_workspace_member;
 
 use crate::types::{FlowInfo, FlowState};
 use duckscript::types::command::Commands;
@@ -19,6 +20,7 @@ pub(crate) fn load(
 ) -> Result<(), ScriptError> {
     if let (Some(flow_info), Some(flow_state)) = (flow_info_option, flow_state_option) {
         commands.set(cm_run_task::create(flow_info, flow_state))?;
+        commands.set(cm_run_workspace_member::create(flow_info))?;
     }
 
     Ok(())
@@ -0,0 +1,57 @@
+//! # secrets
+//!
+//! Keeps track of the env vars holding secret values and redacts their values from any output.<br>
+//! The secret env var names are stored in the environment so they are also known to child cargo-make processes.
+//!
+
+#[cfg(test)]
+#[path = "secrets_test.rs"]
+mod secrets_test;
+
+static SECRET_KEYS_ENV_VAR_NAME: &str = "CARGO_MAKE_INTERNAL_SECRET_ENV_KEYS";
+
+/// The text which replaces the secret values in the output
+pub(crate) static REDACTED_VALUE: &str = "***";
+
+fn get_keys() -> Vec<String> {
+    if envmnt::exists(SECRET_KEYS_ENV_VAR_NAME) {
+        envmnt::get_list(SECRET_KEYS_ENV_VAR_NAME).unwrap_or_default()
+    } else {
+        vec![]
+    }
+}
+
+/// Marks the provided env var as secret
+pub(crate) fn add_key(key: &str) {
+    let mut keys = get_keys();
+
+    if !keys.iter().any(|existing_key| existing_key == key) {
+        keys.push(key.to_string());
+        envmnt::set_list(SECRET_KEYS_ENV_VAR_NAME, &keys);
+    }
+}
+
+fn get_values() -> Vec<String> {
+    let mut values: Vec<String> = get_keys()
+        .iter()
+        .map(|key| envmnt::get_or(key, ""))
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    // longer values first so values containing other secrets are fully redacted
+    values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+    values.dedup();
+
+    values
+}
+
+/// Replaces all current secret env values found in the provided text
+pub(crate) fn redact(text: &str) -> String {
+    let mut output = text.to_string();
+
+    for value in get_values() {
+        output = output.replace(&value, REDACTED_VALUE);
+    }
+
+    output
+}
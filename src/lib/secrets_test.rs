@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+#[ignore]
+fn add_key_valid() {
+    envmnt::remove(SECRET_KEYS_ENV_VAR_NAME);
+
+    assert!(get_keys().is_empty());
+
+    add_key("SECRETS_TEST_ADD_KEY");
+    add_key("SECRETS_TEST_ADD_KEY");
+
+    assert_eq!(get_keys(), vec!["SECRETS_TEST_ADD_KEY".to_string()]);
+
+    envmnt::remove(SECRET_KEYS_ENV_VAR_NAME);
+}
+
+#[test]
+#[ignore]
+fn redact_no_secrets() {
+    envmnt::remove(SECRET_KEYS_ENV_VAR_NAME);
+
+    let output = redact("some text");
+
+    assert_eq!(output, "some text");
+}
+
+#[test]
+#[ignore]
+fn redact_valid() {
+    envmnt::remove(SECRET_KEYS_ENV_VAR_NAME);
+    envmnt::set("SECRETS_TEST_REDACT_SHORT", "my-token");
+    envmnt::set("SECRETS_TEST_REDACT_LONG", "my-token-with-suffix");
+    envmnt::set("SECRETS_TEST_REDACT_EMPTY", "");
+    envmnt::set("SECRETS_TEST_REDACT_PUBLIC", "public");
+
+    add_key("SECRETS_TEST_REDACT_SHORT");
+    add_key("SECRETS_TEST_REDACT_LONG");
+    add_key("SECRETS_TEST_REDACT_EMPTY");
+    add_key("SECRETS_TEST_REDACT_NOT_SET");
+
+    let output = redact("args: [\"my-token-with-suffix\", \"Bearer my-token\", \"public\"]");
+
+    envmnt::remove(SECRET_KEYS_ENV_VAR_NAME);
+
+    assert_eq!(output, "args: [\"***\", \"Bearer ***\", \"public\"]");
+}
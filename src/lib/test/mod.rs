@@ -131,8 +131,10 @@ pub(crate) fn create_empty_flow_info() -> FlowInfo {
             env_files: vec![],
             env: IndexMap::new(),
             env_scripts: vec![],
+            env_required: None,
             tasks: IndexMap::new(),
             plugins: None,
+            functions: None,
         },
         task: "test".to_string(),
         env_info: EnvInfo {
@@ -9,11 +9,13 @@ mod types_test;
 
 use crate::legacy;
 use crate::plugin::types::Plugins;
+use crate::secrets;
 use ci_info::types::CiInfo;
 use git_info::types::GitInfo;
 use indexmap::{IndexMap, IndexSet};
 use regex::Regex;
 use rust_info::types::RustInfo;
+use schemars::JsonSchema;
 use std::collections::HashMap;
 
 /// Returns the platform name
@@ -115,10 +117,30 @@ pub struct CliArgs {
     pub list_category_steps: Option<String>,
     /// Diff flows
     pub diff_execution_plan: bool,
+    /// Print the resolved env and the source of each env var
+    pub print_env: bool,
+    /// Prints the makefile JSON schema
+    pub print_schema: bool,
+    /// Validates the makefiles and reports all found problems
+    pub lint: bool,
+    /// Rewrites the makefile in the canonical format
+    pub fmt: bool,
+    /// Only checks the makefile is in the canonical format (used with fmt)
+    pub fmt_check: bool,
+    /// Rewrites the deprecated makefile attributes and task references with their replacements
+    pub migrate: bool,
+    /// Writes the migrated makefile instead of only printing the diff (used with migrate)
+    pub migrate_write: bool,
     /// Disables the update check during startup
     pub disable_check_for_updates: bool,
     /// Allows access unsupported experimental predefined tasks
     pub experimental: bool,
+    /// Only load git extended makefiles from the local cache
+    pub offline: bool,
+    /// Fail in case the extended makefiles do not match the makefile lock file
+    pub locked: bool,
+    /// Update the makefile lock file
+    pub update_lock: bool,
     /// additional command line arguments
     pub arguments: Option<Vec<String>>,
     /// Output format
@@ -153,8 +175,18 @@ impl CliArgs {
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
+            print_env: false,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            migrate_write: false,
             disable_check_for_updates: false,
             experimental: false,
+            offline: false,
+            locked: false,
+            update_lock: false,
             arguments: None,
             output_format: "default".to_string(),
             output_file: None,
@@ -286,6 +318,8 @@ pub struct CrateInfo {
     pub workspace: Option<Workspace>,
     /// crate dependencies
     pub dependencies: Option<IndexMap<String, CrateDependency>>,
+    /// crate features
+    pub features: Option<IndexMap<String, Vec<String>>>,
 }
 
 impl CrateInfo {
@@ -347,7 +381,7 @@ impl FlowState {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Rust version condition structure
 pub struct RustVersionCondition {
     /// min version number
@@ -358,7 +392,7 @@ pub struct RustVersionCondition {
     pub equal: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Files modified (input/output) condition structure
 pub struct FilesFilesModifiedCondition {
     /// input files
@@ -367,7 +401,26 @@ pub struct FilesFilesModifiedCondition {
     pub output: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+/// Git changed files condition options
+pub struct GitChangedFilesOptions {
+    /// changed files glob patterns
+    pub files: Vec<String>,
+    /// the base git reference (branch, tag, commit) whose merge base with HEAD is compared against, if not provided the working tree is checked
+    pub base_ref: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(untagged)]
+/// Git changed files condition structure
+pub enum GitChangedFilesCondition {
+    /// changed files glob patterns checked against the working tree
+    Files(Vec<String>),
+    /// changed files glob patterns and base reference
+    Options(GitChangedFilesOptions),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 /// Control how condition checks are evaluated
 pub enum ConditionType {
     /// All conditions must pass
@@ -378,7 +431,7 @@ pub enum ConditionType {
     GroupOr,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds condition attributes
 pub struct TaskCondition {
     /// condition type (AND/OR) by default AND
@@ -413,6 +466,26 @@ pub struct TaskCondition {
     pub files_not_exist: Option<Vec<String>>,
     /// Files modified since last execution
     pub files_modified: Option<FilesFilesModifiedCondition>,
+    /// Git branch name glob patterns
+    pub git_branch: Option<Vec<String>>,
+    /// Git working tree dirty state
+    pub git_dirty: Option<bool>,
+    /// Git changed files glob patterns
+    pub git_changed_files: Option<GitChangedFilesCondition>,
+    /// Git tag on HEAD commit
+    pub git_tag_on_head: Option<bool>,
+    /// Commands which must be found in the PATH
+    pub commands_exist: Option<Vec<String>>,
+    /// Target triple glob patterns
+    pub target_triples: Option<Vec<String>>,
+    /// Crate features which must be declared by the crate
+    pub crate_features: Option<Vec<String>>,
+    /// Nested conditions which must all be met
+    pub all: Option<Vec<TaskCondition>>,
+    /// Nested conditions which at least one of them must be met
+    pub any: Option<Vec<TaskCondition>>,
+    /// Nested condition which must not be met
+    pub not: Option<Box<TaskCondition>>,
 }
 
 impl TaskCondition {
@@ -424,7 +497,7 @@ impl TaskCondition {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env file path and attributes
 pub struct EnvFileInfo {
     /// The file path as string
@@ -435,6 +508,10 @@ pub struct EnvFileInfo {
     pub profile: Option<String>,
     /// If true, only set the env vars if not already defined
     pub defaults_only: Option<bool>,
+    /// If true, all env vars defined in the file are secret and their values are redacted from the output
+    pub secret: Option<bool>,
+    /// The env vars defined in the file which are secret and their values are redacted from the output
+    pub secret_keys: Option<Vec<String>>,
 }
 
 impl EnvFileInfo {
@@ -445,11 +522,13 @@ impl EnvFileInfo {
             base_path: None,
             profile: None,
             defaults_only: None,
+            secret: None,
+            secret_keys: None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds the env file path and attributes
 pub enum EnvFile {
@@ -459,7 +538,7 @@ pub enum EnvFile {
     Info(EnvFileInfo),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value provided by a script
 pub struct EnvValueScript {
     /// The script to execute to get the env value
@@ -472,7 +551,7 @@ pub struct EnvValueScript {
     pub depends_on: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value provided by decoding other values
 pub struct EnvValueDecode {
     /// The source value (can be an env expression)
@@ -485,14 +564,14 @@ pub struct EnvValueDecode {
     pub condition: Option<TaskCondition>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy)]
 /// Enables to unset env variables
 pub struct EnvValueUnset {
     /// If true, the env variable will be unset, else ignored
     pub unset: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value set if condition is met
 pub struct EnvValueConditioned {
     /// The value to set (can be an env expression)
@@ -501,7 +580,121 @@ pub struct EnvValueConditioned {
     pub condition: Option<TaskCondition>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+/// Env value which is secret and should not be printed
+pub struct EnvValueSecret {
+    /// The value to set (can be an env expression)
+    pub value: String,
+    /// True to redact the value from the output
+    pub secret: bool,
+    /// The condition to validate
+    pub condition: Option<TaskCondition>,
+}
+
+impl std::fmt::Debug for EnvValueSecret {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = if self.secret {
+            secrets::REDACTED_VALUE
+        } else {
+            &self.value
+        };
+
+        formatter
+            .debug_struct("EnvValueSecret")
+            .field("value", &value)
+            .field("secret", &self.secret)
+            .field("condition", &self.condition)
+            .finish()
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+/// Env value loaded from a file content
+pub struct EnvValueFile {
+    /// The file path (can be an env expression)
+    pub from_file: String,
+    /// True to trim the file content (defaults to true)
+    pub trim: Option<bool>,
+    /// The condition to validate
+    pub condition: Option<TaskCondition>,
+}
+
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+/// Env value extracted from a structured data file by a key path
+pub struct EnvValueDataPath {
+    /// The TOML file path (can be an env expression)
+    pub toml: Option<String>,
+    /// The JSON file path (can be an env expression)
+    pub json: Option<String>,
+    /// The YAML file path (can be an env expression)
+    pub yaml: Option<String>,
+    /// The dot separated key path of the value, for example: package.metadata.release.channel
+    pub path: String,
+    /// The value to set in case the key path is not found
+    pub default_value: Option<String>,
+    /// The condition to validate
+    pub condition: Option<TaskCondition>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EnvValueDataPathFields {
+    toml: Option<String>,
+    json: Option<String>,
+    yaml: Option<String>,
+    path: String,
+    default_value: Option<String>,
+    condition: Option<TaskCondition>,
+}
+
+impl<'de> serde::de::Deserialize<'de> for EnvValueDataPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let fields = EnvValueDataPathFields::deserialize(deserializer)?;
+
+        // the file format key distinguishes the value from a profile env holding a path key
+        let formats = [&fields.toml, &fields.json, &fields.yaml]
+            .iter()
+            .filter(|file| file.is_some())
+            .count();
+        if formats != 1 {
+            return Err(serde::de::Error::custom(
+                "exactly one of toml, json or yaml files must be defined",
+            ));
+        }
+
+        Ok(EnvValueDataPath {
+            toml: fields.toml,
+            json: fields.json,
+            yaml: fields.yaml,
+            path: fields.path,
+            default_value: fields.default_value,
+            condition: fields.condition,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+/// Env value provided by a command output
+pub struct EnvValueCommand {
+    /// The command to execute (can be an env expression)
+    pub command: String,
+    /// The command arguments (can be env expressions)
+    pub args: Option<Vec<String>>,
+    /// True to trim the command output (defaults to true)
+    pub trim: Option<bool>,
+    /// True to set the default value instead of failing in case the command failed
+    pub allow_failure: Option<bool>,
+    /// The value to set in case the command failed and failures are allowed
+    pub default: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value holding a list of paths based on given glob definitions
 pub struct EnvValuePathGlob {
     /// The glob used to fetch all paths
@@ -514,7 +707,7 @@ pub struct EnvValuePathGlob {
     pub ignore_type: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds the env value or script
 pub enum EnvValue {
@@ -532,10 +725,18 @@ pub enum EnvValue {
     Script(EnvValueScript),
     /// Env decoding info
     Decode(EnvValueDecode),
+    /// Secret env value
+    Secret(EnvValueSecret),
     /// Conditional env value
     Conditional(EnvValueConditioned),
     /// Path glob
     PathGlob(EnvValuePathGlob),
+    /// File content
+    File(EnvValueFile),
+    /// Value from a structured data file
+    DataPath(EnvValueDataPath),
+    /// Command output
+    Command(EnvValueCommand),
     /// Profile env
     Profile(IndexMap<String, EnvValue>),
 }
@@ -602,7 +803,18 @@ impl<'de> serde::de::Deserialize<'de> for TestArg {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl JsonSchema for TestArg {
+    fn schema_name() -> String {
+        "TestArg".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        // same as the custom deserializer, a single string or an array of strings
+        <MaybeArray<String>>::json_schema(generator)
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install the cargo plugin
 pub struct InstallCargoPluginInfo {
     /// The provided crate to install
@@ -672,7 +884,7 @@ impl PartialEq for InstallCargoPluginInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install the crate
 pub struct InstallCrateInfo {
     /// The provided crate to install
@@ -777,7 +989,7 @@ impl PartialEq for InstallCrateInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install a rustup component
 pub struct InstallRustupComponentInfo {
     /// The component to install via rustup
@@ -813,7 +1025,7 @@ impl PartialEq for InstallRustupComponentInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Install crate name or params
 pub enum InstallCrate {
@@ -856,7 +1068,7 @@ impl PartialEq for InstallCrate {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 /// Holds the run task name/s
 pub enum RunTaskName {
@@ -866,7 +1078,7 @@ pub enum RunTaskName {
     Multiple(Vec<String>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds the run task information
 pub struct RunTaskDetails {
     /// The task name
@@ -879,7 +1091,7 @@ pub struct RunTaskDetails {
     pub cleanup_task: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds the run task routing information
 pub struct RunTaskRoutingInfo {
     /// The task name
@@ -898,7 +1110,7 @@ pub struct RunTaskRoutingInfo {
     pub condition_script_runner_args: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Run task info
 pub enum RunTaskInfo {
@@ -910,7 +1122,7 @@ pub enum RunTaskInfo {
     Routing(Vec<RunTaskRoutingInfo>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds watch options
 pub struct WatchOptions {
     /// Watch version to install if not already installed
@@ -927,7 +1139,7 @@ pub struct WatchOptions {
     pub watch: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Eq, PartialEq)]
 #[serde(untagged)]
 /// Could be an array or single value
 pub enum MaybeArray<T> {
@@ -1012,7 +1224,7 @@ impl PartialEq for WatchOptions {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds watch options or simple true/false value
 pub enum TaskWatchOptions {
@@ -1037,7 +1249,7 @@ impl PartialEq for TaskWatchOptions {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds deprecation info such as true/false/message
 pub enum DeprecationInfo {
@@ -1062,7 +1274,7 @@ impl PartialEq for DeprecationInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Script file name
 pub struct FileScriptValue {
     /// Script file name
@@ -1071,7 +1283,7 @@ pub struct FileScriptValue {
     pub absolute_path: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Script content split to parts to enable a more fine tuned extension capability
 pub struct ScriptSections {
     /// Script section
@@ -1082,7 +1294,7 @@ pub struct ScriptSections {
     pub post: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Script value (text, file name, ...)
 pub enum ScriptValue {
@@ -1096,7 +1308,7 @@ pub enum ScriptValue {
     Sections(ScriptSections),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Condition script value (not as advanced as normal script value)
 pub enum ConditionScriptValue {
@@ -1106,7 +1318,59 @@ pub enum ConditionScriptValue {
     Text(Vec<String>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
+/// Holds the validations of a required env var
+pub struct EnvRequiredInfo {
+    /// The regex the env value must match
+    pub pattern: Option<String>,
+    /// The allowed env values
+    pub allowed_values: Option<Vec<String>>,
+    /// The help message printed if the env var is missing or invalid
+    pub help: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+/// Defines a required env var
+pub enum EnvRequired {
+    /// True if the env var is required (false to disable an inherited requirement)
+    Boolean(bool),
+    /// The env var is required and its value is validated
+    Info(EnvRequiredInfo),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+/// The scope of the task env
+pub enum EnvScope {
+    /// The task env is set globally and is available for all following tasks
+    Global,
+    /// The task env is only available during the task invocation and restored afterwards
+    Task,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+/// Holds the task template instantiation info
+pub struct InstantiateInfo {
+    /// The template name
+    pub template: String,
+    /// The values replacing the ${param} placeholders in the template
+    pub params: Option<IndexMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+/// The severity of a lint rule
+pub enum LintLevel {
+    /// Problems are reported and fail the lint
+    Error,
+    /// Problems are reported but do not fail the lint
+    Warning,
+    /// Problems are not reported
+    Off,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds a single task configuration such as command and dependencies list
 pub struct Task {
     /// if true, it should ignore all data in base task
@@ -1123,6 +1387,8 @@ pub struct Task {
     pub deprecated: Option<DeprecationInfo>,
     /// Extend any task based on the defined name
     pub extend: Option<String>,
+    /// Creates the task from a task template (expanded while the makefiles are loaded)
+    pub instantiate: Option<InstantiateInfo>,
     /// set to false to notify cargo-make that this is not a workspace and should not call task for every member (same as --no-workspace CLI flag)
     pub workspace: Option<bool>,
     /// Optional plugin used to execute the task
@@ -1131,6 +1397,8 @@ pub struct Task {
     pub watch: Option<TaskWatchOptions>,
     /// if provided all condition values must be met in order for the task to be invoked (will not stop dependencies)
     pub condition: Option<TaskCondition>,
+    /// if provided the expression must evaluate to true in order for the task to be invoked (will not stop dependencies)
+    pub condition_expr: Option<String>,
     /// if script exit code is not 0, the command/script of this task will not be invoked, dependencies however will be
     pub condition_script: Option<ConditionScriptValue>,
     /// The script runner arguments before the script file path
@@ -1143,6 +1411,10 @@ pub struct Task {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the task commands
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// The env scope (set to task to restore the env after the task is invoked)
+    pub env_scope: Option<EnvScope>,
+    /// The env vars which must be defined (and valid) before running the task commands
+    pub env_required: Option<IndexMap<String, EnvRequired>>,
     /// The working directory for the task to execute its command/script
     pub cwd: Option<String>,
     /// if defined, task points to another task and all other properties are ignored
@@ -1187,7 +1459,7 @@ pub struct Task {
 
 /// A toolchain, defined either as a string (following the rustup syntax)
 /// or a ToolchainBoundedSpecifier.
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum ToolchainSpecifier {
     /// A string specifying the channel name of the toolchain
@@ -1238,7 +1510,7 @@ impl ToolchainSpecifier {
 }
 
 /// A toolchain with a minimum version bound
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct ToolchainBoundedSpecifier {
     /// The channel of the toolchain to use
     pub channel: String,
@@ -1253,7 +1525,7 @@ impl std::fmt::Display for ToolchainBoundedSpecifier {
 }
 
 /// A dependency, defined either as a string or as a Dependency object
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum DependencyIdentifier {
     /// A full dependency definition (potentially in a different file)
@@ -1292,7 +1564,7 @@ impl DependencyIdentifier {
 }
 
 /// An identifier for a task
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct TaskIdentifier {
     /// The task name to execute
     pub name: String,
@@ -1505,6 +1777,12 @@ impl Task {
             self.extend = None;
         }
 
+        if task.instantiate.is_some() {
+            self.instantiate = task.instantiate.clone();
+        } else if override_values {
+            self.instantiate = None;
+        }
+
         if task.workspace.is_some() {
             self.workspace = task.workspace.clone();
         } else if override_values {
@@ -1529,6 +1807,12 @@ impl Task {
             self.condition = None;
         }
 
+        if task.condition_expr.is_some() {
+            self.condition_expr = task.condition_expr.clone();
+        } else if override_values {
+            self.condition_expr = None;
+        }
+
         if task.condition_script.is_some() {
             self.condition_script = task.condition_script.clone();
         } else if override_values {
@@ -1565,6 +1849,18 @@ impl Task {
             self.env = None;
         }
 
+        if task.env_scope.is_some() {
+            self.env_scope = task.env_scope;
+        } else if override_values {
+            self.env_scope = None;
+        }
+
+        if task.env_required.is_some() {
+            self.env_required = task.env_required.clone();
+        } else if override_values {
+            self.env_required = None;
+        }
+
         if task.cwd.is_some() {
             self.cwd = task.cwd.clone();
         } else if override_values {
@@ -1693,7 +1989,7 @@ impl Task {
             Some(value) => value,
             None => match self.force {
                 Some(value) => {
-                    legacy::show_deprecated_attriute_warning("force", "ignore_errors");
+                    legacy::show_deprecated_attriute_warning("force");
 
                     value
                 }
@@ -1737,10 +2033,12 @@ impl Task {
                     private: override_task.private.clone(),
                     deprecated: override_task.deprecated.clone(),
                     extend: override_task.extend.clone(),
+                    instantiate: self.instantiate.clone(),
                     workspace: self.workspace.clone(),
                     plugin: override_task.plugin.clone(),
                     watch: override_task.watch.clone(),
                     condition: override_task.condition.clone(),
+                    condition_expr: override_task.condition_expr.clone(),
                     condition_script: override_task.condition_script.clone(),
                     condition_script_runner_args: override_task
                         .condition_script_runner_args
@@ -1749,6 +2047,8 @@ impl Task {
                     force: override_task.force.clone(),
                     env_files: override_task.env_files.clone(),
                     env: override_task.env.clone(),
+                    env_scope: override_task.env_scope,
+                    env_required: override_task.env_required.clone(),
                     cwd: override_task.cwd.clone(),
                     alias: None,
                     linux_alias: None,
@@ -1883,7 +2183,7 @@ impl Task {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds a single task configuration for a specific platform as an override of another task
 pub struct PlatformOverrideTask {
     /// if true, it should ignore all data in base task
@@ -1902,6 +2202,8 @@ pub struct PlatformOverrideTask {
     pub watch: Option<TaskWatchOptions>,
     /// if provided all condition values must be met in order for the task to be invoked (will not stop dependencies)
     pub condition: Option<TaskCondition>,
+    /// if provided the expression must evaluate to true in order for the task to be invoked (will not stop dependencies)
+    pub condition_expr: Option<String>,
     /// if script exit code is not 0, the command/script of this task will not be invoked, dependencies however will be
     pub condition_script: Option<ConditionScriptValue>,
     /// The script runner arguments before the script file path
@@ -1914,6 +2216,10 @@ pub struct PlatformOverrideTask {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the task commands
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// The env scope (set to task to restore the env after the task is invoked)
+    pub env_scope: Option<EnvScope>,
+    /// The env vars which must be defined (and valid) before running the task commands
+    pub env_required: Option<IndexMap<String, EnvRequired>>,
     /// The working directory for the task to execute its command/script
     pub cwd: Option<String>,
     /// if defined, the provided crate will be installed (if needed) before running the task
@@ -1983,6 +2289,10 @@ impl PlatformOverrideTask {
                 self.condition = task.condition.clone();
             }
 
+            if self.condition_expr.is_none() && task.condition_expr.is_some() {
+                self.condition_expr = task.condition_expr.clone();
+            }
+
             if self.condition_script.is_none() && task.condition_script.is_some() {
                 self.condition_script = task.condition_script.clone();
             }
@@ -2009,6 +2319,14 @@ impl PlatformOverrideTask {
                 self.env = task.env.clone();
             }
 
+            if self.env_scope.is_none() && task.env_scope.is_some() {
+                self.env_scope = task.env_scope;
+            }
+
+            if self.env_required.is_none() && task.env_required.is_some() {
+                self.env_required = task.env_required.clone();
+            }
+
             if self.cwd.is_none() && task.cwd.is_some() {
                 self.cwd = task.cwd.clone();
             }
@@ -2065,16 +2383,22 @@ impl PlatformOverrideTask {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Extend with more fine tuning options
 pub struct ExtendOptions {
-    /// Path to another makefile
+    /// Path to another makefile (relative to the repository root when git is defined)
     pub path: String,
     /// Enable optional extend (default to false)
     pub optional: Option<bool>,
+    /// Git repository URL to load the makefile from
+    pub git: Option<String>,
+    /// The git revision (commit sha or tag) to pin the repository to
+    pub rev: Option<String>,
+    /// If set, all tasks of the makefile (and references to them) are prefixed with namespace::
+    pub namespace: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds makefile extend value
 pub enum Extend {
@@ -2086,7 +2410,7 @@ pub enum Extend {
     List(Vec<ExtendOptions>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds properties to modify the core tasks
 pub struct ModifyConfig {
     /// If true, all core tasks will be set to private (default false)
@@ -2117,7 +2441,7 @@ impl ModifyConfig {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// Unstable cargo-make feature
 pub enum UnstableFeature {
@@ -2139,7 +2463,7 @@ impl UnstableFeature {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the configuration found in the makefile toml config section.
 pub struct ConfigSection {
     /// If true, the default core tasks will not be loaded
@@ -2172,6 +2496,10 @@ pub struct ConfigSection {
     pub time_summary: Option<bool>,
     /// Automatically load cargo aliases as cargo-make tasks
     pub load_cargo_aliases: Option<bool>,
+    /// Automatically load the .env.<profile> file (from the working directory) for the active profiles
+    pub load_profile_env_file: Option<bool>,
+    /// Restore the env after every task invocation so task env does not leak to following tasks
+    pub isolate_task_env: Option<bool>,
     /// The project information member (used by workspaces)
     pub main_project_member: Option<String>,
     /// Invoked while loading the descriptor file but before loading any extended descriptor
@@ -2184,6 +2512,8 @@ pub struct ConfigSection {
     pub mac_load_script: Option<ScriptValue>,
     /// Enables unstable cargo-make features
     pub unstable_features: Option<IndexSet<UnstableFeature>>,
+    /// Overrides the severity of lint rules (rule name to level)
+    pub lint_levels: Option<IndexMap<String, LintLevel>>,
 }
 
 impl ConfigSection {
@@ -2294,6 +2624,14 @@ impl ConfigSection {
             self.load_cargo_aliases = extended.load_cargo_aliases.clone();
         }
 
+        if extended.load_profile_env_file.is_some() {
+            self.load_profile_env_file = extended.load_profile_env_file;
+        }
+
+        if extended.isolate_task_env.is_some() {
+            self.isolate_task_env = extended.isolate_task_env;
+        }
+
         if extended.main_project_member.is_some() {
             self.main_project_member = extended.main_project_member.clone();
         }
@@ -2331,6 +2669,14 @@ impl ConfigSection {
                 self.unstable_features = Some(extended_unstable_features);
             }
         }
+
+        if let Some(extended_lint_levels) = extended.lint_levels.clone() {
+            if let Some(lint_levels) = &mut self.lint_levels {
+                lint_levels.extend(extended_lint_levels);
+            } else {
+                self.lint_levels = Some(extended_lint_levels);
+            }
+        }
     }
 
     /// Returns the load script based on the current platform
@@ -2359,7 +2705,17 @@ impl ConfigSection {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+/// A makefile function implemented as a duckscript script
+pub struct UserFunction {
+    /// The function description
+    pub description: Option<String>,
+    /// The duckscript code, the function arguments are available as ${1}, ${2}, ... and the
+    /// function output is the value of the output variable
+    pub script: ScriptValue,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the entire configuration such as task definitions and env vars
 pub struct Config {
     /// Runtime config
@@ -2370,10 +2726,14 @@ pub struct Config {
     pub env: IndexMap<String, EnvValue>,
     /// The env scripts to execute before running the flow
     pub env_scripts: Vec<String>,
+    /// The env vars which must be defined (and valid) before running the flow
+    pub env_required: Option<IndexMap<String, EnvRequired>>,
     /// All task definitions
     pub tasks: IndexMap<String, Task>,
     /// All plugin definitions
     pub plugins: Option<Plugins>,
+    /// All user defined function definitions
+    pub functions: Option<IndexMap<String, UserFunction>>,
 }
 
 impl Config {
@@ -2401,7 +2761,7 @@ impl Config {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the entire externally read configuration such as task definitions and env vars where all values are optional
 pub struct ExternalConfig {
     /// Path to another toml file to extend
@@ -2414,10 +2774,16 @@ pub struct ExternalConfig {
     pub env: Option<IndexMap<String, EnvValue>>,
     /// The env scripts to execute before running the flow
     pub env_scripts: Option<Vec<String>>,
+    /// The env vars which must be defined (and valid) before running the flow
+    pub env_required: Option<IndexMap<String, EnvRequired>>,
+    /// All task template definitions (with ${param} placeholders)
+    pub templates: Option<IndexMap<String, Task>>,
     /// All task definitions
     pub tasks: Option<IndexMap<String, Task>>,
     /// All plugin definitions
     pub plugins: Option<Plugins>,
+    /// All user defined function definitions
+    pub functions: Option<IndexMap<String, UserFunction>>,
 }
 
 impl ExternalConfig {
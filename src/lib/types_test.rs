@@ -1554,6 +1554,218 @@ fn env_value_deserialize_decode() {
     }
 }
 
+#[test]
+fn env_value_deserialize_secret_env_value() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { value = "${TOKEN}", secret = true, condition = {} }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::Secret(value) => {
+                assert_eq!(value.value, "${TOKEN}");
+                assert!(value.secret);
+                assert!(value.condition.is_some());
+
+                ()
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_secret_debug_redacted() {
+    let value = EnvValueSecret {
+        value: "my-secret-token".to_string(),
+        secret: true,
+        condition: None,
+    };
+
+    let output = format!("{:?}", &value);
+
+    assert!(!output.contains("my-secret-token"));
+    assert!(output.contains("***"));
+}
+
+#[test]
+fn env_value_secret_debug_not_secret() {
+    let value = EnvValueSecret {
+        value: "my-public-value".to_string(),
+        secret: false,
+        condition: None,
+    };
+
+    let output = format!("{:?}", &value);
+
+    assert!(output.contains("my-public-value"));
+}
+
+#[test]
+fn env_value_deserialize_file() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { from_file = "./VERSION", trim = false }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::File(value) => {
+                assert_eq!(value.from_file, "./VERSION");
+                assert!(!value.trim.unwrap());
+                assert!(value.condition.is_none());
+
+                ()
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_data_path() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { toml = "Cargo.toml", path = "package.metadata.release.channel" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::DataPath(value) => {
+                assert_eq!(value.toml.clone().unwrap(), "Cargo.toml");
+                assert!(value.json.is_none());
+                assert!(value.yaml.is_none());
+                assert_eq!(value.path, "package.metadata.release.channel");
+                assert!(value.default_value.is_none());
+
+                ()
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_profile_with_path_and_file_keys() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env.ci]
+        path = "./bin"
+        file = "./VERSION"
+
+        [env.path_only]
+        path = "./bin"
+
+        [env.file_only]
+        file = "./VERSION"
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    assert_eq!(env.len(), 3);
+    for (_, info) in &env {
+        match info {
+            EnvValue::Profile(value) => {
+                for key in value.keys() {
+                    match value.get(key).unwrap() {
+                        EnvValue::Value(_) => (),
+                        _ => panic!("invalid profile env value type"),
+                    }
+                }
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+    let profile = match env.get("ci").unwrap() {
+        EnvValue::Profile(value) => value,
+        _ => panic!("invalid env value type"),
+    };
+    assert_eq!(profile.len(), 2);
+    assert!(profile.contains_key("path"));
+    assert!(profile.contains_key("file"));
+}
+
+#[test]
+fn env_value_deserialize_profile_with_path_and_format_keys() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env.ci]
+        toml = "Cargo.toml"
+        path = "./bin"
+        CI_VALUE = "1"
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    match env.get("ci").unwrap() {
+        EnvValue::Profile(value) => assert_eq!(value.len(), 3),
+        _ => panic!("invalid env value type"),
+    };
+}
+
+#[test]
+fn env_value_deserialize_command() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { command = "git", args = ["rev-parse", "HEAD"], allow_failure = true, default = "unknown" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::Command(value) => {
+                assert_eq!(value.command, "git");
+                assert_eq!(
+                    value.args.clone().unwrap(),
+                    vec!["rev-parse".to_string(), "HEAD".to_string()]
+                );
+                assert!(value.trim.is_none());
+                assert!(value.allow_failure.unwrap());
+                assert_eq!(value.default.clone().unwrap(), "unknown");
+
+                ()
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_profile_with_command_key() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env.ci]
+        command = "git"
+        CI_VALUE = "1"
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    match env.get("ci").unwrap() {
+        EnvValue::Profile(value) => assert_eq!(value.len(), 2),
+        _ => panic!("invalid env value type"),
+    };
+}
+
 #[test]
 fn env_value_deserialize_conditional_env_value_no_condition() {
     let config: ExternalConfig = toml::from_str(
@@ -1811,14 +2023,18 @@ fn task_extend_both_have_misc_data() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Message("extended".to_string())),
         extend: None,
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         ignore_errors: Some(true),
         force: Some(true),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: None,
         alias: Some("alias2".to_string()),
         linux_alias: None,
@@ -1913,14 +2129,18 @@ fn task_extend_extended_have_all_fields() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: None,
         ignore_errors: Some(true),
         force: Some(true),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: None,
         alias: None,
         linux_alias: None,
@@ -1943,6 +2163,8 @@ fn task_extend_extended_have_all_fields() {
 
     let mut env = IndexMap::new();
     env.insert("test".to_string(), EnvValue::Value("value".to_string()));
+    let mut env_required = IndexMap::new();
+    env_required.insert("REQUIRED".to_string(), EnvRequired::Boolean(true));
     let extended = Task {
         clear: Some(false),
         install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -1956,6 +2178,7 @@ fn task_extend_extended_have_all_fields() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(false)),
         extend: Some("extended".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -1974,13 +2197,26 @@ fn task_extend_extended_have_all_fields() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: Some("os == \"linux\"".to_string()),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![EnvFile::Path("extended".to_string())]),
         env: Some(env.clone()),
+        env_scope: Some(EnvScope::Task),
+        env_required: Some(env_required.clone()),
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -2027,13 +2263,26 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![EnvFile::Path("extended".to_string())]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2077,13 +2326,26 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![EnvFile::Path("extended".to_string())]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2127,13 +2389,26 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![EnvFile::Path("extended".to_string())]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2167,12 +2442,15 @@ fn task_extend_extended_have_all_fields() {
     assert!(base.extend.is_some());
     assert!(base.watch.is_some());
     assert!(base.condition.is_some());
+    assert!(base.condition_expr.is_some());
     assert!(base.condition_script.is_some());
     assert!(base.condition_script_runner_args.is_some());
     assert!(base.ignore_errors.is_some());
     assert!(base.force.is_some());
     assert!(base.env_files.is_some());
     assert!(base.env.is_some());
+    assert!(base.env_scope.is_some());
+    assert!(base.env_required.is_some());
     assert!(base.cwd.is_some());
     assert!(base.alias.is_some());
     assert!(base.linux_alias.is_some());
@@ -2213,10 +2491,13 @@ fn task_extend_extended_have_all_fields() {
     };
     assert_eq!(condition_script.len(), 1);
     assert_eq!(base.condition_script_runner_args.unwrap().len(), 2);
+    assert_eq!(base.condition_expr.unwrap(), "os == \"linux\"");
     assert!(!base.ignore_errors.unwrap());
     assert!(!base.force.unwrap());
     assert_eq!(base.env_files.unwrap().len(), 1);
     assert_eq!(base.env.unwrap().len(), 1);
+    assert_eq!(base.env_scope, Some(EnvScope::Task));
+    assert_eq!(base.env_required.unwrap().len(), 1);
     assert_eq!(base.cwd.unwrap(), "cwd".to_string());
     assert_eq!(base.alias.unwrap(), "alias2");
     assert_eq!(base.linux_alias.unwrap(), "linux");
@@ -2260,6 +2541,7 @@ fn task_extend_clear_with_no_data() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2278,13 +2560,26 @@ fn task_extend_clear_with_no_data() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(env.clone()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -2331,13 +2626,26 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2381,13 +2689,26 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2431,13 +2752,26 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2517,6 +2851,7 @@ fn task_extend_clear_with_all_data() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2535,13 +2870,26 @@ fn task_extend_clear_with_all_data() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(env.clone()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -2588,13 +2936,26 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2638,13 +2999,26 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2688,13 +3062,26 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
             ignore_errors: Some(true),
             force: Some(true),
             env_files: Some(vec![]),
             env: Some(env.clone()),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd".to_string()),
             install_script: Some(ScriptValue::Text(vec!["i1".to_string(), "i2".to_string()])),
             args: Some(vec!["a1".to_string(), "a2".to_string()]),
@@ -2803,14 +3190,18 @@ fn task_get_normalized_task_undefined() {
         private: Some(true),
         deprecated: None,
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
+        condition_expr: None,
         condition_script: None,
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: None,
         force: None,
         env_files: None,
         env: None,
+        env_scope: None,
+        env_required: None,
         cwd: None,
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -2938,6 +3329,7 @@ fn task_get_normalized_task_with_override_no_clear() {
         private: Some(true),
         deprecated: None,
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2956,13 +3348,26 @@ fn task_get_normalized_task_with_override_no_clear() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3005,7 +3410,18 @@ fn task_get_normalized_task_with_override_no_clear() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec![
                 "csr_a1".to_string(),
@@ -3016,6 +3432,8 @@ fn task_get_normalized_task_with_override_no_clear() {
             force: Some(true),
             env_files: Some(vec![EnvFile::Path("extended".to_string())]),
             env: Some(env),
+            env_scope: Some(EnvScope::Task),
+            env_required: None,
             cwd: Some("cwd2".to_string()),
             install_script: Some(ScriptValue::Text(vec![
                 "A".to_string(),
@@ -3119,6 +3537,7 @@ fn task_get_normalized_task_with_override_no_clear() {
     assert!(normalized_task.force.unwrap());
     assert_eq!(normalized_task.env_files.unwrap().len(), 1);
     assert_eq!(normalized_task.env.unwrap().len(), 1);
+    assert_eq!(normalized_task.env_scope, Some(EnvScope::Task));
     assert_eq!(normalized_task.cwd.unwrap(), "cwd2".to_string());
     assert_eq!(get_script_as_vec(normalized_task.install_script).len(), 4);
     assert_eq!(normalized_task.args.unwrap().len(), 3);
@@ -3162,6 +3581,7 @@ fn task_get_normalized_task_with_override_clear_false() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(false)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3180,13 +3600,26 @@ fn task_get_normalized_task_with_override_clear_false() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3232,7 +3665,18 @@ fn task_get_normalized_task_with_override_clear_false() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                git_branch: None,
+                git_dirty: None,
+                git_changed_files: None,
+                git_tag_on_head: None,
+                commands_exist: None,
+                target_triples: None,
+                crate_features: None,
+                all: None,
+                any: None,
+                not: None,
             }),
+            condition_expr: None,
             condition_script: Some(ConditionScriptValue::Text(vec![
                 "echo test".to_string(),
                 "exit 1".to_string(),
@@ -3246,6 +3690,8 @@ fn task_get_normalized_task_with_override_clear_false() {
             force: Some(true),
             env_files: Some(vec![EnvFile::Path("extended".to_string())]),
             env: Some(env),
+            env_scope: None,
+            env_required: None,
             cwd: Some("cwd2".to_string()),
             install_crate_args: Some(vec!["c1".to_string(), "c2".to_string(), "c3".to_string()]),
             install_script: Some(ScriptValue::Text(vec![
@@ -3386,6 +3832,7 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3404,13 +3851,26 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3441,12 +3901,15 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             plugin: None,
             watch: None,
             condition: None,
+            condition_expr: None,
             condition_script: None,
             condition_script_runner_args: None,
             ignore_errors: None,
             force: None,
             env_files: None,
             env: None,
+            env_scope: None,
+            env_required: None,
             cwd: None,
             install_script: None,
             args: None,
@@ -3559,6 +4022,7 @@ fn task_get_normalized_task_with_override_clear_true() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        instantiate: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3577,13 +4041,26 @@ fn task_get_normalized_task_with_override_clear_true() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            git_branch: None,
+            git_dirty: None,
+            git_changed_files: None,
+            git_tag_on_head: None,
+            commands_exist: None,
+            target_triples: None,
+            crate_features: None,
+            all: None,
+            any: None,
+            not: None,
         }),
+        condition_expr: None,
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        env_scope: None,
+        env_required: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3614,12 +4091,15 @@ fn task_get_normalized_task_with_override_clear_true() {
             plugin: None,
             watch: None,
             condition: None,
+            condition_expr: None,
             condition_script: None,
             condition_script_runner_args: None,
             ignore_errors: None,
             force: None,
             env_files: None,
             env: None,
+            env_scope: None,
+            env_required: None,
             cwd: None,
             install_script: None,
             args: None,
@@ -3899,6 +4379,8 @@ fn config_section_new() {
     assert!(config.reduce_output.is_none());
     assert!(config.time_summary.is_none());
     assert!(config.load_cargo_aliases.is_none());
+    assert!(config.load_profile_env_file.is_none());
+    assert!(config.isolate_task_env.is_none());
     assert!(config.main_project_member.is_none());
     assert!(config.load_script.is_none());
     assert!(config.linux_load_script.is_none());
@@ -3929,6 +4411,8 @@ fn config_section_extend_all_values() {
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
     base.load_cargo_aliases = Some(true);
+    base.load_profile_env_file = Some(true);
+    base.isolate_task_env = Some(true);
     base.load_script = Some(ScriptValue::Text(vec!["base_info".to_string()]));
     base.linux_load_script = Some(ScriptValue::Text(vec![
         "linux".to_string(),
@@ -3962,6 +4446,8 @@ fn config_section_extend_all_values() {
     extended.reduce_output = Some(false);
     extended.time_summary = Some(false);
     extended.load_cargo_aliases = Some(false);
+    extended.load_profile_env_file = Some(false);
+    extended.isolate_task_env = Some(false);
     extended.load_script = Some(ScriptValue::Text(vec![
         "extended_info".to_string(),
         "arg2".to_string(),
@@ -3995,6 +4481,8 @@ fn config_section_extend_all_values() {
     assert!(!base.reduce_output.unwrap());
     assert!(!base.time_summary.unwrap());
     assert!(!base.load_cargo_aliases.unwrap());
+    assert!(!base.load_profile_env_file.unwrap());
+    assert!(!base.isolate_task_env.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 1);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 1);
@@ -4024,6 +4512,8 @@ fn config_section_extend_no_values() {
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
     base.load_cargo_aliases = Some(true);
+    base.load_profile_env_file = Some(true);
+    base.isolate_task_env = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
         "arg2".to_string(),
@@ -4066,6 +4556,8 @@ fn config_section_extend_no_values() {
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
     assert!(base.load_cargo_aliases.unwrap());
+    assert!(base.load_profile_env_file.unwrap());
+    assert!(base.isolate_task_env.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 2);
@@ -4095,6 +4587,8 @@ fn config_section_extend_some_values() {
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
     base.load_cargo_aliases = Some(true);
+    base.load_profile_env_file = Some(true);
+    base.isolate_task_env = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
         "arg2".to_string(),
@@ -4140,12 +4634,35 @@ fn config_section_extend_some_values() {
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
     assert!(base.load_cargo_aliases.unwrap());
+    assert!(base.load_profile_env_file.unwrap());
+    assert!(base.isolate_task_env.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.mac_load_script).len(), 2);
 }
 
+#[test]
+fn config_section_extend_lint_levels() {
+    let mut base = ConfigSection::new();
+    let mut extended = ConfigSection::new();
+
+    let mut base_levels = IndexMap::new();
+    base_levels.insert("unknown-key".to_string(), LintLevel::Off);
+    base_levels.insert("unused-private-task".to_string(), LintLevel::Error);
+    base.lint_levels = Some(base_levels);
+    let mut extended_levels = IndexMap::new();
+    extended_levels.insert("unused-private-task".to_string(), LintLevel::Warning);
+    extended.lint_levels = Some(extended_levels);
+
+    base.extend(&mut extended);
+
+    let lint_levels = base.lint_levels.unwrap();
+    assert_eq!(lint_levels.len(), 2);
+    assert_eq!(lint_levels["unknown-key"], LintLevel::Off);
+    assert_eq!(lint_levels["unused-private-task"], LintLevel::Warning);
+}
+
 #[test]
 fn config_section_get_get_load_script_all_none() {
     let config = ConfigSection::new();
@@ -4624,8 +5141,10 @@ fn config_apply_modify_empty() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     config.apply(&modify_config);
 
@@ -4652,8 +5171,10 @@ fn config_apply_modify_all() {
         env_files: vec![],
         env: IndexMap::new(),
         env_scripts: vec![],
+        env_required: None,
         tasks,
         plugins: None,
+        functions: None,
     };
     config.apply(&modify_config);
 
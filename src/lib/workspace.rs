@@ -0,0 +1,121 @@
+//! # workspace
+//!
+//! Runs the requested task on a workspace member inside the current process.<br>
+//! The member makefile is loaded and its execution plan is invoked the same way
+//! as a nested cargo-make invocation would, while the env and working directory
+//! are restored once the member flow is done.
+//!
+
+#[cfg(test)]
+#[path = "workspace_test.rs"]
+mod workspace_test;
+
+use crate::cli;
+use crate::cli::DEFAULT_OUTPUT_FORMAT;
+use crate::descriptor;
+use crate::environment;
+use crate::environment::provenance;
+use crate::error::CargoMakeError;
+use crate::functions::user_functions;
+use crate::logger;
+use crate::recursion_level;
+use crate::runner;
+use crate::types::{CliArgs, EnvInfo};
+use std::env;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Returns true if workspace members should be invoked in the current process
+/// instead of spawning a new cargo-make process per member.
+pub(crate) fn is_in_process_enabled() -> bool {
+    envmnt::is_or("CARGO_MAKE_WORKSPACE_IN_PROCESS", true)
+}
+
+fn create_member_cli_args(
+    member_name: &str,
+    profile_name: &str,
+    task: &str,
+    arguments: &[String],
+) -> CliArgs {
+    let mut cli_args = CliArgs::new();
+
+    cli_args.command = envmnt::get_or("CARGO_MAKE_COMMAND", "cargo make");
+    cli_args.task = task.to_string();
+    cli_args.profile = Some(profile_name.to_string());
+    cli_args.log_level = logger::get_log_level();
+    cli_args.disable_check_for_updates = true;
+    cli_args.allow_private = true;
+    cli_args.disable_on_error = true;
+    cli_args.output_format = DEFAULT_OUTPUT_FORMAT.to_string();
+    cli_args.env = Some(vec![format!(
+        "CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER={}",
+        member_name
+    )]);
+    cli_args.arguments = if arguments.is_empty() {
+        None
+    } else {
+        Some(arguments.to_vec())
+    };
+
+    cli_args
+}
+
+fn run_member_flow(cli_args: &CliArgs, cached_env_info: &EnvInfo) -> Result<(), CargoMakeError> {
+    let start_time = SystemTime::now();
+
+    let home = environment::setup_cwd(None);
+
+    let build_file = descriptor::get_default_makefile(Path::new("."));
+    let (config, normalized_profile_name) = cli::load_makefiles(cli_args, &build_file, false)?;
+
+    let (env_info, time_summary_vec) = cli::setup_flow(
+        cli_args,
+        &config,
+        &build_file,
+        &normalized_profile_name,
+        home,
+        start_time,
+        Some(cached_env_info),
+    )?;
+
+    runner::run(
+        config,
+        &cli_args.task,
+        env_info,
+        cli_args,
+        start_time,
+        time_summary_vec,
+    )
+}
+
+/// Runs the requested task for the workspace member found in the current working directory.<br>
+/// The env and working directory are restored after the member flow is done, regardless
+/// if it was successful or not.
+pub(crate) fn run_member(
+    env_info: &EnvInfo,
+    member_name: &str,
+    profile_name: &str,
+    task: &str,
+    arguments: &[String],
+) -> Result<(), CargoMakeError> {
+    debug!("Running workspace member: {} task: {}", member_name, task);
+
+    let env_snapshot = environment::get_env_snapshot();
+    let cwd = env::current_dir()?;
+    // the member flow records its own env sources, same as a new cargo-make process
+    let provenance_state = provenance::take();
+    // the member makefile registers its own user defined functions
+    let functions_registry = user_functions::take();
+
+    recursion_level::increment();
+
+    let cli_args = create_member_cli_args(member_name, profile_name, task, arguments);
+    let result = run_member_flow(&cli_args, env_info);
+
+    environment::restore_env_snapshot(&env_snapshot);
+    provenance::restore(provenance_state);
+    user_functions::restore(functions_registry);
+    env::set_current_dir(&cwd)?;
+
+    result
+}
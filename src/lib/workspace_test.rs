@@ -0,0 +1,194 @@
+use super::*;
+use crate::test;
+use crate::types::{CrateInfo, ScriptValue, UserFunction};
+use ci_info::types::CiInfo;
+use git_info::types::GitInfo;
+use indexmap::IndexMap;
+use rust_info::types::RustInfo;
+
+fn create_empty_env_info() -> EnvInfo {
+    EnvInfo {
+        rust_info: RustInfo::new(),
+        crate_info: CrateInfo::new(),
+        git_info: GitInfo::new(),
+        ci_info: CiInfo::new(),
+    }
+}
+
+#[test]
+#[ignore]
+fn is_in_process_enabled_default() {
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
+    assert!(is_in_process_enabled());
+}
+
+#[test]
+#[ignore]
+fn is_in_process_enabled_false() {
+    envmnt::set_bool("CARGO_MAKE_WORKSPACE_IN_PROCESS", false);
+
+    let enabled = is_in_process_enabled();
+
+    envmnt::remove("CARGO_MAKE_WORKSPACE_IN_PROCESS");
+
+    assert!(!enabled);
+}
+
+#[test]
+fn create_member_cli_args_no_arguments() {
+    let cli_args = create_member_cli_args("member1", "test_profile", "some_task", &vec![]);
+
+    assert_eq!(cli_args.task, "some_task");
+    assert_eq!(cli_args.profile.unwrap(), "test_profile");
+    assert!(cli_args.disable_check_for_updates);
+    assert!(cli_args.allow_private);
+    assert!(cli_args.disable_on_error);
+    assert!(!cli_args.disable_workspace);
+    assert!(cli_args.build_file.is_none());
+    assert!(cli_args.arguments.is_none());
+    assert_eq!(
+        cli_args.env.unwrap(),
+        vec!["CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1".to_string()]
+    );
+}
+
+#[test]
+fn create_member_cli_args_with_arguments() {
+    let cli_args = create_member_cli_args(
+        "member2",
+        "test_profile",
+        "some_task",
+        &vec!["arg1".to_string(), "arg2".to_string()],
+    );
+
+    assert_eq!(cli_args.task, "some_task");
+    assert_eq!(
+        cli_args.arguments.unwrap(),
+        vec!["arg1".to_string(), "arg2".to_string()]
+    );
+    assert_eq!(
+        cli_args.env.unwrap(),
+        vec!["CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member2".to_string()]
+    );
+}
+
+#[test]
+#[ignore]
+fn run_member_valid() {
+    let directory = test::get_temp_test_directory("workspace_run_member_valid");
+    let makefile = directory.join("Makefile.toml");
+    fsio::file::write_text_file(
+        &makefile,
+        r#"
+[config]
+skip_core_tasks = true
+skip_crate_env_info = true
+skip_git_env_info = true
+skip_rust_env_info = true
+
+[env]
+WORKSPACE_TEST_MEMBER_ENV = "member"
+
+[tasks.member_task]
+script_runner = "@duckscript"
+script = "writefile ./output.txt ${CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER}:${WORKSPACE_TEST_MEMBER_ENV}:${1}"
+"#,
+    )
+    .unwrap();
+
+    let cwd = env::current_dir().unwrap();
+    envmnt::remove("WORKSPACE_TEST_MEMBER_ENV");
+    envmnt::remove("CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER");
+    provenance::clear();
+    provenance::track("root flow", || {
+        envmnt::set("WORKSPACE_TEST_ROOT_PROVENANCE", "root")
+    });
+
+    env::set_current_dir(&directory).unwrap();
+    let result = run_member(
+        &create_empty_env_info(),
+        "member1",
+        "development",
+        "member_task",
+        &vec!["arg1".to_string()],
+    );
+    let member_cwd = env::current_dir().unwrap();
+    env::set_current_dir(&cwd).unwrap();
+
+    assert!(result.is_ok());
+    assert_eq!(member_cwd, directory);
+    assert!(!envmnt::exists("WORKSPACE_TEST_MEMBER_ENV"));
+    assert!(!envmnt::exists("CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER"));
+    // the root flow env sources are kept while the member sources are discarded
+    assert_eq!(
+        provenance::get_sources("WORKSPACE_TEST_ROOT_PROVENANCE").len(),
+        1
+    );
+    assert!(provenance::get_sources("WORKSPACE_TEST_MEMBER_ENV").is_empty());
+
+    let output = fsio::file::read_text_file(&directory.join("output.txt")).unwrap();
+    assert_eq!(output, "member1:member:arg1");
+}
+
+#[test]
+#[ignore]
+fn run_member_user_functions() {
+    let directory = test::get_temp_test_directory("workspace_run_member_user_functions");
+    let makefile = directory.join("Makefile.toml");
+    fsio::file::write_text_file(
+        &makefile,
+        r#"
+[config]
+skip_core_tasks = true
+skip_crate_env_info = true
+skip_git_env_info = true
+skip_rust_env_info = true
+
+[functions.artifact_name]
+script = "output = set \"member-${1}\""
+
+[tasks.member_task]
+script_runner = "@duckscript"
+script = "writefile ./output.txt @@artifact_name(linux)"
+"#,
+    )
+    .unwrap();
+
+    let mut root_functions = IndexMap::new();
+    root_functions.insert(
+        "artifact_name".to_string(),
+        UserFunction {
+            description: None,
+            script: ScriptValue::Text(vec!["output = set \"root-${1}\"".to_string()]),
+        },
+    );
+    user_functions::register(&Some(root_functions)).unwrap();
+    let root_output = user_functions::invoke("artifact_name", &vec!["linux".to_string()])
+        .unwrap()
+        .unwrap();
+
+    let cwd = env::current_dir().unwrap();
+    env::set_current_dir(&directory).unwrap();
+    let result = run_member(
+        &create_empty_env_info(),
+        "member1",
+        "development",
+        "member_task",
+        &vec![],
+    );
+    env::set_current_dir(&cwd).unwrap();
+
+    assert!(result.is_ok());
+    let output = fsio::file::read_text_file(&directory.join("output.txt")).unwrap();
+    assert_eq!(output, "member-linux");
+
+    // the root functions are restored once the member flow is done
+    let output = user_functions::invoke("artifact_name", &vec!["linux".to_string()])
+        .unwrap()
+        .unwrap();
+    assert_eq!(output, root_output);
+    assert_eq!(output, vec!["root-linux"]);
+
+    user_functions::register(&None).unwrap();
+}